@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/duplicate-header")
+def duplicate_header():
+    return ""
@@ -67,6 +67,7 @@ pub enum Method {
     Unlock,
     Propfind,
     View,
+    Custom(String),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -126,6 +127,7 @@ impl fmt::Display for Method {
             Method::Unlock => "UNLOCK",
             Method::Propfind => "PROPFIND",
             Method::View => "VIEW",
+            Method::Custom(name) => name,
         };
         write!(f, "{value}")
     }
@@ -61,17 +61,18 @@ impl ContentEncoding {
 }
 
 impl Response {
+    /// Returns the charset of the `Content-Type` header, if any.
+    pub fn charset(&self) -> Option<String> {
+        self.content_type()
+            .and_then(|content_type| mimetype::charset(&content_type))
+    }
+
     /// Returns character encoding of the HTTP response.
     fn character_encoding(&self) -> Result<EncodingRef, HttpError> {
-        match self.content_type() {
-            Some(content_type) => match mimetype::charset(&content_type) {
-                Some(charset) => {
-                    match encoding::label::encoding_from_whatwg_label(charset.as_str()) {
-                        None => Err(HttpError::InvalidCharset { charset }),
-                        Some(enc) => Ok(enc),
-                    }
-                }
-                None => Ok(encoding::all::UTF_8),
+        match self.charset() {
+            Some(charset) => match encoding::label::encoding_from_whatwg_label(charset.as_str()) {
+                None => Err(HttpError::InvalidCharset { charset }),
+                Some(enc) => Ok(enc),
             },
             None => Ok(encoding::all::UTF_8),
         }
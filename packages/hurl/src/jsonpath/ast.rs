@@ -24,25 +24,110 @@ pub struct Query {
     pub selectors: Vec<Selector>,
 }
 
+impl Query {
+    /// Returns the number of selectors of this query (`$.a.b` has a depth of 2).
+    pub fn depth(&self) -> usize {
+        self.selectors.len()
+    }
+
+    /// Returns a new query, resolving the relative query `other` against this one.
+    ///
+    /// As queries start from the root `$` and have no explicit root selector, the selectors of
+    /// `other` are simply appended: `$.data` joined with `$.id` gives `$.data.id`.
+    pub fn join(&self, other: &Query) -> Query {
+        let mut selectors = self.selectors.clone();
+        selectors.extend(other.selectors.iter().cloned());
+        Query { selectors }
+    }
+
+    /// Returns true if this query uses a recursive descent selector (`..`).
+    ///
+    /// Such queries walk the whole document and can be expensive over large documents.
+    pub fn contains_recursive(&self) -> bool {
+        self.selectors.iter().any(|s| {
+            matches!(
+                s,
+                Selector::RecursiveWildcard
+                    | Selector::RecursiveKey(_)
+                    | Selector::RecursiveIndex(_)
+                    | Selector::RecursiveFilter(_)
+            )
+        })
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Selector {
     Wildcard,
     NameChild(String),
+    NameUnion(Vec<String>),   // two or more names (separated by comma)
+    DeepName(Vec<String>),    // chain of names, ["a", "b"] is .a.b (built in code only)
     ArrayIndex(usize),        // one unique index
     ArrayIndices(Vec<usize>), // two or more indexes (separated by comma)
     ArraySlice(Slice),
     ArrayWildcard,
-    Filter(Predicate),
+    Flatten,       // elements of the nested arrays, one level deep
+    Stride(usize), // every nth element of an array
+    Filter(FilterExpr),
     RecursiveWildcard,
     RecursiveKey(String),
+    RecursiveIndex(usize), // element at this index of every nested array
+    RecursiveFilter(FilterExpr),
+    Keys,
+    Aggregate(Aggregate), // reduces the whole collection to a single number
+    Distinct,             // the whole collection without its duplicated values
+    Join(String),         // the whole collection joined to a single string with this separator
+}
+
+impl Selector {
+    /// Returns the kind of this selector, to describe it in messages.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Selector::Wildcard | Selector::ArrayWildcard => "wildcard",
+            Selector::NameChild(_) => "name",
+            Selector::NameUnion(_) => "name union",
+            Selector::DeepName(_) => "deep name",
+            Selector::ArrayIndex(_) => "array index",
+            Selector::ArrayIndices(_) => "array indices",
+            Selector::ArraySlice(_) => "array slice",
+            Selector::Stride(_) => "stride",
+            Selector::Flatten => "flatten",
+            Selector::Filter(_) => "filter",
+            Selector::RecursiveWildcard => "recursive wildcard",
+            Selector::RecursiveKey(_) => "recursive name",
+            Selector::RecursiveIndex(_) => "recursive index",
+            Selector::RecursiveFilter(_) => "recursive filter",
+            Selector::Keys => "keys",
+            Selector::Aggregate(_) => "aggregate",
+            Selector::Distinct => "distinct",
+            Selector::Join(_) => "join",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Aggregate {
+    Min,
+    Max,
+    Sum,
+    Avg,
 }
 
-// For the time-being
-// use simple slice start:end (without the step)
+// Slice start:end:step, the step defaults to 1 and is never 0
+// Same semantics as Python slices: [::-1] reverses the array
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Slice {
     pub start: Option<i64>,
     pub end: Option<i64>,
+    pub step: Option<i64>,
+}
+
+// Filter expression, predicates combined with && and || (&& binds tighter than ||)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FilterExpr {
+    Predicate(Predicate),
+    And(Box<FilterExpr>, Box<FilterExpr>),
+    Or(Box<FilterExpr>, Box<FilterExpr>),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -54,17 +139,64 @@ pub struct Predicate {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum PredicateFunc {
     KeyExist {},
+    HasKey(String), // the element is an object with this top-level key
     EqualString(String),
+    NotEqualString(String),
+    GreaterThanString(String), // strings are compared lexicographically
+    GreaterThanOrEqualString(String),
+    LessThanString(String),
+    LessThanOrEqualString(String),
+    EqualBool(bool),
+    EqualNull {},
     Equal(Number),
+    NotEqual(Number),
     GreaterThan(Number),
     GreaterThanOrEqual(Number),
     LessThan(Number),
     LessThanOrEqual(Number),
+    Match(Regex),
+    NotMatch(Regex),
+    TypeIs(JsonType),                      // the JSON type of the value
+    Contains(String), // substring of a string, or string element of an array (case-sensitive)
+    CompareField(Comparison, Vec<String>), // numbers compared to another field, like @.a<@.b
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JsonType {
+    Object,
+    Array,
+    String,
+    Number,
+    Boolean,
+    Null,
+}
+
+#[derive(Clone, Debug)]
+pub struct Regex {
+    pub inner: regex::Regex,
+}
+
+impl PartialEq for Regex {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.as_str() == other.inner.as_str()
+    }
+}
+impl Eq for Regex {}
+
 // Number
 // - without rounding
 // - Equalable
+// - int + decimal / 10^18, the decimal part being always positive (-1.5 is int -2, decimal 0.5)
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Number {
     pub int: i64,
@@ -81,6 +213,31 @@ impl Number {
 mod tests {
     use super::*;
 
+    #[test]
+    pub fn test_query_depth() {
+        // $.a.b
+        let query = Query {
+            selectors: vec![
+                Selector::NameChild("a".to_string()),
+                Selector::NameChild("b".to_string()),
+            ],
+        };
+        assert_eq!(query.depth(), 2);
+        assert!(!query.contains_recursive());
+
+        // $..a
+        let query = Query {
+            selectors: vec![Selector::RecursiveKey("a".to_string())],
+        };
+        assert_eq!(query.depth(), 1);
+        assert!(query.contains_recursive());
+
+        // $
+        let query = Query { selectors: vec![] };
+        assert_eq!(query.depth(), 0);
+        assert!(!query.contains_recursive());
+    }
+
     #[test]
     pub fn test_number() {
         assert!((Number { int: 1, decimal: 0 }.to_f64() - 1.0).abs() < 0.0000001);
@@ -0,0 +1,107 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::jsonpath::ast::Selector;
+use crate::jsonpath::eval::query::eval_selector_ref;
+use crate::jsonpath::eval::JsonpathResultRef;
+
+/// Memoized results of selectors applied to the values of JSON documents, used by
+/// [`crate::jsonpath::Query::eval_cached`].
+///
+/// Results are keyed by selector and by address of the value they are applied to: the
+/// documents are borrowed for the lifetime `'a` of the cache, so their values can not be moved
+/// or modified while cached. Values computed by a selector (like the keys of an object) are
+/// not cached.
+#[derive(Debug, Default)]
+pub struct QueryCache<'a> {
+    results: HashMap<*const serde_json::Value, Vec<(Selector, Option<JsonpathResultRef<'a>>)>>,
+    hits: usize,
+}
+
+impl<'a> QueryCache<'a> {
+    /// Creates a new empty cache.
+    pub fn new() -> QueryCache<'a> {
+        QueryCache::default()
+    }
+
+    /// Returns the number of selector results that have been read from this cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Evaluates `selector` on `value`, reading the result from the cache if it has already
+    /// been computed for this value.
+    pub(crate) fn eval(
+        &mut self,
+        selector: &Selector,
+        value: Cow<'a, serde_json::Value>,
+    ) -> Option<JsonpathResultRef<'a>> {
+        let Cow::Borrowed(value) = value else {
+            return eval_selector_ref(selector, value);
+        };
+        let results = self.results.entry(value as *const _).or_default();
+        if let Some((_, result)) = results.iter().find(|(s, _)| s == selector) {
+            self.hits += 1;
+            return result.clone();
+        }
+        let result = selector.eval_ref(value);
+        results.push((selector.clone(), result.clone()));
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::jsonpath;
+
+    #[test]
+    fn test_eval_cached() {
+        let value = json!({
+            "store": {
+                "book": [
+                    {"author": "Nigel Rees", "price": 8.95},
+                    {"author": "Evelyn Waugh", "price": 12.99},
+                    {"author": "Herman Melville", "price": 8.99}
+                ],
+                "bicycle": {"color": "red", "price": 19.95}
+            }
+        });
+        let mut cache = QueryCache::new();
+        for expr in [
+            "$..author",
+            "$.store.book[?(@.price<10)].author",
+            "$.store.*",
+            "$..price",
+            "$.store.book[0].isbn",
+        ] {
+            let query = jsonpath::parse(expr).unwrap();
+            assert_eq!(query.eval_cached(&value, &mut cache), query.eval(&value));
+        }
+
+        let hits = cache.hits();
+        let query = jsonpath::parse("$..author").unwrap();
+        assert_eq!(query.eval_cached(&value, &mut cache), query.eval(&value));
+        assert_eq!(cache.hits(), hits + 1);
+    }
+}
@@ -16,11 +16,131 @@
  *
  */
 
+use std::borrow::Cow;
+
+mod cache;
 pub mod query;
 mod selector;
 
+pub use self::cache::QueryCache;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum JsonpathResult {
     SingleEntry(serde_json::Value),     // returned by a "definite" path
     Collection(Vec<serde_json::Value>), // returned by a "indefinite" path
 }
+
+impl JsonpathResult {
+    /// Returns this result without duplicated values, keeping the first occurrence of each value.
+    ///
+    /// A recursive or union selector can select the same JSON value many times. A single entry
+    /// is returned unchanged.
+    pub fn dedup(self) -> JsonpathResult {
+        match self {
+            JsonpathResult::SingleEntry(_) => self,
+            JsonpathResult::Collection(values) => JsonpathResult::Collection(distinct(values)),
+        }
+    }
+
+    /// Returns this result with a single level of values, the arrays of a collection being
+    /// merged (at any depth) into the collection itself.
+    ///
+    /// A single entry is returned unchanged.
+    pub fn flatten(self) -> JsonpathResult {
+        match self {
+            JsonpathResult::SingleEntry(_) => self,
+            JsonpathResult::Collection(values) => {
+                let mut elements = vec![];
+                flatten_values(values, &mut elements);
+                JsonpathResult::Collection(elements)
+            }
+        }
+    }
+}
+
+/// Appends to `elements` the `values`, replacing each array by its own flattened values.
+fn flatten_values(values: Vec<serde_json::Value>, elements: &mut Vec<serde_json::Value>) {
+    for value in values {
+        match value {
+            serde_json::Value::Array(values) => flatten_values(values, elements),
+            _ => elements.push(value),
+        }
+    }
+}
+
+/// A [`JsonpathResult`] borrowing its values from the evaluated JSON document.
+///
+/// Values that are computed rather than selected (like the keys of an object, or an aggregate)
+/// can not be borrowed, and are owned.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JsonpathResultRef<'a> {
+    SingleEntry(Cow<'a, serde_json::Value>),
+    Collection(Vec<Cow<'a, serde_json::Value>>),
+}
+
+impl JsonpathResultRef<'_> {
+    /// Returns the owned result, cloning the borrowed values.
+    pub fn into_owned(self) -> JsonpathResult {
+        match self {
+            JsonpathResultRef::SingleEntry(value) => {
+                JsonpathResult::SingleEntry(value.into_owned())
+            }
+            JsonpathResultRef::Collection(values) => {
+                JsonpathResult::Collection(values.into_iter().map(Cow::into_owned).collect())
+            }
+        }
+    }
+}
+
+impl From<JsonpathResult> for JsonpathResultRef<'_> {
+    fn from(result: JsonpathResult) -> Self {
+        match result {
+            JsonpathResult::SingleEntry(value) => JsonpathResultRef::SingleEntry(Cow::Owned(value)),
+            JsonpathResult::Collection(values) => {
+                JsonpathResultRef::Collection(values.into_iter().map(Cow::Owned).collect())
+            }
+        }
+    }
+}
+
+/// Returns `values` without duplicated values, keeping the first occurrence of each value.
+pub(crate) fn distinct<T: PartialEq>(values: impl IntoIterator<Item = T>) -> Vec<T> {
+    let mut elements = vec![];
+    for value in values {
+        if !elements.contains(&value) {
+            elements.push(value);
+        }
+    }
+    elements
+}
+
+/// Joins `values` to a single JSON string with `separator`.
+///
+/// Strings are joined as is, the other values with their JSON representation.
+pub(crate) fn join<'a>(
+    separator: &str,
+    values: impl IntoIterator<Item = &'a serde_json::Value>,
+) -> serde_json::Value {
+    let strings = values
+        .into_iter()
+        .map(|value| match value {
+            serde_json::Value::String(s) => s.clone(),
+            _ => value.to_string(),
+        })
+        .collect::<Vec<String>>();
+    serde_json::Value::String(strings.join(separator))
+}
+
+/// Errors raised by [`crate::jsonpath::Query::try_eval`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JsonpathError {
+    /// The selector at `selector_index` (starting at 0), of kind `selector`, expects a JSON value
+    /// of type `expected`, but is applied to a JSON value of type `found`, for instance an array
+    /// index applied to an object.
+    TypeMismatch {
+        selector_index: usize,
+        selector: String,
+        expected: String,
+        found: String,
+    },
+}
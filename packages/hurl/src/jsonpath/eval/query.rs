@@ -16,42 +16,178 @@
  *
  */
 
-use crate::jsonpath::ast::Query;
+use std::borrow::Cow;
+
+use crate::jsonpath::ast::{Query, Selector};
+use crate::jsonpath::eval::{distinct, join, JsonpathError, JsonpathResultRef, QueryCache};
 use crate::jsonpath::JsonpathResult;
 
 impl Query {
     /// Eval a JSONPath `Query` for a `serde_json::Value` input.
     /// It returns an Option<`JsonResultPath`>.
+    ///
+    /// The query is evaluated with [`Query::eval_ref`], values being cloned only once at the end.
+    /// `None` is returned both when nothing matches and when a selector is applied to a value
+    /// of the wrong type, use [`Query::try_eval`] to tell them apart.
     pub fn eval(&self, value: &serde_json::Value) -> Option<JsonpathResult> {
-        let mut result = JsonpathResult::SingleEntry(value.clone());
-        for selector in &self.selectors {
-            match result.clone() {
-                JsonpathResult::SingleEntry(value) => {
-                    result = selector.eval(&value)?;
-                }
-                JsonpathResult::Collection(values) => {
-                    let mut elements = vec![];
-                    for value in values {
-                        match selector.eval(&value)? {
-                            JsonpathResult::SingleEntry(new_value) => {
-                                elements.push(new_value);
-                            }
-                            JsonpathResult::Collection(mut new_values) => {
-                                elements.append(&mut new_values);
+        self.eval_ref(value).map(JsonpathResultRef::into_owned)
+    }
+
+    /// Eval a JSONPath `Query` for a `serde_json::Value` input, borrowing the selected values
+    /// from `value` instead of cloning them at each selector.
+    ///
+    /// The result is the same as [`Query::eval`], without the cost of copying large documents.
+    pub fn eval_ref<'a>(&self, value: &'a serde_json::Value) -> Option<JsonpathResultRef<'a>> {
+        self.eval_with(value, |_, selector, value| {
+            Ok(eval_selector_ref(selector, value))
+        })
+        .ok()
+        .flatten()
+    }
+
+    /// Eval a JSONPath `Query` for a `serde_json::Value` input, reusing the selector results
+    /// memoized in `cache` by previous queries on the same document.
+    ///
+    /// The result is the same as [`Query::eval`].
+    pub fn eval_cached<'a>(
+        &self,
+        value: &'a serde_json::Value,
+        cache: &mut QueryCache<'a>,
+    ) -> Option<JsonpathResult> {
+        self.eval_with(value, |_, selector, value| Ok(cache.eval(selector, value)))
+            .ok()
+            .flatten()
+            .map(JsonpathResultRef::into_owned)
+    }
+
+    /// Eval a JSONPath `Query` for a `serde_json::Value` input, failing on the first selector
+    /// that can not be applied to the JSON value it selects from.
+    ///
+    /// Contrary to [`Query::eval`], a type mismatch (like an array index applied to an object)
+    /// is reported as a [`JsonpathError::TypeMismatch`] instead of no result.
+    pub fn try_eval(
+        &self,
+        value: &serde_json::Value,
+    ) -> Result<Option<JsonpathResult>, JsonpathError> {
+        let result = self.eval_with(value, |selector_index, selector, value| {
+            if let Some(expected) = selector.expected_type(&value) {
+                return Err(JsonpathError::TypeMismatch {
+                    selector_index,
+                    selector: selector.kind().to_string(),
+                    expected: expected.to_string(),
+                    found: json_type(&value).to_string(),
+                });
+            }
+            Ok(eval_selector_ref(selector, value))
+        })?;
+        Ok(result.map(JsonpathResultRef::into_owned))
+    }
+
+    /// Eval a JSONPath `Query` for a `serde_json::Value` input, each selector being applied to
+    /// a value with `eval_selector`, given the index of the selector in the query.
+    ///
+    /// The evaluation stops at the first error returned by `eval_selector`.
+    fn eval_with<'a>(
+        &self,
+        value: &'a serde_json::Value,
+        mut eval_selector: impl FnMut(
+            usize,
+            &Selector,
+            Cow<'a, serde_json::Value>,
+        ) -> Result<Option<JsonpathResultRef<'a>>, JsonpathError>,
+    ) -> Result<Option<JsonpathResultRef<'a>>, JsonpathError> {
+        let mut result = JsonpathResultRef::SingleEntry(Cow::Borrowed(value));
+        for (selector_index, selector) in self.selectors.iter().enumerate() {
+            result = match result {
+                // An aggregate (or distinct) applies to the whole collection, not to each of its
+                // elements.
+                JsonpathResultRef::Collection(values) => match selector {
+                    Selector::Aggregate(aggregate) => {
+                        match aggregate.eval(values.iter().map(|value| value.as_ref())) {
+                            Some(value) => JsonpathResultRef::SingleEntry(Cow::Owned(value)),
+                            None => return Ok(None),
+                        }
+                    }
+                    Selector::Distinct => JsonpathResultRef::Collection(distinct(values)),
+                    Selector::Join(separator) => JsonpathResultRef::SingleEntry(Cow::Owned(join(
+                        separator,
+                        values.iter().map(|value| value.as_ref()),
+                    ))),
+                    _ => {
+                        let mut elements = vec![];
+                        for value in values {
+                            match eval_selector(selector_index, selector, value)? {
+                                Some(JsonpathResultRef::SingleEntry(new_value)) => {
+                                    elements.push(new_value);
+                                }
+                                Some(JsonpathResultRef::Collection(mut new_values)) => {
+                                    elements.append(&mut new_values);
+                                }
+                                None => return Ok(None),
                             }
                         }
-                        result = JsonpathResult::Collection(elements.clone());
+                        JsonpathResultRef::Collection(elements)
                     }
+                },
+                JsonpathResultRef::SingleEntry(value) => {
+                    match eval_selector(selector_index, selector, value)? {
+                        Some(result) => result,
+                        None => return Ok(None),
+                    }
+                }
+            };
+        }
+        Ok(Some(result))
+    }
+
+    /// Eval a JSONPath `Query` for a `serde_json::Value` input, returning the normalized path
+    /// of each selected value instead of the value itself, like `$['store']['book'][0]['title']`.
+    ///
+    /// Names are written in bracket notation with single quotes, indexes as numbers. Queries
+    /// computing values (with keys or an aggregate) have no paths and return `None`.
+    pub fn eval_paths(&self, value: &serde_json::Value) -> Option<Vec<String>> {
+        let mut nodes = vec![("$".to_string(), value)];
+        for selector in &self.selectors {
+            let mut elements = vec![];
+            for (path, value) in nodes {
+                for (child_path, child) in selector.eval_paths(value)? {
+                    elements.push((format!("{path}{child_path}"), child));
                 }
             }
+            nodes = elements;
         }
-        Some(result)
+        Some(nodes.into_iter().map(|(path, _)| path).collect())
+    }
+}
+
+/// Evaluates `selector` on `value`, borrowing from the document when `value` is borrowed.
+///
+/// Owned values have been computed by a previous selector, and can only give owned results.
+pub(crate) fn eval_selector_ref<'a>(
+    selector: &Selector,
+    value: Cow<'a, serde_json::Value>,
+) -> Option<JsonpathResultRef<'a>> {
+    match value {
+        Cow::Borrowed(value) => selector.eval_ref(value),
+        Cow::Owned(value) => selector.eval(&value).map(JsonpathResultRef::from),
+    }
+}
+
+/// Returns the type name of a JSON `value`.
+fn json_type(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::jsonpath::ast::{Number, Predicate, PredicateFunc, Query, Selector};
+    use crate::jsonpath::ast::{FilterExpr, Number, Predicate, PredicateFunc, Query, Selector};
     use crate::jsonpath::JsonpathResult;
     use serde_json::json;
 
@@ -148,13 +284,13 @@ mod tests {
             selectors: vec![
                 Selector::NameChild("store".to_string()),
                 Selector::NameChild("book".to_string()),
-                Selector::Filter(Predicate {
+                Selector::Filter(FilterExpr::Predicate(Predicate {
                     key: vec!["price".to_string()],
                     func: PredicateFunc::LessThan(Number {
                         int: 10,
                         decimal: 0,
                     }),
-                }),
+                })),
                 Selector::NameChild("title".to_string()),
             ],
         };
@@ -16,191 +16,721 @@
  *
  */
 
-use crate::jsonpath::ast::{Predicate, PredicateFunc, Selector, Slice};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+use crate::jsonpath::ast::{
+    Aggregate, Comparison, FilterExpr, JsonType, Number, Predicate, PredicateFunc, Selector, Slice,
+};
+use crate::jsonpath::eval::{distinct, join, JsonpathResultRef};
 use crate::jsonpath::JsonpathResult;
 use float_cmp::approx_eq;
 
 impl Selector {
     pub fn eval(&self, root: &serde_json::Value) -> Option<JsonpathResult> {
+        self.eval_ref(root).map(JsonpathResultRef::into_owned)
+    }
+
+    /// Eval this selector for a `serde_json::Value` input, borrowing the selected values from
+    /// `root` instead of cloning them.
+    pub fn eval_ref<'a>(&self, root: &'a serde_json::Value) -> Option<JsonpathResultRef<'a>> {
         match self {
             // Selectors returning single JSON node ("finite")
             Selector::NameChild(field) => root
                 .get(field)
-                .map(|result| JsonpathResult::SingleEntry(result.clone())),
+                .map(|result| JsonpathResultRef::SingleEntry(Cow::Borrowed(result))),
+            Selector::DeepName(fields) => fields
+                .iter()
+                .try_fold(root, |value, field| value.get(field))
+                .map(|result| JsonpathResultRef::SingleEntry(Cow::Borrowed(result))),
             Selector::ArrayIndex(index) => root
                 .get(index)
-                .map(|result| JsonpathResult::SingleEntry(result.clone())),
+                .map(|result| JsonpathResultRef::SingleEntry(Cow::Borrowed(result))),
 
             // Selectors returning a collection ("indefinite")
-            Selector::Wildcard | Selector::ArrayWildcard => {
-                let mut elements = vec![];
-                if let serde_json::Value::Array(values) = root {
-                    for value in values {
-                        elements.push(value.clone());
-                    }
-                } else if let serde_json::Value::Object(key_values) = root {
-                    for value in key_values.values() {
-                        elements.push(value.clone());
+            // Missing names are skipped, the values follow the order of the names.
+            Selector::NameUnion(fields) => Some(JsonpathResultRef::Collection(
+                fields
+                    .iter()
+                    .filter_map(|field| root.get(field))
+                    .map(Cow::Borrowed)
+                    .collect(),
+            )),
+            // A wildcard on a scalar selects nothing.
+            Selector::Wildcard => {
+                // Object values are selected in document order, serde_json being built with
+                // its `preserve_order` feature.
+                let elements = match root {
+                    serde_json::Value::Array(values) => values.iter().map(Cow::Borrowed).collect(),
+                    serde_json::Value::Object(key_values) => {
+                        key_values.values().map(Cow::Borrowed).collect()
                     }
-                }
-                Some(JsonpathResult::Collection(elements))
+                    _ => vec![],
+                };
+                Some(JsonpathResultRef::Collection(elements))
             }
-            Selector::ArraySlice(Slice { start, end }) => {
-                let mut elements = vec![];
-                if let serde_json::Value::Array(values) = root {
-                    for (i, value) in values.iter().enumerate() {
-                        if let Some(n) = start {
-                            let n = if *n < 0 { values.len() as i64 + n } else { *n };
-                            if (i as i64) < n {
-                                continue;
-                            }
-                        }
-                        if let Some(n) = end {
-                            let n = if *n < 0 { values.len() as i64 + n } else { *n };
-                            if (i as i64) >= n {
-                                continue;
-                            }
-                        }
-                        elements.push(value.clone());
-                    }
-                }
-                Some(JsonpathResult::Collection(elements))
+            // Contrary to `Wildcard`, the values of an object are not selected.
+            Selector::ArrayWildcard => {
+                let elements = match root {
+                    serde_json::Value::Array(values) => values.iter().map(Cow::Borrowed).collect(),
+                    _ => vec![],
+                };
+                Some(JsonpathResultRef::Collection(elements))
+            }
+            Selector::ArraySlice(slice) => {
+                let elements = match root {
+                    serde_json::Value::Array(values) => slice
+                        .indexes(values.len())
+                        .into_iter()
+                        .map(|i| Cow::Borrowed(&values[i]))
+                        .collect(),
+                    _ => vec![],
+                };
+                Some(JsonpathResultRef::Collection(elements))
             }
             Selector::RecursiveKey(key) => {
                 let mut elements = vec![];
-                match root {
-                    serde_json::Value::Object(ref obj) => {
-                        if let Some(elem) = obj.get(key.as_str()) {
-                            elements.push(elem.clone());
-                        }
-                        for value in obj.values() {
-                            if let Some(JsonpathResult::Collection(mut values)) =
-                                Selector::RecursiveKey(key.clone()).eval(value)
-                            {
-                                elements.append(&mut values);
-                            }
-                        }
-                    }
-                    serde_json::Value::Array(values) => {
-                        for value in values {
-                            if let Some(JsonpathResult::Collection(mut values)) =
-                                Selector::RecursiveKey(key.clone()).eval(value)
-                            {
-                                elements.append(&mut values);
-                            }
-                        }
-                    }
-                    _ => {}
-                }
-                Some(JsonpathResult::Collection(elements))
+                recursive_key(root, key, &mut elements);
+                Some(JsonpathResultRef::Collection(
+                    elements.into_iter().map(Cow::Borrowed).collect(),
+                ))
+            }
+            Selector::RecursiveIndex(index) => {
+                // The root and all its descendants are searched, like for a recursive key.
+                let mut elements = vec![root];
+                recursive_wildcard(root, &mut elements);
+                Some(JsonpathResultRef::Collection(
+                    elements
+                        .into_iter()
+                        .filter_map(|value| match value {
+                            serde_json::Value::Array(values) => values.get(*index),
+                            _ => None,
+                        })
+                        .map(Cow::Borrowed)
+                        .collect(),
+                ))
             }
             Selector::RecursiveWildcard => {
                 let mut elements = vec![];
-                match root {
-                    serde_json::Value::Object(map) => {
-                        for elem in map.values() {
-                            elements.push(elem.clone());
-                            if let Some(JsonpathResult::Collection(mut values)) =
-                                Selector::RecursiveWildcard.eval(elem)
-                            {
-                                elements.append(&mut values);
-                            }
-                        }
-                    }
-                    serde_json::Value::Array(values) => {
-                        for elem in values {
-                            elements.push(elem.clone());
-                            if let Some(JsonpathResult::Collection(mut values)) =
-                                Selector::RecursiveWildcard.eval(elem)
-                            {
-                                elements.append(&mut values);
-                            }
-                        }
-                    }
-                    _ => {}
-                }
-                Some(JsonpathResult::Collection(elements))
+                recursive_wildcard(root, &mut elements);
+                Some(JsonpathResultRef::Collection(
+                    elements.into_iter().map(Cow::Borrowed).collect(),
+                ))
             }
-            Selector::Filter(predicate) => {
+            Selector::RecursiveFilter(filter) => {
+                // The filter is applied to every node below the root, at any depth.
+                let mut elements = vec![];
+                recursive_wildcard(root, &mut elements);
+                let elements = elements
+                    .into_iter()
+                    .filter(|&e| filter.eval(e))
+                    .map(Cow::Borrowed)
+                    .collect();
+                Some(JsonpathResultRef::Collection(elements))
+            }
+            Selector::Filter(filter) => {
                 let elements = match root {
                     serde_json::Value::Array(elements) => elements
                         .iter()
-                        .filter(|&e| predicate.eval(e.clone()))
-                        .cloned()
+                        .filter(|&e| filter.eval(e))
+                        .map(Cow::Borrowed)
+                        .collect(),
+                    // The values of an object are filtered like the elements of an array.
+                    serde_json::Value::Object(map) => map
+                        .values()
+                        .filter(|&e| filter.eval(e))
+                        .map(Cow::Borrowed)
+                        .collect(),
+                    _ => vec![],
+                };
+                Some(JsonpathResultRef::Collection(elements))
+            }
+            Selector::Stride(n) => {
+                let elements = match root {
+                    serde_json::Value::Array(values) if *n > 0 => {
+                        values.iter().step_by(*n).map(Cow::Borrowed).collect()
+                    }
+                    _ => vec![],
+                };
+                Some(JsonpathResultRef::Collection(elements))
+            }
+            Selector::Flatten => {
+                // Only one level of nesting is removed, non-array elements are kept as is.
+                let elements = match root {
+                    serde_json::Value::Array(values) => values
+                        .iter()
+                        .flat_map(|value| match value {
+                            serde_json::Value::Array(values) => values.iter().collect(),
+                            _ => vec![value],
+                        })
+                        .map(Cow::Borrowed)
                         .collect(),
                     _ => vec![],
                 };
-                Some(JsonpathResult::Collection(elements))
+                Some(JsonpathResultRef::Collection(elements))
             }
+            Selector::Keys => {
+                // Keys are sorted so that the result does not depend on the object ordering.
+                let elements = match root {
+                    serde_json::Value::Object(map) => {
+                        let mut keys = map.keys().cloned().collect::<Vec<String>>();
+                        keys.sort();
+                        keys.into_iter().map(serde_json::Value::String).collect()
+                    }
+                    serde_json::Value::Array(values) => {
+                        (0..values.len()).map(serde_json::Value::from).collect()
+                    }
+                    _ => return None,
+                };
+                Some(JsonpathResult::Collection(elements).into())
+            }
+            Selector::Aggregate(aggregate) => match root {
+                serde_json::Value::Array(values) => {
+                    Some(JsonpathResult::SingleEntry(aggregate.eval(values)?).into())
+                }
+                _ => None,
+            },
+            Selector::Distinct => match root {
+                serde_json::Value::Array(values) => Some(JsonpathResultRef::Collection(distinct(
+                    values.iter().map(Cow::Borrowed),
+                ))),
+                _ => None,
+            },
+            Selector::Join(separator) => match root {
+                serde_json::Value::Array(values) => {
+                    Some(JsonpathResult::SingleEntry(join(separator, values)).into())
+                }
+                _ => None,
+            },
             Selector::ArrayIndices(indexes) => {
-                let mut values = vec![];
-                for index in indexes {
-                    if let Some(value) = root.get(index) {
-                        values.push(value.clone())
+                let elements = indexes
+                    .iter()
+                    .filter_map(|index| root.get(index))
+                    .map(Cow::Borrowed)
+                    .collect();
+                Some(JsonpathResultRef::Collection(elements))
+            }
+        }
+    }
+
+    /// Eval this selector for a `serde_json::Value` input, returning each selected value with
+    /// its normalized path relative to `root`, like `['book'][0]`.
+    ///
+    /// Values that are computed rather than selected (like the keys of an object, or an
+    /// aggregate) have no location in the document and give no result.
+    pub fn eval_paths<'a>(
+        &self,
+        root: &'a serde_json::Value,
+    ) -> Option<Vec<(String, &'a serde_json::Value)>> {
+        let nodes = match self {
+            Selector::NameChild(field) => vec![(name_path(field), root.get(field)?)],
+            Selector::DeepName(fields) => {
+                let mut path = String::new();
+                let mut value = root;
+                for field in fields {
+                    path.push_str(&name_path(field));
+                    value = value.get(field)?;
+                }
+                vec![(path, value)]
+            }
+            Selector::ArrayIndex(index) => vec![(index_path(*index), root.get(index)?)],
+            Selector::NameUnion(fields) => fields
+                .iter()
+                .filter_map(|field| Some((name_path(field), root.get(field)?)))
+                .collect(),
+            Selector::Wildcard => children(root),
+            Selector::ArrayWildcard => match root {
+                serde_json::Value::Array(_) => children(root),
+                _ => vec![],
+            },
+            Selector::ArraySlice(slice) => match root {
+                serde_json::Value::Array(values) => slice
+                    .indexes(values.len())
+                    .into_iter()
+                    .map(|i| (index_path(i), &values[i]))
+                    .collect(),
+                _ => vec![],
+            },
+            Selector::RecursiveKey(key) => {
+                let mut nodes = vec![];
+                recursive_wildcard_paths(root, "", &mut nodes);
+                let mut elements = root
+                    .get(key)
+                    .map(|value| vec![(name_path(key), value)])
+                    .unwrap_or_default();
+                for (path, value) in nodes {
+                    if let Some(value) = value.get(key) {
+                        elements.push((format!("{path}{}", name_path(key)), value));
                     }
                 }
-                Some(JsonpathResult::Collection(values))
+                elements
+            }
+            Selector::RecursiveIndex(index) => {
+                let mut nodes = vec![(String::new(), root)];
+                recursive_wildcard_paths(root, "", &mut nodes);
+                nodes
+                    .into_iter()
+                    .filter_map(|(path, value)| match value {
+                        serde_json::Value::Array(values) => {
+                            Some((format!("{path}{}", index_path(*index)), values.get(*index)?))
+                        }
+                        _ => None,
+                    })
+                    .collect()
+            }
+            Selector::RecursiveWildcard => {
+                let mut nodes = vec![];
+                recursive_wildcard_paths(root, "", &mut nodes);
+                nodes
+            }
+            Selector::RecursiveFilter(filter) => {
+                let mut nodes = vec![];
+                recursive_wildcard_paths(root, "", &mut nodes);
+                nodes.retain(|(_, e)| filter.eval(e));
+                nodes
+            }
+            Selector::Filter(filter) => {
+                let mut nodes = children(root);
+                nodes.retain(|(_, e)| filter.eval(e));
+                nodes
+            }
+            Selector::Stride(n) => match root {
+                serde_json::Value::Array(values) if *n > 0 => values
+                    .iter()
+                    .enumerate()
+                    .step_by(*n)
+                    .map(|(i, value)| (index_path(i), value))
+                    .collect(),
+                _ => vec![],
+            },
+            Selector::Flatten => match root {
+                serde_json::Value::Array(values) => values
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, value)| match value {
+                        serde_json::Value::Array(values) => values
+                            .iter()
+                            .enumerate()
+                            .map(|(j, value)| {
+                                (format!("{}{}", index_path(i), index_path(j)), value)
+                            })
+                            .collect(),
+                        _ => vec![(index_path(i), value)],
+                    })
+                    .collect(),
+                _ => vec![],
+            },
+            Selector::Keys | Selector::Aggregate(_) | Selector::Distinct | Selector::Join(_) => {
+                return None
+            }
+            Selector::ArrayIndices(indexes) => indexes
+                .iter()
+                .filter_map(|index| Some((index_path(*index), root.get(index)?)))
+                .collect(),
+        };
+        Some(nodes)
+    }
+}
+
+/// Appends to `elements` the values of `key` in `value` and in all its descendants.
+fn recursive_key<'a>(
+    value: &'a serde_json::Value,
+    key: &str,
+    elements: &mut Vec<&'a serde_json::Value>,
+) {
+    match value {
+        serde_json::Value::Object(obj) => {
+            if let Some(elem) = obj.get(key) {
+                elements.push(elem);
+            }
+            for value in obj.values() {
+                recursive_key(value, key, elements);
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                recursive_key(value, key, elements);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Appends to `elements` all the descendants of `value`, each node followed by its own descendants.
+fn recursive_wildcard<'a>(value: &'a serde_json::Value, elements: &mut Vec<&'a serde_json::Value>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for elem in map.values() {
+                elements.push(elem);
+                recursive_wildcard(elem, elements);
             }
         }
+        serde_json::Value::Array(values) => {
+            for elem in values {
+                elements.push(elem);
+                recursive_wildcard(elem, elements);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the normalized path segment of an object member `name`, like `['title']`.
+fn name_path(name: &str) -> String {
+    let name = name.replace('\\', "\\\\").replace('\'', "\\'");
+    format!("['{name}']")
+}
+
+/// Returns the normalized path segment of an array `index`, like `[0]`.
+fn index_path(index: usize) -> String {
+    format!("[{index}]")
+}
+
+/// Returns the direct children of `value` (array elements or object values) with their path.
+fn children(value: &serde_json::Value) -> Vec<(String, &serde_json::Value)> {
+    match value {
+        serde_json::Value::Array(values) => values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (index_path(i), value))
+            .collect(),
+        serde_json::Value::Object(map) => map
+            .iter()
+            .map(|(name, value)| (name_path(name), value))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Appends to `nodes` all the descendants of `value` with their path, prefixed by `path`,
+/// each node followed by its own descendants.
+fn recursive_wildcard_paths<'a>(
+    value: &'a serde_json::Value,
+    path: &str,
+    nodes: &mut Vec<(String, &'a serde_json::Value)>,
+) {
+    for (child_path, child) in children(value) {
+        let child_path = format!("{path}{child_path}");
+        nodes.push((child_path.clone(), child));
+        recursive_wildcard_paths(child, &child_path, nodes);
+    }
+}
+
+impl Selector {
+    /// Returns the type of JSON value expected by this selector if it can not be applied
+    /// to `value`, `None` otherwise.
+    pub fn expected_type(&self, value: &serde_json::Value) -> Option<&'static str> {
+        match (self, value) {
+            (
+                Selector::NameChild(_) | Selector::NameUnion(_) | Selector::DeepName(_),
+                serde_json::Value::Object(_),
+            ) => None,
+            (Selector::NameChild(_) | Selector::NameUnion(_) | Selector::DeepName(_), _) => {
+                Some("object")
+            }
+            (
+                Selector::ArrayIndex(_)
+                | Selector::ArrayIndices(_)
+                | Selector::ArraySlice(_)
+                | Selector::ArrayWildcard
+                | Selector::Stride(_)
+                | Selector::Flatten
+                | Selector::Aggregate(_)
+                | Selector::Distinct
+                | Selector::Join(_),
+                serde_json::Value::Array(_),
+            ) => None,
+            (
+                Selector::ArrayIndex(_)
+                | Selector::ArrayIndices(_)
+                | Selector::ArraySlice(_)
+                | Selector::ArrayWildcard
+                | Selector::Stride(_)
+                | Selector::Flatten
+                | Selector::Aggregate(_)
+                | Selector::Distinct
+                | Selector::Join(_),
+                _,
+            ) => Some("array"),
+            (Selector::Filter(_), serde_json::Value::Object(_) | serde_json::Value::Array(_)) => {
+                None
+            }
+            (Selector::Filter(_), _) => Some("object or array"),
+            (Selector::Keys, serde_json::Value::Object(_) | serde_json::Value::Array(_)) => None,
+            (Selector::Keys, _) => Some("object or array"),
+            _ => None,
+        }
+    }
+}
+
+impl Slice {
+    /// Returns the indexes selected by this slice in an array of `len` elements, in order.
+    ///
+    /// Negative bounds count from the end of the array, and a negative step iterates backward
+    /// from `start` (the last element by default) down to `end` (excluded).
+    fn indexes(&self, len: usize) -> Vec<usize> {
+        let len = len as i64;
+        let step = self.step.unwrap_or(1);
+        let normalize = |n: i64| if n < 0 { len + n } else { n };
+        let mut indexes = vec![];
+        if step > 0 {
+            let lower = normalize(self.start.unwrap_or(0)).clamp(0, len);
+            let upper = normalize(self.end.unwrap_or(len)).clamp(0, len);
+            let mut i = lower;
+            while i < upper {
+                indexes.push(i as usize);
+                i += step;
+            }
+        } else if step < 0 {
+            let upper = match self.start {
+                Some(n) => normalize(n).clamp(-1, len - 1),
+                None => len - 1,
+            };
+            let lower = match self.end {
+                Some(n) => normalize(n).clamp(-1, len - 1),
+                None => -1,
+            };
+            let mut i = upper;
+            while i > lower {
+                indexes.push(i as usize);
+                i += step;
+            }
+        }
+        indexes
+    }
+}
+
+impl Aggregate {
+    /// Reduces the numbers `values` to a single number.
+    ///
+    /// Returns `None` if one of the values is not a number, or if there is no value to get the
+    /// minimum, the maximum or the average from (the sum of no value is 0).
+    /// Minimum and maximum keep the selected number as is, the sum stays an integer when
+    /// all the values are integers, and the average is always a float.
+    pub fn eval<'a>(
+        &self,
+        values: impl IntoIterator<Item = &'a serde_json::Value>,
+    ) -> Option<serde_json::Value> {
+        let numbers = values
+            .into_iter()
+            .map(|value| match value {
+                serde_json::Value::Number(number) => Some(number),
+                _ => None,
+            })
+            .collect::<Option<Vec<&serde_json::Number>>>()?;
+        let to_f64 = |number: &serde_json::Number| number.as_f64().unwrap_or(f64::NAN);
+        match self {
+            Aggregate::Min => numbers
+                .into_iter()
+                .min_by(|a, b| to_f64(a).total_cmp(&to_f64(b)))
+                .map(|number| serde_json::Value::Number(number.clone())),
+            Aggregate::Max => numbers
+                .into_iter()
+                .max_by(|a, b| to_f64(a).total_cmp(&to_f64(b)))
+                .map(|number| serde_json::Value::Number(number.clone())),
+            Aggregate::Sum => {
+                let integers = numbers
+                    .iter()
+                    .map(|number| number.as_i64())
+                    .collect::<Option<Vec<i64>>>();
+                let sum = integers.and_then(|integers| {
+                    integers
+                        .into_iter()
+                        .try_fold(0i64, |sum, integer| sum.checked_add(integer))
+                });
+                match sum {
+                    Some(sum) => Some(serde_json::Value::from(sum)),
+                    None => float_value(numbers.into_iter().map(to_f64).sum()),
+                }
+            }
+            Aggregate::Avg => {
+                if numbers.is_empty() {
+                    return None;
+                }
+                let count = numbers.len() as f64;
+                float_value(numbers.into_iter().map(to_f64).sum::<f64>() / count)
+            }
+        }
+    }
+}
+
+/// Returns a JSON number for the float `value`, `None` if it is not finite.
+fn float_value(value: f64) -> Option<serde_json::Value> {
+    serde_json::Number::from_f64(value).map(serde_json::Value::Number)
+}
+
+/// Compares the JSON number `value` to the predicate number `num`.
+///
+/// Integers are compared exactly, as large integers (beyond 2^53) can not be represented by a
+/// float. When one of the numbers is fractional, numbers are compared as floats, with a small
+/// tolerance for equality.
+fn compare_number(value: &serde_json::Number, num: &Number) -> Ordering {
+    if num.decimal == 0 {
+        if let Some(value) = value.as_i64() {
+            return value.cmp(&num.int);
+        }
+        if value.is_u64() {
+            // An unsigned integer not fitting in an i64 is greater than any i64.
+            return Ordering::Greater;
+        }
+    }
+    let value = value.as_f64().unwrap();
+    let num = num.to_f64();
+    if approx_eq!(f64, value, num, ulps = 2) {
+        Ordering::Equal
+    } else {
+        value.total_cmp(&num)
+    }
+}
+
+/// Compares two JSON numbers, integers exactly and other numbers as floats (with the same
+/// tolerance for equality as [`compare_number`]).
+fn compare_numbers(value: &serde_json::Number, other: &serde_json::Number) -> Ordering {
+    if let (Some(value), Some(other)) = (value.as_i64(), other.as_i64()) {
+        return value.cmp(&other);
+    }
+    if let (Some(value), Some(other)) = (value.as_u64(), other.as_u64()) {
+        return value.cmp(&other);
+    }
+    let value = value.as_f64().unwrap();
+    let other = other.as_f64().unwrap();
+    if approx_eq!(f64, value, other, ulps = 2) {
+        Ordering::Equal
+    } else {
+        value.total_cmp(&other)
+    }
+}
+
+impl Comparison {
+    /// Returns true if the `ordering` of two values satisfies this comparison.
+    fn matches(&self, ordering: Ordering) -> bool {
+        match self {
+            Comparison::Equal => ordering == Ordering::Equal,
+            Comparison::NotEqual => ordering != Ordering::Equal,
+            Comparison::GreaterThan => ordering == Ordering::Greater,
+            Comparison::GreaterThanOrEqual => ordering != Ordering::Less,
+            Comparison::LessThan => ordering == Ordering::Less,
+            Comparison::LessThanOrEqual => ordering != Ordering::Greater,
+        }
+    }
+}
+
+impl FilterExpr {
+    /// Evaluates this filter expression on `elem`, short-circuiting `&&` and `||`.
+    pub fn eval(&self, elem: &serde_json::Value) -> bool {
+        match self {
+            FilterExpr::Predicate(predicate) => predicate.eval(elem),
+            FilterExpr::And(left, right) => left.eval(elem) && right.eval(elem),
+            FilterExpr::Or(left, right) => left.eval(elem) || right.eval(elem),
+        }
     }
 }
 
 impl Predicate {
-    pub fn eval(&self, elem: serde_json::Value) -> bool {
-        match elem {
-            serde_json::Value::Object(_) => {
-                if let Some(value) = extract_value(elem, self.key.clone()) {
-                    match (value, self.func.clone()) {
-                        (_, PredicateFunc::KeyExist {}) => true,
-                        (serde_json::Value::Number(v), PredicateFunc::Equal(ref num)) => {
-                            approx_eq!(f64, v.as_f64().unwrap(), num.to_f64(), ulps = 2)
-                        } //v.as_f64().unwrap() == num.to_f64(),
-                        (serde_json::Value::Number(v), PredicateFunc::GreaterThan(ref num)) => {
-                            v.as_f64().unwrap() > num.to_f64()
-                        }
-                        (
-                            serde_json::Value::Number(v),
-                            PredicateFunc::GreaterThanOrEqual(ref num),
-                        ) => v.as_f64().unwrap() >= num.to_f64(),
-                        (serde_json::Value::Number(v), PredicateFunc::LessThan(ref num)) => {
-                            v.as_f64().unwrap() < num.to_f64()
-                        }
-                        (serde_json::Value::Number(v), PredicateFunc::LessThanOrEqual(ref num)) => {
-                            v.as_f64().unwrap() <= num.to_f64()
-                        }
-                        (serde_json::Value::String(v), PredicateFunc::EqualString(ref s)) => {
-                            v == *s
-                        }
-                        _ => false,
-                    }
-                } else {
-                    false
+    pub fn eval(&self, elem: &serde_json::Value) -> bool {
+        // Both fields must be numbers, a missing field or another type never matches.
+        if let PredicateFunc::CompareField(comparison, other_key) = &self.func {
+            let value = extract_value(elem, &self.key);
+            let other = extract_value(elem, other_key);
+            return match (value.as_deref(), other.as_deref()) {
+                (Some(serde_json::Value::Number(v)), Some(serde_json::Value::Number(other))) => {
+                    comparison.matches(compare_numbers(v, other))
                 }
+                _ => false,
+            };
+        }
+        // An empty key path targets the current element `@` itself.
+        let Some(value) = extract_value(elem, &self.key) else {
+            return false;
+        };
+        match (value.as_ref(), &self.func) {
+            (_, PredicateFunc::KeyExist {}) => true,
+            (value, PredicateFunc::TypeIs(json_type)) => json_type.matches(value),
+            (serde_json::Value::Object(map), PredicateFunc::HasKey(key)) => map.contains_key(key),
+            (serde_json::Value::Number(v), PredicateFunc::Equal(num)) => {
+                compare_number(v, num) == Ordering::Equal
+            }
+            (serde_json::Value::Number(v), PredicateFunc::NotEqual(num)) => {
+                compare_number(v, num) != Ordering::Equal
             }
+            (serde_json::Value::Number(v), PredicateFunc::GreaterThan(num)) => {
+                compare_number(v, num) == Ordering::Greater
+            }
+            (serde_json::Value::Number(v), PredicateFunc::GreaterThanOrEqual(num)) => {
+                compare_number(v, num) != Ordering::Less
+            }
+            (serde_json::Value::Number(v), PredicateFunc::LessThan(num)) => {
+                compare_number(v, num) == Ordering::Less
+            }
+            (serde_json::Value::Number(v), PredicateFunc::LessThanOrEqual(num)) => {
+                compare_number(v, num) != Ordering::Greater
+            }
+            (serde_json::Value::String(v), PredicateFunc::EqualString(s)) => v == s,
+            (serde_json::Value::String(v), PredicateFunc::NotEqualString(s)) => v != s,
+            (serde_json::Value::String(v), PredicateFunc::GreaterThanString(s)) => v > s,
+            (serde_json::Value::String(v), PredicateFunc::GreaterThanOrEqualString(s)) => v >= s,
+            (serde_json::Value::String(v), PredicateFunc::LessThanString(s)) => v < s,
+            (serde_json::Value::String(v), PredicateFunc::LessThanOrEqualString(s)) => v <= s,
+            (serde_json::Value::String(v), PredicateFunc::Match(regex)) => regex.inner.is_match(v),
+            (serde_json::Value::String(v), PredicateFunc::NotMatch(regex)) => {
+                !regex.inner.is_match(v)
+            }
+            (serde_json::Value::String(v), PredicateFunc::Contains(s)) => v.contains(s.as_str()),
+            (serde_json::Value::Array(values), PredicateFunc::Contains(s)) => values
+                .iter()
+                .any(|value| value.as_str() == Some(s.as_str())),
+            (serde_json::Value::Bool(v), PredicateFunc::EqualBool(b)) => v == b,
+            (serde_json::Value::Null, PredicateFunc::EqualNull {}) => true,
             _ => false,
         }
     }
 }
 
-fn extract_value(obj: serde_json::Value, key_path: Vec<String>) -> Option<serde_json::Value> {
-    let mut path = key_path;
+impl JsonType {
+    /// Returns true if `value` is of this JSON type.
+    pub fn matches(&self, value: &serde_json::Value) -> bool {
+        matches!(
+            (self, value),
+            (JsonType::Object, serde_json::Value::Object(_))
+                | (JsonType::Array, serde_json::Value::Array(_))
+                | (JsonType::String, serde_json::Value::String(_))
+                | (JsonType::Number, serde_json::Value::Number(_))
+                | (JsonType::Boolean, serde_json::Value::Bool(_))
+                | (JsonType::Null, serde_json::Value::Null)
+        )
+    }
+}
+
+/// Returns the value at `key_path` in `obj`, borrowed from `obj` except for a synthetic `length`.
+fn extract_value<'a>(
+    obj: &'a serde_json::Value,
+    key_path: &[String],
+) -> Option<Cow<'a, serde_json::Value>> {
     let mut value = obj;
-    loop {
-        if path.is_empty() {
-            break;
-        }
-        let key = path.remove(0);
-        match value.get(key) {
-            None => return None,
-            Some(v) => value = v.clone(),
+    for (index, key) in key_path.iter().enumerate() {
+        if let Some(v) = value.get(key) {
+            value = v;
+            continue;
         }
+        // `length` is the size of an array or a string, unless an object has such a key.
+        let length = match value {
+            serde_json::Value::Array(values) if key == "length" => values.len(),
+            serde_json::Value::String(s) if key == "length" => s.chars().count(),
+            _ => return None,
+        };
+        // A length is a number, no key can follow it.
+        return if index == key_path.len() - 1 {
+            Some(Cow::Owned(length.into()))
+        } else {
+            None
+        };
     }
-    Some(value)
+    Some(Cow::Borrowed(value))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::jsonpath::ast::Number;
+    use crate::jsonpath::ast::{Number, Query};
     use serde_json::json;
 
     use super::*;
@@ -277,6 +807,56 @@ mod tests {
             .is_none(),);
     }
 
+    #[test]
+    pub fn test_selector_name_union() {
+        assert_eq!(
+            Selector::NameUnion(vec![
+                "title".to_string(),
+                "undefined".to_string(),
+                "author".to_string()
+            ])
+            .eval(&json_first_book())
+            .unwrap(),
+            JsonpathResult::Collection(vec![json!("Sayings of the Century"), json!("Nigel Rees")])
+        );
+    }
+
+    #[test]
+    pub fn test_selector_deep_name() {
+        let value = json!({"a": {"b": {"c": 42}}});
+        let selector = Selector::DeepName(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+        assert_eq!(
+            selector.eval(&value).unwrap(),
+            JsonpathResult::SingleEntry(json!(42))
+        );
+        let names = ["a", "b", "c"].map(|name| Selector::NameChild(name.to_string()));
+        assert_eq!(
+            Query {
+                selectors: vec![selector]
+            }
+            .eval(&value),
+            Query {
+                selectors: names.to_vec()
+            }
+            .eval(&value)
+        );
+
+        assert!(
+            Selector::DeepName(vec!["a".to_string(), "x".to_string(), "c".to_string()])
+                .eval(&value)
+                .is_none()
+        );
+        // Names can not be looked up in a scalar.
+        assert!(Selector::DeepName(vec![
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string(),
+            "d".to_string()
+        ])
+        .eval(&value)
+        .is_none());
+    }
+
     #[test]
     pub fn test_selector_array_index() {
         assert_eq!(
@@ -302,6 +882,14 @@ mod tests {
                 json_fourth_book()
             ])
         );
+        assert_eq!(
+            Selector::ArrayWildcard {}.eval(&json_store()).unwrap(),
+            JsonpathResult::Collection(vec![])
+        );
+        assert_eq!(
+            Selector::Wildcard {}.eval(&json!("title")).unwrap(),
+            JsonpathResult::Collection(vec![])
+        );
     }
 
     #[test]
@@ -310,11 +898,60 @@ mod tests {
             Selector::ArraySlice(Slice {
                 start: None,
                 end: Some(2),
+                step: None,
             })
             .eval(&json_books())
             .unwrap(),
             JsonpathResult::Collection(vec![json_first_book(), json_second_book(),])
         );
+        assert_eq!(
+            Selector::ArraySlice(Slice {
+                start: None,
+                end: None,
+                step: Some(2),
+            })
+            .eval(&json_books())
+            .unwrap(),
+            JsonpathResult::Collection(vec![json_first_book(), json_third_book(),])
+        );
+        assert_eq!(
+            Selector::ArraySlice(Slice {
+                start: None,
+                end: None,
+                step: Some(-1),
+            })
+            .eval(&json_books())
+            .unwrap(),
+            JsonpathResult::Collection(vec![
+                json_fourth_book(),
+                json_third_book(),
+                json_second_book(),
+                json_first_book()
+            ])
+        );
+    }
+
+    #[test]
+    pub fn test_slice_indexes() {
+        let slice = |start, end, step| Slice { start, end, step };
+        assert_eq!(slice(None, None, None).indexes(5), vec![0, 1, 2, 3, 4]);
+        assert_eq!(slice(Some(1), Some(10), Some(3)).indexes(10), vec![1, 4, 7]);
+        assert_eq!(slice(Some(-2), None, None).indexes(5), vec![3, 4]);
+        assert_eq!(slice(Some(10), None, None).indexes(5), Vec::<usize>::new());
+
+        // Negative steps, with negative bounds.
+        assert_eq!(slice(None, None, Some(-2)).indexes(5), vec![4, 2, 0]);
+        assert_eq!(
+            slice(Some(-1), Some(-4), Some(-1)).indexes(5),
+            vec![4, 3, 2]
+        );
+        assert_eq!(slice(Some(3), Some(0), Some(-1)).indexes(5), vec![3, 2, 1]);
+        assert_eq!(slice(Some(10), Some(-10), Some(-3)).indexes(5), vec![4, 1]);
+        assert_eq!(
+            slice(Some(1), Some(3), Some(-1)).indexes(5),
+            Vec::<usize>::new()
+        );
+        assert_eq!(slice(None, None, Some(-1)).indexes(0), Vec::<usize>::new());
     }
 
     #[test]
@@ -332,6 +969,25 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_recursive_index() {
+        let value = json!({"a": [1, [2, 3]], "b": {"c": [[4], 5]}, "0": "zero"});
+        assert_eq!(
+            Selector::RecursiveIndex(0).eval(&value).unwrap(),
+            JsonpathResult::Collection(vec![json!(1), json!(2), json!([4]), json!(4)])
+        );
+        assert_eq!(
+            Selector::RecursiveIndex(1)
+                .eval(&json!([0, [1, 2]]))
+                .unwrap(),
+            JsonpathResult::Collection(vec![json!([1, 2]), json!(2)])
+        );
+        assert_eq!(
+            Selector::RecursiveIndex(5).eval(&value).unwrap(),
+            JsonpathResult::Collection(vec![])
+        );
+    }
+
     // tests from https://cburgmer.github.io/json-path-comparison
     #[test]
     pub fn test_array_index() {
@@ -352,36 +1008,36 @@ mod tests {
             key: vec!["key".to_string()],
             func: PredicateFunc::KeyExist {},
         }
-        .eval(json!({"key": "value"})));
+        .eval(&json!({"key": "value"})));
         assert!(Predicate {
             key: vec!["key".to_string()],
             func: PredicateFunc::EqualString("value".to_string()),
         }
-        .eval(json!({"key": "value"})));
+        .eval(&json!({"key": "value"})));
 
         assert!(!Predicate {
             key: vec!["key".to_string()],
             func: PredicateFunc::EqualString("value".to_string()),
         }
-        .eval(json!({"key": "some"})));
+        .eval(&json!({"key": "some"})));
 
         assert!(Predicate {
             key: vec!["key".to_string()],
             func: PredicateFunc::Equal(Number { int: 1, decimal: 0 }),
         }
-        .eval(json!({"key": 1})));
+        .eval(&json!({"key": 1})));
 
         assert!(!Predicate {
             key: vec!["key".to_string()],
             func: PredicateFunc::Equal(Number { int: 1, decimal: 0 }),
         }
-        .eval(json!({"key": 2})));
+        .eval(&json!({"key": 2})));
 
         assert!(!Predicate {
             key: vec!["key".to_string()],
             func: PredicateFunc::Equal(Number { int: 1, decimal: 0 }),
         }
-        .eval(json!({"key": "1"})));
+        .eval(&json!({"key": "1"})));
 
         assert!(Predicate {
             key: vec!["key".to_string()],
@@ -390,23 +1046,106 @@ mod tests {
                 decimal: 0,
             }),
         }
-        .eval(json!({"key": 1})));
+        .eval(&json!({"key": 1})));
+
+        assert!(Predicate {
+            key: vec!["key".to_string()],
+            func: PredicateFunc::EqualBool(true),
+        }
+        .eval(&json!({"key": true})));
+
+        assert!(!Predicate {
+            key: vec!["key".to_string()],
+            func: PredicateFunc::EqualBool(true),
+        }
+        .eval(&json!({"key": "true"})));
+
+        assert!(Predicate {
+            key: vec!["key".to_string()],
+            func: PredicateFunc::EqualNull {},
+        }
+        .eval(&json!({"key": null})));
+
+        assert!(!Predicate {
+            key: vec!["key".to_string()],
+            func: PredicateFunc::EqualNull {},
+        }
+        .eval(&json!({"key": false})));
+
+        assert!(!Predicate {
+            key: vec!["key".to_string()],
+            func: PredicateFunc::EqualNull {},
+        }
+        .eval(&json!({})));
+
+        assert!(Predicate {
+            key: vec!["key".to_string()],
+            func: PredicateFunc::KeyExist {},
+        }
+        .eval(&json!({"key": null})));
+    }
+
+    #[test]
+    pub fn test_predicate_current_element() {
+        assert!(Predicate {
+            key: vec![],
+            func: PredicateFunc::EqualBool(true),
+        }
+        .eval(&json!(true)));
+
+        assert!(!Predicate {
+            key: vec![],
+            func: PredicateFunc::EqualBool(true),
+        }
+        .eval(&json!(false)));
+
+        assert!(!Predicate {
+            key: vec![],
+            func: PredicateFunc::EqualBool(false),
+        }
+        .eval(&json!(0)));
     }
 
     #[test]
     pub fn test_extract_value() {
         assert_eq!(
-            extract_value(json!({"key": 1}), vec!["key".to_string()]).unwrap(),
-            json!(1)
+            extract_value(&json!({"key": 1}), &["key".to_string()]).as_deref(),
+            Some(&json!(1))
         );
-        assert!(extract_value(json!({"key": 1}), vec!["unknown".to_string()]).is_none());
+        assert!(extract_value(&json!({"key": 1}), &["unknown".to_string()]).is_none());
         assert_eq!(
             extract_value(
-                json!({"key1": {"key2": 1}}),
-                vec!["key1".to_string(), "key2".to_string()]
+                &json!({"key1": {"key2": 1}}),
+                &["key1".to_string(), "key2".to_string()]
             )
-            .unwrap(),
-            json!(1)
+            .as_deref(),
+            Some(&json!(1))
+        );
+
+        assert_eq!(
+            extract_value(
+                &json!({"tags": ["a", "b", "c"]}),
+                &["tags".to_string(), "length".to_string()]
+            )
+            .as_deref(),
+            Some(&json!(3))
+        );
+        assert_eq!(
+            extract_value(
+                &json!({"name": "héllo"}),
+                &["name".to_string(), "length".to_string()]
+            )
+            .as_deref(),
+            Some(&json!(5))
+        );
+        assert_eq!(
+            extract_value(&json!({"length": 12}), &["length".to_string()]).as_deref(),
+            Some(&json!(12))
         );
+        assert!(extract_value(
+            &json!({"count": 1}),
+            &["count".to_string(), "length".to_string()]
+        )
+        .is_none());
     }
 }
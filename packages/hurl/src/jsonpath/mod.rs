@@ -27,11 +27,11 @@
 //!
 //! We describe below the behaviour that we expect in Hurl.
 //!
-//! Specify a field key in a subscript operator:  `$['name']`.
-//! The key must be enclosed within single quotes only.
-//! The following expressions will not be valid: `$["name"]` and `$[name]`.
+//! Specify a field key in a subscript operator:  `$['name']` or `$["name"]`.
+//! The key must be enclosed within single or double quotes, `$[name]` is not valid.
 //!
-//! Accessing a key containing a single quote must be escape:  `$['\'']`.
+//! Within the quotes, the enclosing quote and the backslash must be escaped:  `$['\'']`,
+//! `$["\""]` and `$['\\']`, and `\n` is a newline: `$['a\nb']`.
 //! Key with unicode are supported: `$['✈']`
 //!
 //! Any character within these quote won't have a specific meaning:
@@ -48,6 +48,10 @@
 //! The key can can specify one or more levels.
 //! For example, `.price.US` specify field 'US' in an object for the field price.
 //! The predicate if not present just checks the key existence.
+//! The key can also be omitted to test the element itself, for instance `$[?(@==true)]`
+//! selects the `true` values of a boolean array.
+//! Combined with the recursive descent, `$..[?(@.active==true)]` selects the matching nodes
+//! at any depth of the document.
 //!
 //! The Hurl API for evaluating a jsonpath expression does not always return a collection (as defined in the jsonpath spec).
 //! It returns an optional value, which is either a collection or a single value (scalar).
@@ -56,12 +60,22 @@
 //! Note that the only selectors returning a scalar are:
 //! - array index selector (`$.store.book[2]`)
 //! - object key selector (`$.store.bicycle.color/$.store.bicycle['color']`)
-//!  
+//! - aggregate function selectors (`$.prices.min()`, `.max()`, `.sum()` and `.avg()`)
+//! - join function selector (`$.tags.join(', ')`)
+//!
 //! This will make testing the value a bit easier.
 //!
+//! Besides the runner's own queries, a parsed [`Query`] can be evaluated directly, reporting
+//! type mismatches with [`Query::try_eval`], sharing results between queries with a
+//! [`QueryCache`], or returning the normalized paths of the selected values with
+//! [`Query::eval_paths`].
 
-pub use self::eval::JsonpathResult;
-pub use self::parser::parse;
+pub use self::ast::{
+    Aggregate, Comparison, FilterExpr, JsonType, Number, Predicate, PredicateFunc, Query, Regex,
+    Selector, Slice,
+};
+pub use self::eval::{JsonpathError, JsonpathResult, JsonpathResultRef, QueryCache};
+pub use self::parser::{parse, Error, ParseError, Pos};
 
 mod ast;
 mod eval;
@@ -27,4 +27,5 @@ pub struct Error {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ParseError {
     Expecting { value: String },
+    RegexExpr { message: String },
 }
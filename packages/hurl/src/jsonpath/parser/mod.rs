@@ -16,7 +16,7 @@
  *
  */
 
-use error::Error;
+pub use error::{Error, ParseError};
 use reader::Reader;
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -49,11 +49,19 @@ fn selector(reader: &mut Reader) -> ParseResult<Selector> {
             selector_filter,
             selector_wildcard,
             selector_recursive_wildcard,
+            selector_recursive_filter,
+            selector_recursive_index,
             selector_recursive_key,
+            selector_array_slice,
             selector_array_index_or_array_indices,
+            selector_flatten,
             selector_array_wildcard,
-            selector_array_slice,
             selector_object_key_bracket,
+            selector_keys,
+            selector_aggregate,
+            selector_distinct,
+            selector_join,
+            selector_stride,
             selector_object_key,
         ],
         reader,
@@ -109,6 +117,13 @@ fn selector_array_wildcard(reader: &mut Reader) -> Result<Selector, Error> {
     Ok(Selector::ArrayWildcard {})
 }
 
+fn selector_flatten(reader: &mut Reader) -> Result<Selector, Error> {
+    try_left_bracket(reader)?;
+    try_literal("**", reader)?;
+    literal("]", reader)?;
+    Ok(Selector::Flatten)
+}
+
 fn selector_array_slice(reader: &mut Reader) -> Result<Selector, Error> {
     try_left_bracket(reader)?;
     let state = reader.state.clone();
@@ -136,16 +151,42 @@ fn selector_array_slice(reader: &mut Reader) -> Result<Selector, Error> {
         }
         Ok(v) => Some(v),
     };
+    let state = reader.state.clone();
+    let step = if try_literal(":", reader).is_ok() {
+        slice_step(reader)?
+    } else {
+        reader.state = state;
+        None
+    };
     literal("]", reader)?;
-    Ok(Selector::ArraySlice(Slice { start, end }))
+    Ok(Selector::ArraySlice(Slice { start, end, step }))
+}
+
+/// Parses the optional step of a slice, after its second colon.
+fn slice_step(reader: &mut Reader) -> Result<Option<i64>, Error> {
+    let state = reader.state.clone();
+    match integer(reader) {
+        Err(_) => {
+            reader.state = state;
+            Ok(None)
+        }
+        Ok(0) => Err(Error {
+            pos: state.pos,
+            recoverable: false,
+            inner: ParseError::Expecting {
+                value: "non-zero integer".to_string(),
+            },
+        }),
+        Ok(v) => Ok(Some(v)),
+    }
 }
 
 fn selector_filter(reader: &mut Reader) -> Result<Selector, Error> {
     try_left_bracket(reader)?;
     try_literal("?(", reader)?;
-    let pred = predicate(reader)?;
+    let filter = filter_expr(reader)?;
     literal(")]", reader)?;
-    Ok(Selector::Filter(pred))
+    Ok(Selector::Filter(filter))
 }
 
 fn selector_object_key_bracket(reader: &mut Reader) -> Result<Selector, Error> {
@@ -159,8 +200,18 @@ fn selector_object_key_bracket(reader: &mut Reader) -> Result<Selector, Error> {
             },
         }),
         Ok(v) => {
+            let mut names = vec![v];
+            while reader.try_literal(",") {
+                whitespace(reader);
+                names.push(string_value(reader)?);
+            }
             literal("]", reader)?;
-            Ok(Selector::NameChild(v))
+            let selector = if names.len() == 1 {
+                Selector::NameChild(names.remove(0))
+            } else {
+                Selector::NameUnion(names)
+            };
+            Ok(selector)
         }
     }
 }
@@ -189,6 +240,61 @@ fn selector_object_key(reader: &mut Reader) -> Result<Selector, Error> {
     Ok(Selector::NameChild(s))
 }
 
+fn selector_keys(reader: &mut Reader) -> Result<Selector, Error> {
+    try_literal(".keys()", reader)?;
+    Ok(Selector::Keys)
+}
+
+fn selector_aggregate(reader: &mut Reader) -> Result<Selector, Error> {
+    let start = reader.state.clone();
+    for (name, aggregate) in [
+        (".min()", Aggregate::Min),
+        (".max()", Aggregate::Max),
+        (".sum()", Aggregate::Sum),
+    ] {
+        if try_literal(name, reader).is_ok() {
+            return Ok(Selector::Aggregate(aggregate));
+        }
+        reader.state = start.clone();
+    }
+    try_literal(".avg()", reader)?;
+    Ok(Selector::Aggregate(Aggregate::Avg))
+}
+
+fn selector_distinct(reader: &mut Reader) -> Result<Selector, Error> {
+    try_literal(".distinct()", reader)?;
+    Ok(Selector::Distinct)
+}
+
+fn selector_join(reader: &mut Reader) -> Result<Selector, Error> {
+    try_literal(".join(", reader)?;
+    let separator = string_value(reader).map_err(|e| Error {
+        recoverable: false,
+        ..e
+    })?;
+    literal(")", reader)?;
+    Ok(Selector::Join(separator))
+}
+
+fn selector_stride(reader: &mut Reader) -> Result<Selector, Error> {
+    try_literal(".stride(", reader)?;
+    let start = reader.state.clone();
+    let n = match natural(reader) {
+        Ok(n) if n > 0 => n,
+        _ => {
+            return Err(Error {
+                pos: start.pos,
+                recoverable: false,
+                inner: ParseError::Expecting {
+                    value: "strictly positive integer".to_string(),
+                },
+            })
+        }
+    };
+    literal(")", reader)?;
+    Ok(Selector::Stride(n))
+}
+
 fn selector_wildcard(reader: &mut Reader) -> Result<Selector, Error> {
     try_literal(".*", reader)?;
     Ok(Selector::Wildcard {})
@@ -199,6 +305,23 @@ fn selector_recursive_wildcard(reader: &mut Reader) -> Result<Selector, Error> {
     Ok(Selector::RecursiveWildcard {})
 }
 
+fn selector_recursive_filter(reader: &mut Reader) -> Result<Selector, Error> {
+    try_literal("..[?(", reader)?;
+    let filter = filter_expr(reader)?;
+    literal(")]", reader)?;
+    Ok(Selector::RecursiveFilter(filter))
+}
+
+/// Parses a recursive index, like `..0`.
+///
+/// A number after `..` is always an array index: object members are not selected, even when
+/// their name is numeric.
+fn selector_recursive_index(reader: &mut Reader) -> Result<Selector, Error> {
+    try_literal("..", reader)?;
+    let index = natural(reader)?;
+    Ok(Selector::RecursiveIndex(index))
+}
+
 fn selector_recursive_key(reader: &mut Reader) -> Result<Selector, Error> {
     try_literal("..", reader)?;
     let k = key_name(reader)?;
@@ -214,6 +337,40 @@ fn try_left_bracket(reader: &mut Reader) -> Result<(), Error> {
     Ok(())
 }
 
+fn filter_expr(reader: &mut Reader) -> ParseResult<FilterExpr> {
+    // || has the lowest precedence: a && b || c is (a && b) || c
+    whitespace(reader);
+    let mut expr = and_filter_expr(reader)?;
+    while reader.try_literal("||") {
+        whitespace(reader);
+        let right = and_filter_expr(reader)?;
+        expr = FilterExpr::Or(Box::new(expr), Box::new(right));
+    }
+    Ok(expr)
+}
+
+fn and_filter_expr(reader: &mut Reader) -> ParseResult<FilterExpr> {
+    let mut expr = primary_filter_expr(reader)?;
+    while reader.try_literal("&&") {
+        whitespace(reader);
+        let right = primary_filter_expr(reader)?;
+        expr = FilterExpr::And(Box::new(expr), Box::new(right));
+    }
+    Ok(expr)
+}
+
+fn primary_filter_expr(reader: &mut Reader) -> ParseResult<FilterExpr> {
+    let expr = if reader.try_literal("(") {
+        let expr = filter_expr(reader)?;
+        literal(")", reader)?;
+        expr
+    } else {
+        FilterExpr::Predicate(predicate(reader)?)
+    };
+    whitespace(reader);
+    Ok(expr)
+}
+
 fn predicate(reader: &mut Reader) -> ParseResult<Predicate> {
     // predicate always on key?
     // TODO parsing key-value
@@ -224,15 +381,48 @@ fn predicate(reader: &mut Reader) -> ParseResult<Predicate> {
     // @.key          Exist(Key)
     // @.key==value   Equal(Key,Value)
     // @.key>=value   GreaterThanOrEqual(Key, Value)
-    literal("@.", reader)?; // assume key value for the time being
-    let key = key_path(reader)?;
+    // @==value       Equal(Value) on the current element
+    // hasKey 'key'   HasKey(Key) on the current element
+    // 'value' in @.key   Contains(Key, Value)
+    if reader.try_literal("hasKey") {
+        whitespace(reader);
+        let key = string_value(reader)?;
+        return Ok(Predicate {
+            key: vec![],
+            func: PredicateFunc::HasKey(key),
+        });
+    }
+    if matches!(reader.peek(), Some('\'') | Some('"')) {
+        let value = string_value(reader)?;
+        literal("in", reader)?;
+        whitespace(reader);
+        literal("@", reader)?;
+        let key = if reader.try_literal(".") {
+            key_path(reader)?
+        } else {
+            vec![]
+        };
+        whitespace(reader);
+        return Ok(Predicate {
+            key,
+            func: PredicateFunc::Contains(value),
+        });
+    }
+    literal("@", reader)?;
+    let key = if reader.try_literal(".") {
+        key_path(reader)?
+    } else {
+        vec![]
+    };
+    whitespace(reader);
     let state = reader.state.clone();
     let func = match predicate_func(reader) {
         Ok(f) => f,
-        Err(_) => {
+        Err(e) if e.recoverable => {
             reader.state = state;
             PredicateFunc::KeyExist {}
         }
+        Err(e) => return Err(e),
     };
     Ok(Predicate { key, func })
 }
@@ -240,17 +430,58 @@ fn predicate(reader: &mut Reader) -> ParseResult<Predicate> {
 fn predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
     choice(
         &[
+            compare_field_predicate_func,
             equal_number_predicate_func,
+            not_equal_number_predicate_func,
             greater_than_predicate_func,
             greater_than_or_equal_predicate_func,
             less_than_predicate_func,
             less_than_or_equal_predicate_func,
+            equal_bool_predicate_func,
+            equal_null_predicate_func,
             equal_string_predicate_func,
+            not_equal_string_predicate_func,
+            greater_than_string_predicate_func,
+            greater_than_or_equal_string_predicate_func,
+            less_than_string_predicate_func,
+            less_than_or_equal_string_predicate_func,
+            match_predicate_func,
+            not_match_predicate_func,
+            type_is_predicate_func,
+            contains_predicate_func,
         ],
         reader,
     )
 }
 
+/// Parses a comparison with another field of the current element, like `< @.cost`.
+fn compare_field_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    let start = reader.state.clone();
+    for (operator, comparison) in [
+        ("==", Comparison::Equal),
+        ("!=", Comparison::NotEqual),
+        (">=", Comparison::GreaterThanOrEqual),
+        ("<=", Comparison::LessThanOrEqual),
+        (">", Comparison::GreaterThan),
+        ("<", Comparison::LessThan),
+    ] {
+        if try_literal(operator, reader).is_ok() {
+            whitespace(reader);
+            try_literal("@.", reader)?;
+            let key = key_path(reader)?;
+            return Ok(PredicateFunc::CompareField(comparison, key));
+        }
+        reader.state = start.clone();
+    }
+    Err(Error {
+        pos: start.pos,
+        recoverable: true,
+        inner: ParseError::Expecting {
+            value: "comparison operator".to_string(),
+        },
+    })
+}
+
 fn equal_number_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
     try_literal("==", reader)?;
     whitespace(reader);
@@ -258,6 +489,13 @@ fn equal_number_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc
     Ok(PredicateFunc::Equal(num))
 }
 
+fn not_equal_number_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal("!=", reader)?;
+    whitespace(reader);
+    let num = number(reader)?;
+    Ok(PredicateFunc::NotEqual(num))
+}
+
 fn greater_than_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
     try_literal(">", reader)?;
     whitespace(reader);
@@ -286,6 +524,20 @@ fn less_than_or_equal_predicate_func(reader: &mut Reader) -> ParseResult<Predica
     Ok(PredicateFunc::LessThanOrEqual(num))
 }
 
+fn equal_bool_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal("==", reader)?;
+    whitespace(reader);
+    let b = boolean(reader)?;
+    Ok(PredicateFunc::EqualBool(b))
+}
+
+fn equal_null_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal("==", reader)?;
+    whitespace(reader);
+    try_literal("null", reader)?;
+    Ok(PredicateFunc::EqualNull {})
+}
+
 fn equal_string_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
     try_literal("==", reader)?;
     whitespace(reader);
@@ -293,6 +545,86 @@ fn equal_string_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc
     Ok(PredicateFunc::EqualString(s))
 }
 
+fn not_equal_string_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal("!=", reader)?;
+    whitespace(reader);
+    let s = string_value(reader)?;
+    Ok(PredicateFunc::NotEqualString(s))
+}
+
+fn greater_than_string_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal(">", reader)?;
+    whitespace(reader);
+    let s = string_value(reader)?;
+    Ok(PredicateFunc::GreaterThanString(s))
+}
+
+fn greater_than_or_equal_string_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal(">=", reader)?;
+    whitespace(reader);
+    let s = string_value(reader)?;
+    Ok(PredicateFunc::GreaterThanOrEqualString(s))
+}
+
+fn less_than_string_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal("<", reader)?;
+    whitespace(reader);
+    let s = string_value(reader)?;
+    Ok(PredicateFunc::LessThanString(s))
+}
+
+fn less_than_or_equal_string_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal("<=", reader)?;
+    whitespace(reader);
+    let s = string_value(reader)?;
+    Ok(PredicateFunc::LessThanOrEqualString(s))
+}
+
+fn match_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal("=~", reader)?;
+    whitespace(reader);
+    let regex = regex(reader)?;
+    Ok(PredicateFunc::Match(regex))
+}
+
+fn contains_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal("contains", reader)?;
+    whitespace(reader);
+    let s = string_value(reader)?;
+    Ok(PredicateFunc::Contains(s))
+}
+
+fn type_is_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal("typeIs", reader)?;
+    whitespace(reader);
+    let start = reader.state.clone();
+    let json_type = match string_value(reader)?.as_str() {
+        "object" => JsonType::Object,
+        "array" => JsonType::Array,
+        "string" => JsonType::String,
+        "number" => JsonType::Number,
+        "boolean" => JsonType::Boolean,
+        "null" => JsonType::Null,
+        _ => {
+            return Err(Error {
+                pos: start.pos,
+                recoverable: false,
+                inner: ParseError::Expecting {
+                    value: "object, array, string, number, boolean or null".to_string(),
+                },
+            })
+        }
+    };
+    Ok(PredicateFunc::TypeIs(json_type))
+}
+
+fn not_match_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal("!~", reader)?;
+    whitespace(reader);
+    let regex = regex(reader)?;
+    Ok(PredicateFunc::NotMatch(regex))
+}
+
 #[cfg(test)]
 mod tests {
     // tests from https://cburgmer.github.io/json-path-comparison
@@ -384,10 +716,10 @@ mod tests {
         let mut reader = Reader::new("[?(@.isbn)]");
         assert_eq!(
             selector(&mut reader).unwrap(),
-            Selector::Filter(Predicate {
+            Selector::Filter(FilterExpr::Predicate(Predicate {
                 key: vec!["isbn".to_string()],
                 func: PredicateFunc::KeyExist {},
-            })
+            }))
         );
         assert_eq!(reader.state.cursor, 11);
 
@@ -395,32 +727,32 @@ mod tests {
         let mut reader = Reader::new("[?(@.key=='value')]");
         assert_eq!(
             selector(&mut reader).unwrap(),
-            Selector::Filter(Predicate {
+            Selector::Filter(FilterExpr::Predicate(Predicate {
                 key: vec!["key".to_string()],
                 func: PredicateFunc::EqualString("value".to_string()),
-            })
+            }))
         );
         assert_eq!(reader.state.cursor, 19);
         let mut reader = Reader::new(".[?(@.key=='value')]");
         assert_eq!(
             selector(&mut reader).unwrap(),
-            Selector::Filter(Predicate {
+            Selector::Filter(FilterExpr::Predicate(Predicate {
                 key: vec!["key".to_string()],
                 func: PredicateFunc::EqualString("value".to_string()),
-            })
+            }))
         );
         assert_eq!(reader.state.cursor, 20);
 
         let mut reader = Reader::new("[?(@.price<10)]");
         assert_eq!(
             selector(&mut reader).unwrap(),
-            Selector::Filter(Predicate {
+            Selector::Filter(FilterExpr::Predicate(Predicate {
                 key: vec!["price".to_string()],
                 func: PredicateFunc::LessThan(Number {
                     int: 10,
                     decimal: 0
                 }),
-            })
+            }))
         );
         assert_eq!(reader.state.cursor, 15);
     }
@@ -433,6 +765,20 @@ mod tests {
             Selector::RecursiveKey("book".to_string())
         );
         assert_eq!(reader.state.cursor, 6);
+
+        let mut reader = Reader::new("..12");
+        assert_eq!(selector(&mut reader).unwrap(), Selector::RecursiveIndex(12));
+        assert_eq!(reader.state.cursor, 4);
+
+        let mut reader = Reader::new("..[?(@.active==true)]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::RecursiveFilter(FilterExpr::Predicate(Predicate {
+                key: vec!["active".to_string()],
+                func: PredicateFunc::EqualBool(true),
+            }))
+        );
+        assert_eq!(reader.state.cursor, 21);
     }
 
     #[test]
@@ -475,7 +821,8 @@ mod tests {
             selector(&mut reader).unwrap(),
             Selector::ArraySlice(Slice {
                 start: Some(-1),
-                end: None
+                end: None,
+                step: None,
             })
         );
         assert_eq!(reader.state.cursor, 5);
@@ -485,10 +832,64 @@ mod tests {
             selector(&mut reader).unwrap(),
             Selector::ArraySlice(Slice {
                 start: None,
-                end: Some(2)
+                end: Some(2),
+                step: None,
             })
         );
         assert_eq!(reader.state.cursor, 4);
+
+        let mut reader = Reader::new("[::2]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::ArraySlice(Slice {
+                start: None,
+                end: None,
+                step: Some(2),
+            })
+        );
+        assert_eq!(reader.state.cursor, 5);
+
+        let mut reader = Reader::new("[1:10:3]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::ArraySlice(Slice {
+                start: Some(1),
+                end: Some(10),
+                step: Some(3),
+            })
+        );
+        assert_eq!(reader.state.cursor, 8);
+
+        let mut reader = Reader::new("[::-1]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::ArraySlice(Slice {
+                start: None,
+                end: None,
+                step: Some(-1),
+            })
+        );
+
+        let mut reader = Reader::new("[1:2:]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::ArraySlice(Slice {
+                start: Some(1),
+                end: Some(2),
+                step: None,
+            })
+        );
+
+        let mut reader = Reader::new("[::0]");
+        let error = selector(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 4 });
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "non-zero integer".to_string()
+            }
+        );
     }
 
     #[test]
@@ -513,6 +914,32 @@ mod tests {
             Selector::NameChild("key1".to_string())
         );
         assert_eq!(reader.state.cursor, 8);
+
+        let mut reader = Reader::new("['author', 'title']");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::NameUnion(vec!["author".to_string(), "title".to_string()])
+        );
+        assert_eq!(reader.state.cursor, 19);
+
+        let mut reader = Reader::new("['first name']");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::NameChild("first name".to_string())
+        );
+
+        let mut reader = Reader::new("[\"a.b.c\"]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::NameChild("a.b.c".to_string())
+        );
+
+        let mut reader = Reader::new("['']");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::NameChild("".to_string())
+        );
+        assert_eq!(reader.state.cursor, 4);
     }
 
     #[test]
@@ -530,6 +957,174 @@ mod tests {
             Selector::NameChild("key1".to_string())
         );
         assert_eq!(reader.state.cursor, 5);
+
+        let mut reader = Reader::new(".keys");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::NameChild("keys".to_string())
+        );
+        assert_eq!(reader.state.cursor, 5);
+    }
+
+    #[test]
+    pub fn test_selector_stride() {
+        let mut reader = Reader::new(".stride(2)");
+        assert_eq!(selector(&mut reader).unwrap(), Selector::Stride(2));
+        assert_eq!(reader.state.cursor, 10);
+
+        let mut reader = Reader::new(".stride");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::NameChild("stride".to_string())
+        );
+
+        let mut reader = Reader::new(".stride(0)");
+        let error = selector(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 9 });
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "strictly positive integer".to_string()
+            }
+        );
+    }
+
+    #[test]
+    pub fn test_selector_flatten() {
+        let mut reader = Reader::new("[**]");
+        assert_eq!(selector(&mut reader).unwrap(), Selector::Flatten);
+        assert_eq!(reader.state.cursor, 4);
+
+        let mut reader = Reader::new("[*]");
+        assert_eq!(selector(&mut reader).unwrap(), Selector::ArrayWildcard);
+        assert_eq!(reader.state.cursor, 3);
+    }
+
+    #[test]
+    pub fn test_selector_keys() {
+        let mut reader = Reader::new(".keys()");
+        assert_eq!(selector(&mut reader).unwrap(), Selector::Keys);
+        assert_eq!(reader.state.cursor, 7);
+    }
+
+    #[test]
+    pub fn test_selector_aggregate() {
+        let mut reader = Reader::new(".min()");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::Aggregate(Aggregate::Min)
+        );
+        assert_eq!(reader.state.cursor, 6);
+
+        let mut reader = Reader::new(".avg()");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::Aggregate(Aggregate::Avg)
+        );
+
+        let mut reader = Reader::new(".sum");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::NameChild("sum".to_string())
+        );
+    }
+
+    #[test]
+    pub fn test_selector_distinct() {
+        let mut reader = Reader::new(".distinct()");
+        assert_eq!(selector(&mut reader).unwrap(), Selector::Distinct);
+        assert_eq!(reader.state.cursor, 11);
+
+        let mut reader = Reader::new(".distinct");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::NameChild("distinct".to_string())
+        );
+    }
+
+    #[test]
+    pub fn test_selector_join() {
+        let mut reader = Reader::new(".join(', ')");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::Join(", ".to_string())
+        );
+        assert_eq!(reader.state.cursor, 11);
+
+        let mut reader = Reader::new(".join(\"\")");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::Join("".to_string())
+        );
+
+        let mut reader = Reader::new(".join");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::NameChild("join".to_string())
+        );
+
+        let mut reader = Reader::new(".join(,)");
+        assert!(!selector(&mut reader).unwrap_err().recoverable);
+    }
+
+    #[test]
+    pub fn test_filter_expr() {
+        let predicate_a = FilterExpr::Predicate(Predicate {
+            key: vec!["a".to_string()],
+            func: PredicateFunc::KeyExist {},
+        });
+        let predicate_b = FilterExpr::Predicate(Predicate {
+            key: vec!["b".to_string()],
+            func: PredicateFunc::KeyExist {},
+        });
+        let predicate_c = FilterExpr::Predicate(Predicate {
+            key: vec!["c".to_string()],
+            func: PredicateFunc::KeyExist {},
+        });
+
+        let mut reader = Reader::new("@.a");
+        assert_eq!(filter_expr(&mut reader).unwrap(), predicate_a);
+        assert_eq!(reader.state.cursor, 3);
+
+        // && binds tighter than ||
+        let mut reader = Reader::new("@.a && @.b || @.c");
+        assert_eq!(
+            filter_expr(&mut reader).unwrap(),
+            FilterExpr::Or(
+                Box::new(FilterExpr::And(
+                    Box::new(predicate_a.clone()),
+                    Box::new(predicate_b.clone())
+                )),
+                Box::new(predicate_c.clone())
+            )
+        );
+        assert_eq!(reader.state.cursor, 17);
+
+        let mut reader = Reader::new("@.a || @.b && @.c");
+        assert_eq!(
+            filter_expr(&mut reader).unwrap(),
+            FilterExpr::Or(
+                Box::new(predicate_a.clone()),
+                Box::new(FilterExpr::And(
+                    Box::new(predicate_b.clone()),
+                    Box::new(predicate_c.clone())
+                ))
+            )
+        );
+
+        // Parentheses group sub-expressions
+        let mut reader = Reader::new("@.a && (@.b || @.c)");
+        assert_eq!(
+            filter_expr(&mut reader).unwrap(),
+            FilterExpr::And(
+                Box::new(predicate_a),
+                Box::new(FilterExpr::Or(Box::new(predicate_b), Box::new(predicate_c)))
+            )
+        );
+        assert_eq!(reader.state.cursor, 19);
+
+        assert!(filter_expr(&mut Reader::new("(@.a || @.b")).is_err());
     }
 
     #[test]
@@ -543,6 +1138,52 @@ mod tests {
             }
         );
 
+        // Comparison with another field
+        assert_eq!(
+            predicate(&mut Reader::new("@.price < @.cost")).unwrap(),
+            Predicate {
+                key: vec!["price".to_string()],
+                func: PredicateFunc::CompareField(Comparison::LessThan, vec!["cost".to_string()]),
+            }
+        );
+        assert_eq!(
+            predicate(&mut Reader::new("@.a.b>=@.c.d")).unwrap(),
+            Predicate {
+                key: vec!["a".to_string(), "b".to_string()],
+                func: PredicateFunc::CompareField(
+                    Comparison::GreaterThanOrEqual,
+                    vec!["c".to_string(), "d".to_string()]
+                ),
+            }
+        );
+
+        // Object has a top-level key
+        assert_eq!(
+            predicate(&mut Reader::new("hasKey 'isbn'")).unwrap(),
+            Predicate {
+                key: vec![],
+                func: PredicateFunc::HasKey("isbn".to_string()),
+            }
+        );
+
+        // Substring or array element, with both operand orders
+        assert_eq!(
+            predicate(&mut Reader::new("@.title contains 'Honour'")).unwrap(),
+            Predicate {
+                key: vec!["title".to_string()],
+                func: PredicateFunc::Contains("Honour".to_string()),
+            }
+        );
+        let mut reader = Reader::new("'fiction' in @.tags");
+        assert_eq!(
+            predicate(&mut reader).unwrap(),
+            Predicate {
+                key: vec!["tags".to_string()],
+                func: PredicateFunc::Contains("fiction".to_string()),
+            }
+        );
+        assert_eq!(reader.state.cursor, 19);
+
         // Filter equal on string with single quotes
         assert_eq!(
             predicate(&mut Reader::new("@.key=='value'")).unwrap(),
@@ -581,6 +1222,36 @@ mod tests {
                 }),
             }
         );
+
+        // Filter equal on bool
+        assert_eq!(
+            predicate(&mut Reader::new("@.active==true")).unwrap(),
+            Predicate {
+                key: vec!["active".to_string()],
+                func: PredicateFunc::EqualBool(true),
+            }
+        );
+
+        // Filter on the current element
+        assert_eq!(
+            predicate(&mut Reader::new("@==false")).unwrap(),
+            Predicate {
+                key: vec![],
+                func: PredicateFunc::EqualBool(false),
+            }
+        );
+        let mut reader = Reader::new("@ > 10");
+        assert_eq!(
+            predicate(&mut reader).unwrap(),
+            Predicate {
+                key: vec![],
+                func: PredicateFunc::GreaterThan(Number {
+                    int: 10,
+                    decimal: 0
+                }),
+            }
+        );
+        assert_eq!(reader.state.cursor, 6);
     }
 
     #[test]
@@ -619,6 +1290,20 @@ mod tests {
         );
         assert_eq!(reader.state.cursor, 9);
 
+        let mut reader = Reader::new("==true");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::EqualBool(true)
+        );
+        assert_eq!(reader.state.cursor, 6);
+
+        let mut reader = Reader::new("== null");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::EqualNull {}
+        );
+        assert_eq!(reader.state.cursor, 7);
+
         let mut reader = Reader::new(">5");
         assert_eq!(
             predicate_func(&mut reader).unwrap(),
@@ -646,5 +1331,124 @@ mod tests {
             PredicateFunc::LessThanOrEqual(Number { int: 5, decimal: 0 })
         );
         assert_eq!(reader.state.cursor, 3);
+
+        let mut reader = Reader::new("!=5");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::NotEqual(Number { int: 5, decimal: 0 })
+        );
+        assert_eq!(reader.state.cursor, 3);
+
+        let mut reader = Reader::new("!='fiction'");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::NotEqualString("fiction".to_string())
+        );
+        assert_eq!(reader.state.cursor, 11);
+
+        let mut reader = Reader::new(">'a'");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::GreaterThanString("a".to_string())
+        );
+        assert_eq!(reader.state.cursor, 4);
+
+        let mut reader = Reader::new(">='a'");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::GreaterThanOrEqualString("a".to_string())
+        );
+        assert_eq!(reader.state.cursor, 5);
+
+        let mut reader = Reader::new("<'a'");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::LessThanString("a".to_string())
+        );
+        assert_eq!(reader.state.cursor, 4);
+
+        let mut reader = Reader::new("<= 'a'");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::LessThanOrEqualString("a".to_string())
+        );
+        assert_eq!(reader.state.cursor, 6);
+
+        let mut reader = Reader::new("=~ /^0-\\d\\//");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::Match(Regex {
+                inner: regex::Regex::new("^0-\\d/").unwrap()
+            })
+        );
+        assert_eq!(reader.state.cursor, 12);
+
+        let mut reader = Reader::new("!~/a/");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::NotMatch(Regex {
+                inner: regex::Regex::new("a").unwrap()
+            })
+        );
+        assert_eq!(reader.state.cursor, 5);
+
+        let mut reader = Reader::new("=~ /x{a}/");
+        let error = predicate_func(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 5 });
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.inner,
+            ParseError::RegexExpr {
+                message: "repetition quantifier expects a valid decimal".to_string()
+            }
+        );
+
+        let mut reader = Reader::new("typeIs 'string'");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::TypeIs(JsonType::String)
+        );
+        assert_eq!(reader.state.cursor, 15);
+
+        let mut reader = Reader::new("typeIs 'integer'");
+        let error = predicate_func(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 8 });
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_parse_invalid_regex() {
+        let error = parse("$.book[?(@.isbn =~ /x{a}/)]").err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 21
+            }
+        );
+        assert_eq!(
+            error.inner,
+            ParseError::RegexExpr {
+                message: "repetition quantifier expects a valid decimal".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_type() {
+        let error = parse("$.a[?(@.a typeIs 'integer')]").err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 18
+            }
+        );
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "object, array, string, number, boolean or null".to_string()
+            }
+        );
     }
 }
@@ -15,6 +15,8 @@
  * limitations under the License.
  *
  */
+use hurl_core::parser::regex_error_message;
+
 use super::super::ast::*;
 use super::error::{Error, ParseError};
 use super::{ParseResult, Reader};
@@ -65,6 +67,7 @@ pub fn integer(reader: &mut Reader) -> ParseResult<'static, i64> {
 }
 
 pub fn number(reader: &mut Reader) -> ParseResult<'static, Number> {
+    let negative = reader.peek() == Some('-');
     let int = integer(reader)?;
 
     let decimal = if reader.try_literal(".") {
@@ -93,11 +96,49 @@ pub fn number(reader: &mut Reader) -> ParseResult<'static, Number> {
         0
     };
     whitespace(reader);
+    // The decimal part is always added to the integer part: -1.25 is -2 + 0.75.
+    if negative && decimal > 0 {
+        return Ok(Number {
+            int: int - 1,
+            decimal: 1_000_000_000_000_000_000 - decimal,
+        });
+    }
     Ok(Number { int, decimal })
 }
 
+pub fn boolean(reader: &mut Reader) -> ParseResult<'static, bool> {
+    let value = if reader.try_literal("true") {
+        true
+    } else if reader.try_literal("false") {
+        false
+    } else {
+        return Err(Error {
+            pos: reader.state.pos.clone(),
+            recoverable: true,
+            inner: ParseError::Expecting {
+                value: String::from("true|false"),
+            },
+        });
+    };
+    whitespace(reader);
+    Ok(value)
+}
+
+/// Parses a string delimited by single or double quotes, like `'first name'` or `"a.b"`.
+///
+/// Quotes, the backslash and the newline can be escaped (`\'`, `\"`, `\\` and `\n`), the other
+/// characters (spaces, dots, Unicode...) are kept as is.
 pub fn string_value(reader: &mut Reader) -> Result<String, Error> {
-    try_literal("'", reader)?;
+    let quote = if reader.peek() == Some('"') {
+        "\""
+    } else {
+        "'"
+    };
+    try_literal(quote, reader)?;
+    let quote = quote.chars().next().unwrap();
+    let expecting = || ParseError::Expecting {
+        value: quote.to_string(),
+    };
     let mut s = "".to_string();
     loop {
         match reader.read() {
@@ -105,29 +146,21 @@ pub fn string_value(reader: &mut Reader) -> Result<String, Error> {
                 return Err(Error {
                     pos: reader.state.pos.clone(),
                     recoverable: false,
-                    inner: ParseError::Expecting {
-                        value: String::from("'"),
-                    },
+                    inner: expecting(),
                 })
             }
-            Some('\'') => break,
-            Some('\\') => {
-                // only single quote can be escaped
-                match reader.read() {
-                    Some('\'') => {
-                        s.push('\'');
-                    }
-                    _ => {
-                        return Err(Error {
-                            pos: reader.state.pos.clone(),
-                            recoverable: false,
-                            inner: ParseError::Expecting {
-                                value: String::from("'"),
-                            },
-                        })
-                    }
+            Some(c) if c == quote => break,
+            Some('\\') => match reader.read() {
+                Some(c @ ('\'' | '"' | '\\')) => s.push(c),
+                Some('n') => s.push('\n'),
+                _ => {
+                    return Err(Error {
+                        pos: reader.state.pos.clone(),
+                        recoverable: false,
+                        inner: expecting(),
+                    })
                 }
-            }
+            },
             Some(c) => {
                 s.push(c);
             }
@@ -239,6 +272,49 @@ pub fn try_literal(s: &str, p: &mut Reader) -> ParseResult<'static, ()> {
     }
 }
 
+pub fn regex(reader: &mut Reader) -> ParseResult<'static, Regex> {
+    try_literal("/", reader)?;
+    let start = reader.state.pos.clone();
+    let mut s = String::new();
+    // Only `/` is escaped, other escaped sequences such as `\d` are part of the regex.
+    loop {
+        match reader.read() {
+            None => {
+                return Err(Error {
+                    pos: reader.state.pos.clone(),
+                    recoverable: false,
+                    inner: ParseError::Expecting {
+                        value: String::from("/"),
+                    },
+                })
+            }
+            Some('/') => break,
+            Some('\\') => {
+                if reader.try_literal("/") {
+                    s.push('/');
+                } else {
+                    s.push('\\');
+                }
+            }
+            Some(c) => s.push(c),
+        }
+    }
+    match regex::Regex::new(s.as_str()) {
+        Ok(inner) => {
+            whitespace(reader);
+            Ok(Regex { inner })
+        }
+        Err(e) => {
+            let message = regex_error_message(e);
+            Err(Error {
+                pos: start,
+                recoverable: false,
+                inner: ParseError::RegexExpr { message },
+            })
+        }
+    }
+}
+
 pub fn whitespace(reader: &mut Reader) {
     while reader.peek() == Some(' ') {
         reader.read();
@@ -388,11 +464,31 @@ mod tests {
         assert_eq!(
             number(&mut reader).unwrap(),
             Number {
-                int: 0,
-                decimal: 333_333_333_333_333_333
+                int: -1,
+                decimal: 666_666_666_666_666_667
             }
         );
         assert_eq!(reader.state.cursor, 21);
+
+        let mut reader = Reader::new("-5");
+        assert_eq!(
+            number(&mut reader).unwrap(),
+            Number {
+                int: -5,
+                decimal: 0
+            }
+        );
+
+        let mut reader = Reader::new("-1.25");
+        let value = number(&mut reader).unwrap();
+        assert_eq!(
+            value,
+            Number {
+                int: -2,
+                decimal: 750_000_000_000_000_000
+            }
+        );
+        assert_eq!(value.to_f64(), -1.25);
     }
 
     #[test]
@@ -442,6 +538,22 @@ mod tests {
         assert!(!error.recoverable);
     }
 
+    #[test]
+    fn test_boolean() {
+        let mut reader = Reader::new("true");
+        assert!(boolean(&mut reader).unwrap());
+        assert_eq!(reader.state.cursor, 4);
+
+        let mut reader = Reader::new("false ");
+        assert!(!boolean(&mut reader).unwrap());
+        assert_eq!(reader.state.cursor, 6);
+
+        let mut reader = Reader::new("tru");
+        let error = boolean(&mut reader).err().unwrap();
+        assert!(error.recoverable);
+        assert_eq!(reader.state.cursor, 0);
+    }
+
     #[test]
     fn test_string_value() {
         let mut reader = Reader::new("'hello'");
@@ -450,6 +562,37 @@ mod tests {
         let mut reader = Reader::new("'\\''");
         assert_eq!(string_value(&mut reader).unwrap(), "'".to_string());
 
+        let mut reader = Reader::new("''");
+        assert_eq!(string_value(&mut reader).unwrap(), "".to_string());
+
+        let mut reader = Reader::new("\"first 'name'\"");
+        assert_eq!(
+            string_value(&mut reader).unwrap(),
+            "first 'name'".to_string()
+        );
+
+        let mut reader = Reader::new("'a\\\\b'");
+        assert_eq!(string_value(&mut reader).unwrap(), "a\\b".to_string());
+
+        let mut reader = Reader::new("'he said \\\"hi\\\"\\n'");
+        assert_eq!(
+            string_value(&mut reader).unwrap(),
+            "he said \"hi\"\n".to_string()
+        );
+
+        let mut reader = Reader::new("'\\t'");
+        assert!(!string_value(&mut reader).err().unwrap().recoverable);
+
+        let mut reader = Reader::new("\"hi");
+        let error = string_value(&mut reader).err().unwrap();
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: String::from("\"")
+            }
+        );
+        assert!(!error.recoverable);
+
         let mut reader = Reader::new("1");
         let error = string_value(&mut reader).err().unwrap();
         assert_eq!(
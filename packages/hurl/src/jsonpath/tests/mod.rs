@@ -20,11 +20,14 @@
 //! These tests are not located at the root of the project, like Rust integration tests
 //! are usually located since we do not want to expose the jsonpath module to our public API.
 
+use std::borrow::Cow;
 use std::fs::read_to_string;
 
 use serde_json::json;
 
 use crate::jsonpath;
+use crate::jsonpath::ast::{Query, Selector};
+use crate::jsonpath::eval::{JsonpathError, JsonpathResultRef};
 use crate::jsonpath::JsonpathResult;
 
 fn bookstore_value() -> serde_json::Value {
@@ -187,26 +190,21 @@ fn test_bookstore_path() {
 
     // all things in store, which are some books and a red bicycle.
     let expr = jsonpath::parse("$.store.*").unwrap();
-    // Attention, there is no ordering on object keys with serde_json
-    // But you expect that order stays the same
-    // that's why bicycle and boot are inverted
     assert_eq!(
         expr.eval(&bookstore_value()).unwrap(),
-        JsonpathResult::Collection(vec![bicycle_value(), book_value()])
+        JsonpathResult::Collection(vec![book_value(), bicycle_value()])
     );
 
     // the price of everything in the store.
     let expr = jsonpath::parse("$.store..price").unwrap();
-    // Attention, there is no ordering on object keys with serde_json
-    // But you expect that order stays the same
     assert_eq!(
         expr.eval(&bookstore_value()).unwrap(),
         JsonpathResult::Collection(vec![
-            json!(19.95),
             json!(8.95),
             json!(12.99),
             json!(8.99),
             json!(22.99),
+            json!(19.95),
         ])
     );
 
@@ -227,6 +225,23 @@ fn test_bookstore_path() {
         JsonpathResult::Collection(vec![book3_value()])
     );
 
+    // every other book, and the books in reverse order
+    let expr = jsonpath::parse("$..book[::2]").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![book0_value(), book2_value()])
+    );
+    let expr = jsonpath::parse("$..book[::-1]").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![
+            book3_value(),
+            book2_value(),
+            book1_value(),
+            book0_value()
+        ])
+    );
+
     // the first two books
     let expr = jsonpath::parse("$..book[0,1]").unwrap();
     assert_eq!(
@@ -246,6 +261,19 @@ fn test_bookstore_path() {
         JsonpathResult::Collection(vec![book2_value(), book3_value()])
     );
 
+    // filter all books having an isbn key
+    let expr = jsonpath::parse("$.store.book[?(hasKey 'isbn')]").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![book2_value(), book3_value()])
+    );
+    let expr = jsonpath::parse("$[?(hasKey 'isbn')]").unwrap();
+    assert_eq!(
+        expr.eval(&json!([1, "isbn", {"isbn": null}, {"title": "Dune"}]))
+            .unwrap(),
+        JsonpathResult::Collection(vec![json!({"isbn": null})])
+    );
+
     // filter all books cheaper than 10
     let expr = jsonpath::parse("$..book[?(@.price<10)]").unwrap();
     assert_eq!(
@@ -255,38 +283,37 @@ fn test_bookstore_path() {
 
     // All members of JSON structure
     let expr = jsonpath::parse("$..*").unwrap();
-    // Order is reproducible
-    // but does not keep same order of json input!
+    // Members are selected in the order of the JSON input.
     assert_eq!(
         expr.eval(&bookstore_value()).unwrap(),
         JsonpathResult::Collection(vec![
             store_value(),
-            bicycle_value(),
-            json!("red"),
-            json!(19.95),
             book_value(),
             book0_value(),
-            json!("Nigel Rees"),
             json!("reference"),
-            json!(8.95),
+            json!("Nigel Rees"),
             json!("Sayings of the Century"),
+            json!(8.95),
             book1_value(),
-            json!("Evelyn Waugh"),
             json!("fiction"),
-            json!(12.99),
+            json!("Evelyn Waugh"),
             json!("Sword of Honour"),
+            json!(12.99),
             book2_value(),
-            json!("Herman Melville"),
             json!("fiction"),
+            json!("Herman Melville"),
+            json!("Moby Dick"),
             json!("0-553-21311-3"),
             json!(8.99),
-            json!("Moby Dick"),
             book3_value(),
-            json!("J. R. R. Tolkien"),
             json!("fiction"),
+            json!("J. R. R. Tolkien"),
+            json!("The Lord of the Rings"),
             json!("0-395-19395-8"),
             json!(22.99),
-            json!("The Lord of the Rings"),
+            bicycle_value(),
+            json!("red"),
+            json!(19.95),
         ])
     );
 }
@@ -325,6 +352,38 @@ fn test_array() {
     );
 }
 
+#[test]
+fn test_wildcard_document_order() {
+    let value: serde_json::Value =
+        serde_json::from_str(r#"{"zulu": 1, "alpha": 2, "mike": 3, "bravo": 4}"#).unwrap();
+    let expr = jsonpath::parse("$.*").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!(1), json!(2), json!(3), json!(4)])
+    );
+    assert_eq!(
+        expr.eval_paths(&value).unwrap(),
+        vec!["$['zulu']", "$['alpha']", "$['mike']", "$['bravo']"]
+    );
+}
+
+#[test]
+fn test_root_array_index() {
+    let expr = jsonpath::parse("$[0]").unwrap();
+    assert_eq!(
+        expr,
+        Query {
+            selectors: vec![Selector::ArrayIndex(0)]
+        }
+    );
+    let array = json!(["first", "second"]);
+    assert_eq!(
+        expr.eval(&array).unwrap(),
+        JsonpathResult::SingleEntry(json!("first"))
+    );
+    assert!(expr.eval(&json!({"0": "first"})).is_none());
+}
+
 #[test]
 fn test_key_access() {
     let obj = json!({
@@ -404,16 +463,9 @@ fn test_key_access() {
         assert_eq!(results, values);
     }
 
+    // The array wildcard does not select the values of an object.
     let expr = jsonpath::parse("$[*]").unwrap();
-    let results = expr.eval(&obj).unwrap();
-    if let JsonpathResult::Collection(results) = results {
-        let mut results = results
-            .iter()
-            .map(|e| e.as_str().unwrap())
-            .collect::<Vec<&str>>();
-        results.sort_unstable();
-        assert_eq!(results, values);
-    }
+    assert_eq!(expr.eval(&obj).unwrap(), JsonpathResult::Collection(vec![]));
 }
 
 fn fruit_prices_value() -> serde_json::Value {
@@ -457,6 +509,867 @@ fn test_filter_nested_object() {
     );
 }
 
+#[test]
+fn test_filter_current_element() {
+    let array = json!([true, false, true]);
+    let expr = jsonpath::parse("$[?(@==true)]").unwrap();
+    assert_eq!(
+        expr.eval(&array).unwrap(),
+        JsonpathResult::Collection(vec![json!(true), json!(true)])
+    );
+
+    let array = json!([true, "true", 1, null, false, {"value": true}]);
+    let expr = jsonpath::parse("$[?(@==true)]").unwrap();
+    assert_eq!(
+        expr.eval(&array).unwrap(),
+        JsonpathResult::Collection(vec![json!(true)])
+    );
+    let expr = jsonpath::parse("$[?(@==false)]").unwrap();
+    assert_eq!(
+        expr.eval(&array).unwrap(),
+        JsonpathResult::Collection(vec![json!(false)])
+    );
+
+    let value = json!({"prices": [5, 12.5, "20", 30, {"price": 40}]});
+    let expr = jsonpath::parse("$.prices[?(@ > 10)]").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!(12.5), json!(30)])
+    );
+}
+
+#[test]
+fn test_filter_compare_fields() {
+    let value = json!({
+        "items": [
+            {"name": "a", "price": 5, "cost": 10},
+            {"name": "b", "price": 12, "cost": 10},
+            {"name": "c", "price": 10, "cost": 10.0},
+            {"name": "d", "price": 1},
+            {"name": "e", "price": "1", "cost": 10}
+        ]
+    });
+    let names = |expr: &str| {
+        jsonpath::parse(&format!("$.items[?({expr})].name"))
+            .unwrap()
+            .eval(&value)
+            .unwrap()
+    };
+    assert_eq!(
+        names("@.price < @.cost"),
+        JsonpathResult::Collection(vec![json!("a")])
+    );
+    assert_eq!(
+        names("@.price <= @.cost"),
+        JsonpathResult::Collection(vec![json!("a"), json!("c")])
+    );
+    assert_eq!(
+        names("@.price > @.cost"),
+        JsonpathResult::Collection(vec![json!("b")])
+    );
+    assert_eq!(
+        names("@.price == @.cost"),
+        JsonpathResult::Collection(vec![json!("c")])
+    );
+    // A missing or non-numeric field never matches, even with `!=`.
+    assert_eq!(
+        names("@.price != @.cost"),
+        JsonpathResult::Collection(vec![json!("a"), json!("b")])
+    );
+}
+
+#[test]
+fn test_filter_comparison() {
+    let expr = jsonpath::parse("$.store.book[?(@.category!='fiction')].title").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("Sayings of the Century")])
+    );
+
+    let expr = jsonpath::parse("$.store.book[?(@.price!=8.95)].price").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!(12.99), json!(8.99), json!(22.99)])
+    );
+
+    // Strings are compared lexicographically.
+    let expr = jsonpath::parse("$.store.book[?(@.author<'J')].author").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("Evelyn Waugh"), json!("Herman Melville")])
+    );
+    let expr = jsonpath::parse("$.store.book[?(@.author>='J')].author").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("Nigel Rees"), json!("J. R. R. Tolkien")])
+    );
+
+    // Comparing values of different types never matches.
+    let array = json!(["1", 1, "a"]);
+    let expr = jsonpath::parse("$[?(@!=2)]").unwrap();
+    assert_eq!(
+        expr.eval(&array).unwrap(),
+        JsonpathResult::Collection(vec![json!(1)])
+    );
+    let expr = jsonpath::parse("$[?(@>'0')]").unwrap();
+    assert_eq!(
+        expr.eval(&array).unwrap(),
+        JsonpathResult::Collection(vec![json!("1"), json!("a")])
+    );
+}
+
+#[test]
+fn test_filter_large_integer() {
+    // 9007199254740992 (2^53) and 9007199254740993 are the same f64.
+    let value = json!([
+        {"id": 9007199254740992_i64},
+        {"id": 9007199254740993_i64},
+        {"id": 18446744073709551615_u64}
+    ]);
+    let expr = jsonpath::parse("$[?(@.id==9007199254740993)].id").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!(9007199254740993_i64)])
+    );
+    let expr = jsonpath::parse("$[?(@.id>9007199254740992)].id").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![
+            json!(9007199254740993_i64),
+            json!(18446744073709551615_u64)
+        ])
+    );
+    let expr = jsonpath::parse("$[?(@.id<=9007199254740992)].id").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!(9007199254740992_i64)])
+    );
+}
+
+#[test]
+fn test_filter_match() {
+    let expr = jsonpath::parse("$.store.book[?(@.isbn =~ /^0-\\d{3}-/)].title").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("Moby Dick"), json!("The Lord of the Rings")])
+    );
+    let expr = jsonpath::parse("$.store.book[?(@.isbn =~ /-3$/)].title").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("Moby Dick")])
+    );
+
+    // Elements without the key are neither matching nor not matching.
+    let expr = jsonpath::parse("$.store.book[?(@.isbn !~ /-3$/)].title").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("The Lord of the Rings")])
+    );
+
+    assert!(jsonpath::parse("$.store.book[?(@.isbn =~ /(/)]").is_err());
+}
+
+#[test]
+fn test_filter_logical() {
+    let expr =
+        jsonpath::parse("$.store.book[?(@.price<10 && @.category=='fiction')].title").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("Moby Dick")])
+    );
+
+    // a && b || c is evaluated as (a && b) || c
+    let expr = jsonpath::parse(
+        "$.store.book[?(@.category=='fiction' && @.price<10 || @.category=='reference')].title",
+    )
+    .unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("Sayings of the Century"), json!("Moby Dick")])
+    );
+    let expr = jsonpath::parse(
+        "$.store.book[?(@.category=='fiction' && (@.price<10 || @.category=='reference'))].title",
+    )
+    .unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("Moby Dick")])
+    );
+}
+
+#[test]
+fn test_filter_bool_and_null() {
+    let value = json!([
+        {"name": "bob", "active": true, "deleted": null},
+        {"name": "alice", "active": false, "deleted": "2023-01-01"},
+        {"name": "carol", "active": "true"}
+    ]);
+    let expr = jsonpath::parse("$[?(@.active==true)].name").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!("bob")])
+    );
+    let expr = jsonpath::parse("$[?(@.deleted==null)].name").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!("bob")])
+    );
+    // A key holding null still exists.
+    let expr = jsonpath::parse("$[?(@.deleted)].name").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!("bob"), json!("alice")])
+    );
+}
+
+#[test]
+fn test_filter_type_is() {
+    let value = json!([
+        {"name": "a", "value": "10"},
+        {"name": "b", "value": 10},
+        {"name": "c", "value": null},
+        {"name": "d"}
+    ]);
+    let expr = jsonpath::parse("$[?(@.value typeIs 'string')].name").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!("a")])
+    );
+    let expr = jsonpath::parse("$[?(@.value typeIs 'number')].name").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!("b")])
+    );
+    let expr = jsonpath::parse("$[?(@.value typeIs 'null')].name").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!("c")])
+    );
+}
+
+#[test]
+fn test_filter_contains() {
+    let expr = jsonpath::parse("$.store.book[?(@.title contains 'Honour')].author").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("Evelyn Waugh")])
+    );
+    // Matching is case-sensitive.
+    let expr = jsonpath::parse("$.store.book[?(@.title contains 'honour')]").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![])
+    );
+
+    let value = json!([
+        {"name": "a", "tags": ["fiction", "classic"]},
+        {"name": "b", "tags": [1, null, "fiction", {"x": "fiction"}]},
+        {"name": "c", "tags": [["fiction"], true]},
+        {"name": "d", "tags": "non-fiction"},
+        {"name": "e", "tags": 10},
+        {"name": "f"}
+    ]);
+    let expr = jsonpath::parse("$[?('fiction' in @.tags)].name").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!("a"), json!("b"), json!("d")])
+    );
+    let expr = jsonpath::parse("$[?(@.tags contains 'classic')].name").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!("a")])
+    );
+}
+
+#[test]
+fn test_filter_negative_number() {
+    let value = json!([
+        {"name": "a", "delta": -5},
+        {"name": "b", "delta": -10},
+        {"name": "c", "delta": -1.5},
+        {"name": "d", "delta": 0.5}
+    ]);
+    let eval = |expr: &str| jsonpath::parse(expr).unwrap().eval(&value).unwrap();
+    assert_eq!(
+        eval("$[?(@.delta == -5)].name"),
+        JsonpathResult::Collection(vec![json!("a")])
+    );
+    assert_eq!(
+        eval("$[?(@.delta > -10)].name"),
+        JsonpathResult::Collection(vec![json!("a"), json!("c"), json!("d")])
+    );
+    assert_eq!(
+        eval("$[?(@.delta == -1.5)].name"),
+        JsonpathResult::Collection(vec![json!("c")])
+    );
+    assert_eq!(
+        eval("$[?(@.delta < -0.5)].name"),
+        JsonpathResult::Collection(vec![json!("a"), json!("b"), json!("c")])
+    );
+}
+
+#[test]
+fn test_distinct() {
+    let expr = jsonpath::parse("$.store.book[*].category.distinct()").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("reference"), json!("fiction")])
+    );
+    let expr = jsonpath::parse("$..category.distinct()").unwrap();
+    assert_eq!(
+        expr.try_eval(&bookstore_value()).unwrap().unwrap(),
+        JsonpathResult::Collection(vec![json!("reference"), json!("fiction")])
+    );
+
+    // Distinct also applies to an array, values being compared structurally.
+    let expr = jsonpath::parse("$.distinct()").unwrap();
+    assert_eq!(
+        expr.eval(&json!([{"a": 1}, 2, {"a": 1}, [2], 2])).unwrap(),
+        JsonpathResult::Collection(vec![json!({"a": 1}), json!(2), json!([2])])
+    );
+    assert_eq!(expr.eval(&json!("a")), None);
+}
+
+#[test]
+fn test_name_union() {
+    let expr = jsonpath::parse("$.store.book[*]['author','title']").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![
+            json!("Nigel Rees"),
+            json!("Sayings of the Century"),
+            json!("Evelyn Waugh"),
+            json!("Sword of Honour"),
+            json!("Herman Melville"),
+            json!("Moby Dick"),
+            json!("J. R. R. Tolkien"),
+            json!("The Lord of the Rings")
+        ])
+    );
+    // Values follow the order of the names, missing names are skipped.
+    let expr = jsonpath::parse("$.store.book[2]['isbn', 'price', 'undefined']").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("0-553-21311-3"), json!(8.99)])
+    );
+}
+
+#[test]
+fn test_filter_length() {
+    let value = json!([
+        {"name": "a", "tags": ["x", "y", "z"]},
+        {"name": "b", "tags": ["x"]},
+        {"name": "c", "tags": "xyzw"},
+        {"name": "d", "tags": 42}
+    ]);
+    let expr = jsonpath::parse("$[?(@.tags.length > 2)].name").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!("a"), json!("c")])
+    );
+    let expr = jsonpath::parse("$[?(@.tags.length == 1)].name").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!("b")])
+    );
+}
+
+#[test]
+fn test_recursive_filter() {
+    let value = json!({
+        "users": [
+            {"name": "bob", "active": true},
+            {"name": "alice", "active": false, "friends": [
+                {"name": "carol", "active": true},
+                {"name": "dave"}
+            ]}
+        ],
+        "admin": {"name": "eve", "active": true}
+    });
+    let expr = jsonpath::parse("$..[?(@.active==true)]").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![
+            json!({"name": "bob", "active": true}),
+            json!({"name": "carol", "active": true}),
+            json!({"name": "eve", "active": true}),
+        ])
+    );
+
+    let expr = jsonpath::parse("$..[?(@.active==true)].name").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!("bob"), json!("carol"), json!("eve")])
+    );
+}
+
+#[test]
+fn test_recursive_index() {
+    let value = json!({"matrix": [[1, 2], [3, 4]], "0": "not an index"});
+    let expr = jsonpath::parse("$..0").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!([1, 2]), json!(1), json!(3)])
+    );
+    assert_eq!(
+        expr.eval_paths(&value).unwrap(),
+        vec!["$['matrix'][0]", "$['matrix'][0][0]", "$['matrix'][1][0]"]
+    );
+
+    let expr = jsonpath::parse("$..book..0.title").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("Sayings of the Century")])
+    );
+}
+
+#[test]
+fn test_filter_object_values() {
+    let value = json!({
+        "auth": {"port": 8001, "enabled": true},
+        "billing": {"port": 8002, "enabled": false},
+        "search": {"port": 8003, "enabled": true}
+    });
+    let expr = jsonpath::parse("$[?(@.enabled==true)].port").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!(8001), json!(8003)])
+    );
+
+    // Scalars have nothing to filter.
+    let expr = jsonpath::parse("$.auth.port[?(@==8001)]").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![])
+    );
+}
+
+#[test]
+fn test_join() {
+    let base = jsonpath::parse("$.store.book[0]").unwrap();
+    let relative = jsonpath::parse("$.title").unwrap();
+    let expr = base.join(&relative);
+    assert_eq!(expr, jsonpath::parse("$.store.book[0].title").unwrap());
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::SingleEntry(json!("Sayings of the Century"))
+    );
+
+    // Joining the root query is a no-op.
+    let root = jsonpath::parse("$").unwrap();
+    assert_eq!(base.join(&root), base);
+    assert_eq!(root.join(&base), base);
+}
+
+#[test]
+fn test_keys() {
+    let expr = jsonpath::parse("$.store.book[0].keys()").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![
+            json!("author"),
+            json!("category"),
+            json!("price"),
+            json!("title"),
+        ])
+    );
+
+    let expr = jsonpath::parse("$.store.book.keys()").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!(0), json!(1), json!(2), json!(3)])
+    );
+
+    let expr = jsonpath::parse("$.store.book[0].title.keys()").unwrap();
+    assert_eq!(expr.eval(&bookstore_value()), None);
+}
+
+#[test]
+fn test_stride() {
+    let expr = jsonpath::parse("$.store.book.stride(2)").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![book0_value(), book2_value()])
+    );
+
+    let expr = jsonpath::parse("$.store.book.stride(2).title").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("Sayings of the Century"), json!("Moby Dick")])
+    );
+
+    let expr = jsonpath::parse("$.store.book.stride(5)").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![book0_value()])
+    );
+
+    // A zero stride can not be parsed and selects nothing.
+    assert!(jsonpath::parse("$.store.book.stride(0)").is_err());
+    assert_eq!(
+        Query {
+            selectors: vec![
+                Selector::NameChild("store".to_string()),
+                Selector::NameChild("book".to_string()),
+                Selector::Stride(0),
+            ]
+        }
+        .eval(&bookstore_value())
+        .unwrap(),
+        JsonpathResult::Collection(vec![])
+    );
+}
+
+#[test]
+fn test_flatten() {
+    let expr = jsonpath::parse("$[**]").unwrap();
+    assert_eq!(
+        expr.eval(&json!([[1, 2], [3]])).unwrap(),
+        JsonpathResult::Collection(vec![json!(1), json!(2), json!(3)])
+    );
+
+    // Non-array elements pass through, and only one level is flattened.
+    assert_eq!(
+        expr.eval(&json!([[1], 2, [[3]]])).unwrap(),
+        JsonpathResult::Collection(vec![json!(1), json!(2), json!([3])])
+    );
+
+    let expr = jsonpath::parse("$.items[*].tags[**]").unwrap();
+    assert_eq!(
+        expr.eval(&json!({"items": [{"tags": ["a", "b"]}, {"tags": ["c"]}]}))
+            .unwrap(),
+        JsonpathResult::Collection(vec![json!("a"), json!("b"), json!("c")])
+    );
+}
+
+#[test]
+fn test_aggregate() {
+    let expr = jsonpath::parse("$.store.book[*].price.min()").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::SingleEntry(json!(8.95))
+    );
+
+    let expr = jsonpath::parse("$.store.book[*].price.max()").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::SingleEntry(json!(22.99))
+    );
+
+    let expr = jsonpath::parse("$.store.book[*].price.sum()").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::SingleEntry(json!(53.92))
+    );
+
+    let expr = jsonpath::parse("$.store.book[*].price.avg()").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::SingleEntry(json!(13.48))
+    );
+
+    // Aggregates also apply to an array, and integers sum to an integer.
+    let expr = jsonpath::parse("$.sum()").unwrap();
+    assert_eq!(
+        expr.eval(&json!([1, 2, 3])).unwrap(),
+        JsonpathResult::SingleEntry(json!(6))
+    );
+    assert_eq!(
+        expr.eval(&json!([])).unwrap(),
+        JsonpathResult::SingleEntry(json!(0))
+    );
+
+    // Non-numeric elements or empty collections give no result.
+    let expr = jsonpath::parse("$.store.book[*].title.max()").unwrap();
+    assert_eq!(expr.eval(&bookstore_value()), None);
+    let expr = jsonpath::parse("$.avg()").unwrap();
+    assert_eq!(expr.eval(&json!([])), None);
+}
+
+#[test]
+fn test_join_selector() {
+    let expr = jsonpath::parse("$.store.book[*].author.join(', ')").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::SingleEntry(json!(
+            "Nigel Rees, Evelyn Waugh, Herman Melville, J. R. R. Tolkien"
+        ))
+    );
+    assert_eq!(
+        expr.eval_ref(&bookstore_value())
+            .map(JsonpathResultRef::into_owned),
+        expr.eval(&bookstore_value())
+    );
+    assert_eq!(
+        expr.try_eval(&bookstore_value()).unwrap(),
+        expr.eval(&bookstore_value())
+    );
+
+    // Joins an array, non-string elements with their JSON representation.
+    let expr = jsonpath::parse("$.join('-')").unwrap();
+    assert_eq!(
+        expr.eval(&json!(["a", 1, true, null, {"b": 2}])).unwrap(),
+        JsonpathResult::SingleEntry(json!(r#"a-1-true-null-{"b":2}"#))
+    );
+    assert_eq!(
+        expr.eval(&json!([])).unwrap(),
+        JsonpathResult::SingleEntry(json!(""))
+    );
+    assert_eq!(expr.eval(&json!("a")), None);
+}
+
+#[test]
+fn test_try_eval() {
+    let expr = jsonpath::parse("$.store.book[0].title").unwrap();
+    assert_eq!(
+        expr.try_eval(&bookstore_value()).unwrap(),
+        Some(JsonpathResult::SingleEntry(json!("Sayings of the Century")))
+    );
+
+    let expr = jsonpath::parse("$.store.unknown").unwrap();
+    assert_eq!(expr.try_eval(&bookstore_value()).unwrap(), None);
+
+    // Indexing an object
+    let expr = jsonpath::parse("$.store[0]").unwrap();
+    let error = expr.try_eval(&bookstore_value()).unwrap_err();
+    assert_eq!(
+        error,
+        JsonpathError::TypeMismatch {
+            selector_index: 1,
+            selector: "array index".to_string(),
+            expected: "array".to_string(),
+            found: "object".to_string(),
+        }
+    );
+    assert_eq!(expr.eval(&bookstore_value()), None);
+
+    // Selecting a key in every element of a collection
+    let expr = jsonpath::parse("$.store.book[*].title.value").unwrap();
+    assert_eq!(
+        expr.try_eval(&bookstore_value()).unwrap_err(),
+        JsonpathError::TypeMismatch {
+            selector_index: 4,
+            selector: "name".to_string(),
+            expected: "object".to_string(),
+            found: "string".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_eval_ref() {
+    let value = bookstore_value();
+    for expr in [
+        "$",
+        "$.store.book[0].title",
+        "$.store.unknown",
+        "$.store.book[*].author",
+        "$..author",
+        "$.store.*",
+        "$..*",
+        "$..book[-1:]",
+        "$..book[::-1].title",
+        "$..book[0,1]",
+        "$..book[?(@.price<10)].title",
+        "$..[?(@.isbn)].isbn",
+        "$.store.book.stride(2).title",
+        "$.store.book[0].keys()",
+        "$.store.book.keys()",
+        "$.store.book[*].price.max()",
+        "$.store.book[*].price.sum()",
+    ] {
+        let expr = jsonpath::parse(expr).unwrap();
+        assert_eq!(
+            expr.eval_ref(&value).map(JsonpathResultRef::into_owned),
+            expr.eval(&value)
+        );
+    }
+
+    // Selected values are borrowed from the document.
+    let expr = jsonpath::parse("$.store.book[0]").unwrap();
+    match expr.eval_ref(&value).unwrap() {
+        JsonpathResultRef::SingleEntry(Cow::Borrowed(book)) => {
+            assert!(std::ptr::eq(book, &value["store"]["book"][0]));
+        }
+        result => panic!("unexpected result {result:?}"),
+    }
+}
+
+#[test]
+fn test_eval_paths() {
+    let value = bookstore_value();
+    let paths = |expr: &str| jsonpath::parse(expr).unwrap().eval_paths(&value);
+
+    assert_eq!(paths("$").unwrap(), vec!["$"]);
+    assert_eq!(
+        paths("$.store.book[0].title").unwrap(),
+        vec!["$['store']['book'][0]['title']"]
+    );
+    assert_eq!(paths("$.store.unknown"), None);
+    assert_eq!(
+        paths("$..author").unwrap(),
+        vec![
+            "$['store']['book'][0]['author']",
+            "$['store']['book'][1]['author']",
+            "$['store']['book'][2]['author']",
+            "$['store']['book'][3]['author']",
+        ]
+    );
+    assert_eq!(
+        paths("$..book[?(@.price<10)].title").unwrap(),
+        vec![
+            "$['store']['book'][0]['title']",
+            "$['store']['book'][2]['title']",
+        ]
+    );
+    assert_eq!(
+        paths("$.store.book[-1:]").unwrap(),
+        vec!["$['store']['book'][3]"]
+    );
+    assert_eq!(paths("$.store.book.keys()"), None);
+
+    // Each path selects the value returned by the query at the same position.
+    for expr in [
+        "$..*",
+        "$..price",
+        "$.store.book.stride(2)",
+        "$..[?(@.isbn)]",
+    ] {
+        let values = match jsonpath::parse(expr).unwrap().eval(&value).unwrap() {
+            JsonpathResult::Collection(values) => values,
+            JsonpathResult::SingleEntry(value) => vec![value],
+        };
+        let paths = paths(expr).unwrap();
+        assert_eq!(paths.len(), values.len());
+        for (path, expected) in paths.iter().zip(values) {
+            let query = jsonpath::parse(path).unwrap();
+            assert_eq!(
+                query.eval(&value),
+                Some(JsonpathResult::SingleEntry(expected))
+            );
+        }
+    }
+}
+
+#[test]
+fn test_dedup() {
+    let value = bookstore_value();
+    let expr = jsonpath::parse("$..category").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap().dedup(),
+        JsonpathResult::Collection(vec![json!("reference"), json!("fiction")])
+    );
+
+    // The same node selected through overlapping names is kept once.
+    let expr = jsonpath::parse("$.store.book[0]['title','author','title']").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap().dedup(),
+        JsonpathResult::Collection(vec![json!("Sayings of the Century"), json!("Nigel Rees")])
+    );
+
+    let result = JsonpathResult::SingleEntry(json!([1, 1]));
+    assert_eq!(result.clone().dedup(), result);
+}
+
+#[test]
+fn test_flatten_result() {
+    let result = JsonpathResult::Collection(vec![
+        json!(1),
+        json!([2, [3, [4]]]),
+        json!({"a": [5]}),
+        json!([]),
+        json!("6"),
+    ]);
+    assert_eq!(
+        result.flatten(),
+        JsonpathResult::Collection(vec![
+            json!(1),
+            json!(2),
+            json!(3),
+            json!(4),
+            json!({"a": [5]}),
+            json!("6")
+        ])
+    );
+
+    let value = bookstore_value();
+    let expr = jsonpath::parse("$.store.book[*]['title','author']").unwrap();
+    let result = expr.eval(&value).unwrap();
+    assert_eq!(result.clone().flatten(), result);
+
+    let result = JsonpathResult::SingleEntry(json!([1, [2]]));
+    assert_eq!(result.clone().flatten(), result);
+}
+
+#[test]
+fn test_slice_negative_step() {
+    // Slices follow Python semantics: with a negative step, missing bounds are the ends of
+    // the array taken backward, and out of range bounds are clamped.
+    let value = json!([0, 1, 2, 3, 4]);
+    let eval = |expr: &str| jsonpath::parse(expr).unwrap().eval(&value).unwrap();
+    assert_eq!(
+        eval("$[::-1]"),
+        JsonpathResult::Collection(vec![json!(4), json!(3), json!(2), json!(1), json!(0)])
+    );
+    assert_eq!(
+        eval("$[5:0:-1]"),
+        JsonpathResult::Collection(vec![json!(4), json!(3), json!(2), json!(1)])
+    );
+    assert_eq!(eval("$[:3:-1]"), JsonpathResult::Collection(vec![json!(4)]));
+    assert_eq!(
+        eval("$[-2::-2]"),
+        JsonpathResult::Collection(vec![json!(3), json!(1)])
+    );
+    assert_eq!(eval("$[1:3:-1]"), JsonpathResult::Collection(vec![]));
+}
+
+#[test]
+fn test_bracket_notation_special_keys() {
+    let value = json!({
+        "first name": "Bob",
+        "a.b.c": 1,
+        "a": {"b": {"c": 2}},
+        "café": "☕",
+        "": "empty",
+        "it's": true,
+        "line\nbreak": 3,
+        "he said \"hi\"": 4
+    });
+    let eval = |expr: &str| jsonpath::parse(expr).unwrap().eval(&value).unwrap();
+    assert_eq!(
+        eval("$['first name']"),
+        JsonpathResult::SingleEntry(json!("Bob"))
+    );
+    assert_eq!(
+        eval("$[\"first name\"]"),
+        JsonpathResult::SingleEntry(json!("Bob"))
+    );
+    assert_eq!(eval("$['a.b.c']"), JsonpathResult::SingleEntry(json!(1)));
+    assert_eq!(eval("$.a.b.c"), JsonpathResult::SingleEntry(json!(2)));
+    assert_eq!(eval("$['café']"), JsonpathResult::SingleEntry(json!("☕")));
+    assert_eq!(eval("$['']"), JsonpathResult::SingleEntry(json!("empty")));
+    assert_eq!(
+        eval("$['it\\'s']"),
+        JsonpathResult::SingleEntry(json!(true))
+    );
+    assert_eq!(
+        eval("$[\"it's\"]"),
+        JsonpathResult::SingleEntry(json!(true))
+    );
+    assert_eq!(
+        eval("$[\"it\\'s\"]"),
+        JsonpathResult::SingleEntry(json!(true))
+    );
+    assert_eq!(
+        eval("$['line\\nbreak']"),
+        JsonpathResult::SingleEntry(json!(3))
+    );
+    assert_eq!(
+        eval("$['he said \\\"hi\\\"']"),
+        JsonpathResult::SingleEntry(json!(4))
+    );
+}
+
 #[test]
 fn test_parsing_error() {
     // not supported yet
@@ -43,7 +43,7 @@
 mod html;
 mod http;
 mod json;
-mod jsonpath;
+pub mod jsonpath;
 pub mod output;
 pub mod report;
 pub mod runner;
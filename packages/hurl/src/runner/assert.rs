@@ -15,16 +15,21 @@
  * limitations under the License.
  *
  */
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 use hurl_core::ast::*;
+use hurl_core::parser::{parse_json, Reader};
 
 use crate::http;
+use crate::runner::body::eval_file;
 use crate::runner::core::{Error, RunnerError, *};
 use crate::runner::filter::eval_filters;
 use crate::runner::predicate::eval_predicate;
 use crate::runner::query::eval_query;
+use crate::runner::template::eval_template;
 use crate::runner::value::Value;
+use crate::util::path::ContextDir;
 
 impl AssertResult {
     /// Evaluates an assert and returns `None` if assert is succeeded or an `Error` if failed.
@@ -55,7 +60,7 @@ impl AssertResult {
                 expected,
                 source_info,
             } => {
-                if actual == expected {
+                if status_matches(expected, *actual) {
                     None
                 } else {
                     Some(Error {
@@ -128,10 +133,21 @@ impl AssertResult {
     }
 }
 
+/// Returns true if the status `code` of a response matches the `expected` status.
+fn status_matches(expected: &StatusValue, code: u64) -> bool {
+    match expected {
+        StatusValue::Any => true,
+        StatusValue::Specific(value) => code == *value,
+        StatusValue::Class(class) => code / 100 == u64::from(*class),
+        StatusValue::Range(start, end) => (*start..=*end).contains(&code),
+    }
+}
+
 pub fn eval_assert(
     assert: &Assert,
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
+    context_dir: &ContextDir,
 ) -> AssertResult {
     let query_result = eval_query(&assert.query, variables, http_response);
 
@@ -165,7 +181,10 @@ pub fn eval_assert(
     let source_info = &assert.predicate.predicate_func.source_info;
     let predicate_result = match &actual {
         Err(_) => None,
-        Ok(actual) => Some(eval_predicate(&assert.predicate, variables, actual)),
+        Ok(actual) => Some(
+            resolve_schema_file(&assert.predicate, variables, context_dir)
+                .and_then(|predicate| eval_predicate(&predicate, variables, actual)),
+        ),
     };
 
     AssertResult::Explicit {
@@ -175,13 +194,51 @@ pub fn eval_assert(
     }
 }
 
+/// Returns the `predicate`, with the schema of a `matchesSchema file "schema.json"` predicate
+/// read from its file (relative to `context_dir`) as an inline schema.
+fn resolve_schema_file<'a>(
+    predicate: &'a Predicate,
+    variables: &HashMap<String, Value>,
+    context_dir: &ContextDir,
+) -> Result<Cow<'a, Predicate>, Error> {
+    let PredicateFuncValue::MatchSchema {
+        space0,
+        value: SchemaSource::File { filename, .. },
+    } = &predicate.predicate_func.value
+    else {
+        return Ok(Cow::Borrowed(predicate));
+    };
+    let filename = Filename {
+        value: eval_template(filename, variables)?,
+        source_info: filename.source_info.clone(),
+    };
+    let schema = eval_file(&filename, context_dir)?;
+    let schema = String::from_utf8_lossy(&schema);
+    let mut reader = Reader::new(schema.trim());
+    let Ok(schema) = parse_json(&mut reader) else {
+        return Err(Error {
+            source_info: filename.source_info,
+            inner: RunnerError::InvalidJson {
+                value: schema.to_string(),
+            },
+            assert: false,
+        });
+    };
+    let mut predicate = predicate.clone();
+    predicate.predicate_func.value = PredicateFuncValue::MatchSchema {
+        space0: space0.clone(),
+        value: SchemaSource::Inline(schema),
+    };
+    Ok(Cow::Owned(predicate))
+}
+
 #[cfg(test)]
 pub mod tests {
     use hurl_core::ast::SourceInfo;
 
     use super::super::query;
     use super::*;
-    use crate::http::xml_three_users_http_response;
+    use crate::http::{json_http_response, xml_three_users_http_response};
 
     // xpath //user countEquals 3
     pub fn assert_count_user() -> Assert {
@@ -226,6 +283,7 @@ pub mod tests {
                 &assert_count_user(),
                 &variables,
                 &xml_three_users_http_response(),
+                &ContextDir::default(),
             ),
             AssertResult::Explicit {
                 actual: Ok(Some(Value::Nodeset(3))),
@@ -234,4 +292,82 @@ pub mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_eval_schema_file() {
+        let file = hurl_core::parser::parse_hurl_file(
+            r#"GET http://localhost
+HTTP 200
+[Asserts]
+jsonpath "$.errors[0]" matchesSchema file "tests/schema.json"
+jsonpath "$" matchesSchema file "tests/schema.json"
+jsonpath "$" matchesSchema file "tests/unknown.json"
+"#,
+        )
+        .unwrap();
+        let asserts = file.all_asserts();
+        let variables = HashMap::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let context_dir = ContextDir::new(current_dir.as_path(), std::path::Path::new(""));
+        let predicate_result = |index: usize| match eval_assert(
+            asserts[index].1,
+            &variables,
+            &json_http_response(),
+            &context_dir,
+        ) {
+            AssertResult::Explicit {
+                predicate_result, ..
+            } => predicate_result.unwrap(),
+            _ => panic!("explicit assert expected"),
+        };
+
+        assert!(predicate_result(0).is_ok());
+        assert!(matches!(
+            predicate_result(1).unwrap_err().inner,
+            RunnerError::AssertFailure { .. }
+        ));
+        assert_eq!(
+            predicate_result(2).unwrap_err().inner,
+            RunnerError::FileReadAccess {
+                value: "tests/unknown.json".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_status_range() {
+        let assert_result = |actual| AssertResult::Status {
+            actual,
+            expected: StatusValue::Range(200, 204),
+            source_info: SourceInfo::new(1, 10, 1, 17),
+        };
+        assert!(assert_result(200).error().is_none());
+        assert!(assert_result(202).error().is_none());
+        assert!(assert_result(204).error().is_none());
+        assert_eq!(
+            assert_result(205).error().unwrap().inner,
+            RunnerError::AssertStatus {
+                actual: "205".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_status_class() {
+        let assert_result = |actual| AssertResult::Status {
+            actual,
+            expected: StatusValue::Class(2),
+            source_info: SourceInfo::new(1, 10, 1, 13),
+        };
+        assert!(assert_result(200).error().is_none());
+        assert!(assert_result(204).error().is_none());
+        assert!(assert_result(299).error().is_none());
+        assert_eq!(
+            assert_result(301).error().unwrap().inner,
+            RunnerError::AssertStatus {
+                actual: "301".to_string()
+            }
+        );
+        assert!(assert_result(199).error().is_some());
+    }
 }
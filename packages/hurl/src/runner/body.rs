@@ -33,6 +33,18 @@ pub fn eval_body(
     variables: &HashMap<String, Value>,
     context_dir: &ContextDir,
 ) -> Result<http::Body, Error> {
+    // A reference to a previous response is resolved before running the entry, it's
+    // still there if the referenced entry has no response.
+    if let Bytes::FromResponse(index) = body.value {
+        return Err(Error {
+            source_info: SourceInfo {
+                start: body.space0.source_info.end.clone(),
+                end: body.space0.source_info.end.clone(),
+            },
+            inner: RunnerError::ResponseBodyNotFound { index },
+            assert: false,
+        });
+    }
     eval_bytes(&body.value, variables, context_dir)
 }
 
@@ -46,10 +58,21 @@ pub fn eval_bytes(
             let value = eval_template(value, variables)?;
             Ok(http::Body::Text(value))
         }
-        Bytes::MultilineString(value) => {
-            let value = eval_multiline(value, variables)?;
+        Bytes::Template(value) => {
+            let value = eval_template_body(value, variables)?;
             Ok(http::Body::Text(value))
         }
+        Bytes::MultilineString(multiline) => {
+            let value = eval_multiline(multiline, variables)?;
+            match multiline.charset() {
+                None
+                | Some(MultilineCharset {
+                    value: Charset::Utf8,
+                    ..
+                }) => Ok(http::Body::Text(value)),
+                Some(charset) => Ok(http::Body::Binary(encode(&value, &charset.value))),
+            }
+        }
         Bytes::Xml(value) => Ok(http::Body::Text(value.clone())),
         Bytes::Json(value) => {
             let value = eval_json_value(value, variables, true)?;
@@ -61,6 +84,52 @@ pub fn eval_bytes(
             let value = eval_file(filename, context_dir)?;
             Ok(http::Body::File(value, filename.value.clone()))
         }
+        Bytes::FromResponse(_) => panic!("expect a resolved response body"),
+    }
+}
+
+/// Renders a template body, like `{{payload}}`, given a map of variables.
+///
+/// Objects and lists (captured from a JSON response for instance) can not be rendered in a
+/// template: a body made of such a variable is sent as JSON.
+pub fn eval_template_body(
+    template: &Template,
+    variables: &HashMap<String, Value>,
+) -> Result<String, Error> {
+    match template_json_value(template, variables) {
+        Some(value) => Ok(value.to_json().to_string()),
+        None => eval_template(template, variables),
+    }
+}
+
+/// Returns the object or list value of a template body made of a single variable, like
+/// `{{payload}}`, which is sent as JSON, or `None` if the body is rendered as a plain template.
+pub fn template_json_value<'a>(
+    template: &Template,
+    variables: &'a HashMap<String, Value>,
+) -> Option<&'a Value> {
+    match template.elements.as_slice() {
+        [TemplateElement::Expression(expr)] => match variables.get(&expr.variable.name) {
+            Some(value @ (Value::Object(_) | Value::List(_))) => Some(value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Encodes the string `s` with `charset`.
+///
+/// `utf-16` is encoded in big endian with a byte order mark.
+fn encode(s: &str, charset: &Charset) -> Vec<u8> {
+    match charset {
+        Charset::Utf8 => s.as_bytes().to_vec(),
+        Charset::Utf16 => {
+            let mut bytes = vec![0xfe, 0xff];
+            bytes.extend(s.encode_utf16().flat_map(|u| u.to_be_bytes()));
+            bytes
+        }
+        Charset::Utf16Be => s.encode_utf16().flat_map(|u| u.to_be_bytes()).collect(),
+        Charset::Utf16Le => s.encode_utf16().flat_map(|u| u.to_le_bytes()).collect(),
     }
 }
 
@@ -123,6 +192,57 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_body_multiline_charset() {
+        let whitespace = Whitespace {
+            value: String::from(""),
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+        let text = |charset: Option<Charset>| {
+            Bytes::MultilineString(MultilineString::Text(Text {
+                charset: charset.map(|value| MultilineCharset {
+                    space0: whitespace.clone(),
+                    value,
+                }),
+                content_type: None,
+                space: whitespace.clone(),
+                newline: whitespace.clone(),
+                value: Template {
+                    delimiter: None,
+                    elements: vec![TemplateElement::String {
+                        value: "hé".to_string(),
+                        encoded: "hé".to_string(),
+                    }],
+                    source_info: SourceInfo::new(0, 0, 0, 0),
+                },
+            }))
+        };
+
+        let variables = HashMap::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let context_dir = ContextDir::new(current_dir.as_path(), Path::new(""));
+        assert_eq!(
+            eval_bytes(&text(None), &variables, &context_dir).unwrap(),
+            http::Body::Text("hé".to_string())
+        );
+        assert_eq!(
+            eval_bytes(&text(Some(Charset::Utf8)), &variables, &context_dir).unwrap(),
+            http::Body::Text("hé".to_string())
+        );
+        assert_eq!(
+            eval_bytes(&text(Some(Charset::Utf16)), &variables, &context_dir).unwrap(),
+            http::Body::Binary(vec![0xfe, 0xff, 0x00, 0x68, 0x00, 0xe9])
+        );
+        assert_eq!(
+            eval_bytes(&text(Some(Charset::Utf16Be)), &variables, &context_dir).unwrap(),
+            http::Body::Binary(vec![0x00, 0x68, 0x00, 0xe9])
+        );
+        assert_eq!(
+            eval_bytes(&text(Some(Charset::Utf16Le)), &variables, &context_dir).unwrap(),
+            http::Body::Binary(vec![0x68, 0x00, 0xe9, 0x00])
+        );
+    }
+
     #[test]
     pub fn test_body_file_error() {
         // file, data.bin;
@@ -17,7 +17,7 @@
  */
 use std::path::PathBuf;
 
-use hurl_core::ast::SourceInfo;
+use hurl_core::ast::{SourceInfo, StatusValue};
 
 use crate::http::{Call, Cookie};
 use crate::runner::value::Value;
@@ -72,7 +72,7 @@ pub enum AssertResult {
     },
     Status {
         actual: u64,
-        expected: u64,
+        expected: StatusValue,
         source_info: SourceInfo,
     },
     Header {
@@ -164,7 +164,7 @@ pub enum RunnerError {
         expected: String,
         type_mismatch: bool,
     },
-    InvalidRegex(),
+    InvalidRegex(String),
 
     AssertHeaderValueError {
         actual: String,
@@ -187,9 +187,13 @@ pub enum RunnerError {
     UnauthorizedFileAccess {
         path: PathBuf,
     },
+    ResponseBodyNotFound {
+        index: usize,
+    },
 
     // Filter
     FilterMissingInput {},
     FilterInvalidInput(String),
     FilterRegexNoCapture {},
+    FilterJwtClaimNotFound(String),
 }
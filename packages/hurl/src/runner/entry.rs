@@ -148,6 +148,8 @@ pub fn run(
         }
     };
 
+    // Captures are computed before asserts, whatever the order of the [Captures] and [Asserts]
+    // sections, so that asserts can use the captured variables.
     let captures = match &entry.response {
         None => vec![],
         Some(response_spec) => match eval_captures(response_spec, http_response, variables) {
@@ -304,63 +306,119 @@ pub fn get_entry_options(
 
     for section in &entry.request.sections {
         if let SectionValue::Options(options) = &section.value {
-            for option in options {
-                match option {
-                    EntryOption::CaCertificate(option) => {
-                        runner_options.cacert_file = Some(option.filename.value.clone());
-                        logger.debug(format!("cacert: {}", option.filename.value).as_str());
-                    }
-                    EntryOption::ClientCert(option) => {
-                        runner_options.client_cert_file = Some(option.filename.value.clone());
-                        logger.debug(format!("cert: {}", option.filename.value).as_str());
-                    }
-                    EntryOption::ClientKey(option) => {
-                        runner_options.client_key_file = Some(option.filename.value.clone());
-                        logger.debug(format!("key: {}", option.filename.value).as_str());
-                    }
-                    EntryOption::Compressed(option) => {
-                        runner_options.compressed = option.value;
-                        logger.debug(format!("compressed: {}", option.value).as_str());
-                    }
-                    EntryOption::FollowLocation(option) => {
-                        runner_options.follow_location = option.value;
-                        logger.debug(format!("location: {}", option.value).as_str());
-                    }
-                    EntryOption::Insecure(option) => {
-                        runner_options.insecure = option.value;
-                        logger.debug(format!("insecure: {}", option.value).as_str());
-                    }
-                    EntryOption::MaxRedirect(option) => {
-                        runner_options.max_redirect = Some(option.value);
-                        logger.debug(format!("max-redirs: {}", option.value).as_str());
-                    }
-                    EntryOption::Retry(option) => {
-                        runner_options.retry = option.value;
-                        logger.debug(format!("retry: {}", option.value).as_str());
-                    }
-                    EntryOption::RetryInterval(option) => {
-                        runner_options.retry_interval = Duration::from_millis(option.value);
-                        logger.debug(format!("retry-interval: {}", option.value).as_str());
-                    }
-                    EntryOption::Variable(VariableOption {
-                        value: VariableDefinition { name, value, .. },
-                        ..
-                    }) => {
-                        let value = eval_variable_value(value, variables)?;
-                        variables.insert(name.clone(), value);
-                    }
-                    EntryOption::Verbose(option) => {
-                        logger.debug(format!("verbose: {}", option.value).as_str());
-                    }
+            eval_options(options, &mut runner_options, variables, logger)?;
+        }
+    }
+    Ok(runner_options)
+}
 
-                    EntryOption::VeryVerbose(option) => {
-                        logger.debug(format!("very-verbose: {}", option.value).as_str());
-                    }
-                }
+/// Returns a new [`RunnerOptions`] based on the file `settings` and a default `runner_options`.
+/// The [`variables`] can also be updated if `variable` keys are present in the settings.
+pub fn get_settings_options(
+    settings: &Settings,
+    runner_options: &RunnerOptions,
+    variables: &mut HashMap<String, Value>,
+    logger: &Logger,
+) -> Result<RunnerOptions, Error> {
+    let mut runner_options = runner_options.clone();
+    logger.debug("");
+    logger.debug_important("Settings:");
+    eval_options(&settings.options, &mut runner_options, variables, logger)?;
+    Ok(runner_options)
+}
+
+/// Overrides `runner_options` with a list of `options`, updating `variables` for `variable` keys.
+fn eval_options(
+    options: &[EntryOption],
+    runner_options: &mut RunnerOptions,
+    variables: &mut HashMap<String, Value>,
+    logger: &Logger,
+) -> Result<(), Error> {
+    for option in options {
+        match option {
+            EntryOption::CaCertificate(option) => {
+                runner_options.cacert_file = Some(option.filename.value.clone());
+                logger.debug(format!("cacert: {}", option.filename.value).as_str());
+            }
+            EntryOption::ClientCert(option) => {
+                runner_options.client_cert_file = Some(option.filename.value.clone());
+                logger.debug(format!("cert: {}", option.filename.value).as_str());
+            }
+            EntryOption::ClientKey(option) => {
+                runner_options.client_key_file = Some(option.filename.value.clone());
+                logger.debug(format!("key: {}", option.filename.value).as_str());
+            }
+            EntryOption::Compressed(option) => {
+                runner_options.compressed = option.value;
+                logger.debug(format!("compressed: {}", option.value).as_str());
+            }
+            EntryOption::ConnectTimeout(option) => {
+                runner_options.connect_timeout = Duration::from_millis(option.value.millis());
+                logger.debug(format!("connect-timeout: {}", option.value).as_str());
+            }
+            EntryOption::Curl(option) => {
+                logger.warning(
+                    format!(
+                        "curl arguments <{}> are not supported and ignored",
+                        option.value
+                    )
+                    .as_str(),
+                );
+            }
+            EntryOption::FollowLocation(option) => {
+                runner_options.follow_location = option.value;
+                logger.debug(format!("location: {}", option.value).as_str());
+            }
+            EntryOption::Insecure(option) => {
+                runner_options.insecure = option.value;
+                logger.debug(format!("insecure: {}", option.value).as_str());
+            }
+            EntryOption::MaxRedirect(option) => {
+                runner_options.max_redirect = Some(option.value);
+                logger.debug(format!("max-redirs: {}", option.value).as_str());
+            }
+            EntryOption::NoProxy(option) => {
+                let no_proxy = option.value.join(",");
+                logger.debug(format!("no-proxy: {no_proxy}").as_str());
+                runner_options.no_proxy = Some(no_proxy);
+            }
+            EntryOption::Proxy(option) => {
+                runner_options.proxy = Some(option.value.to_string());
+                logger.debug(format!("proxy: {}", option.value).as_str());
+            }
+            EntryOption::Repeat(option) => {
+                runner_options.repeat = option.value;
+                logger.debug(format!("repeat: {}", option.value).as_str());
+            }
+            EntryOption::Retry(option) => {
+                runner_options.retry = option.value;
+                logger.debug(format!("retry: {}", option.value).as_str());
+            }
+            EntryOption::RetryInterval(option) => {
+                runner_options.retry_interval = Duration::from_millis(option.value);
+                logger.debug(format!("retry-interval: {}", option.value).as_str());
+            }
+            EntryOption::Timeout(option) => {
+                runner_options.timeout = Duration::from_millis(option.value.millis());
+                logger.debug(format!("timeout: {}", option.value).as_str());
+            }
+            EntryOption::Variable(VariableOption {
+                value: VariableDefinition { name, value, .. },
+                ..
+            }) => {
+                let value = eval_variable_value(value, variables)?;
+                variables.insert(name.clone(), value);
+            }
+            EntryOption::Verbose(option) => {
+                logger.debug(format!("verbose: {}", option.value).as_str());
+            }
+
+            EntryOption::VeryVerbose(option) => {
+                logger.debug(format!("very-verbose: {}", option.value).as_str());
             }
         }
     }
-    Ok(runner_options)
+    Ok(())
 }
 
 fn eval_variable_value(
@@ -42,7 +42,7 @@ impl Error for runner::Error {
             RunnerError::CouldNotParseResponse => "HTTP connection".to_string(),
             RunnerError::SslCertificate { .. } => "SSL certificate".to_string(),
             RunnerError::PredicateValue { .. } => "Assert - predicate value failed".to_string(),
-            RunnerError::InvalidRegex {} => "Invalid regex".to_string(),
+            RunnerError::InvalidRegex(_) => "Invalid regex".to_string(),
             RunnerError::FileReadAccess { .. } => "File read access".to_string(),
             RunnerError::QueryInvalidXml { .. } => "Invalid XML".to_string(),
             RunnerError::QueryInvalidXpathEval {} => "Invalid XPath expression".to_string(),
@@ -64,9 +64,11 @@ impl Error for runner::Error {
             RunnerError::CouldNotUncompressResponse(..) => "Decompression error".to_string(),
             RunnerError::InvalidJson { .. } => "Invalid JSON".to_string(),
             RunnerError::UnauthorizedFileAccess { .. } => "Unauthorized file access".to_string(),
+            RunnerError::ResponseBodyNotFound { .. } => "Response body not found".to_string(),
             RunnerError::FilterMissingInput { .. } => "Filter Error".to_string(),
             RunnerError::FilterInvalidInput { .. } => "Filter Error".to_string(),
             RunnerError::FilterRegexNoCapture { .. } => "Filter Error".to_string(),
+            RunnerError::FilterJwtClaimNotFound { .. } => "Filter Error".to_string(),
         }
     }
 
@@ -89,7 +91,9 @@ impl Error for runner::Error {
             RunnerError::PredicateValue(value) => {
                 format!("actual value is <{value}>")
             }
-            RunnerError::InvalidRegex {} => "regex expression is not valid".to_string(),
+            RunnerError::InvalidRegex(message) => {
+                format!("regex expression is not valid: {message}")
+            }
             RunnerError::FileReadAccess { value } => format!("file {value} can not be read"),
             RunnerError::QueryInvalidXml { .. } => {
                 "the HTTP response is not a valid XML".to_string()
@@ -159,11 +163,15 @@ impl Error for runner::Error {
                     path.to_str().unwrap()
                 )
             }
+            RunnerError::ResponseBodyNotFound { index } => {
+                format!("no response body available {index} entries before")
+            }
             RunnerError::FilterMissingInput { .. } => "missing value to apply filter".to_string(),
             RunnerError::FilterInvalidInput(message) => {
                 format!("invalid filter input: {message}")
             }
             RunnerError::FilterRegexNoCapture { .. } => "capture not found".to_string(),
+            RunnerError::FilterJwtClaimNotFound(name) => format!("claim <{name}> not found"),
         }
     }
 }
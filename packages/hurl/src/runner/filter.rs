@@ -17,6 +17,8 @@
  */
 use std::collections::HashMap;
 
+use base64::engine::general_purpose;
+use base64::Engine;
 use chrono::{NaiveDateTime, Utc};
 use hurl_core::ast::{Filter, FilterValue, RegexValue, SourceInfo, Template};
 use percent_encoding::AsciiSet;
@@ -56,6 +58,9 @@ fn eval_filter(
         }
         FilterValue::HtmlEscape => eval_html_escape(value, &filter.source_info, in_assert),
         FilterValue::HtmlUnescape => eval_html_unescape(value, &filter.source_info, in_assert),
+        FilterValue::JwtClaim { name, .. } => {
+            eval_jwt_claim(value, name, variables, &filter.source_info, in_assert)
+        }
         FilterValue::Regex {
             value: regex_value, ..
         } => eval_regex(
@@ -288,6 +293,62 @@ fn eval_html_unescape(
     }
 }
 
+/// Extracts the claim `name` from the payload of the JWT `value`, without verifying its signature.
+fn eval_jwt_claim(
+    value: &Value,
+    name: &Template,
+    variables: &HashMap<String, Value>,
+    source_info: &SourceInfo,
+    assert: bool,
+) -> Result<Value, Error> {
+    let name = eval_template(name, variables)?;
+    let token = match value {
+        Value::String(token) => token,
+        v => {
+            return Err(Error {
+                source_info: source_info.clone(),
+                inner: RunnerError::FilterInvalidInput(v._type()),
+                assert,
+            })
+        }
+    };
+    let payload = match jwt_payload(token) {
+        Some(payload) => payload,
+        None => {
+            return Err(Error {
+                source_info: source_info.clone(),
+                inner: RunnerError::FilterInvalidInput(value.display()),
+                assert,
+            })
+        }
+    };
+    match payload.get(&name) {
+        Some(claim) => Ok(Value::from_json(claim)),
+        None => Err(Error {
+            source_info: source_info.clone(),
+            inner: RunnerError::FilterJwtClaimNotFound(name),
+            assert,
+        }),
+    }
+}
+
+/// Returns the decoded JSON payload of a JWT `token`, or `None` if the token is malformed.
+///
+/// A JWT is made of a header, a payload and a signature, base64url encoded and separated by dots.
+fn jwt_payload(token: &str) -> Option<serde_json::Map<String, serde_json::Value>> {
+    let parts = token.split('.').collect::<Vec<&str>>();
+    if parts.len() != 3 {
+        return None;
+    }
+    let payload = general_purpose::URL_SAFE_NO_PAD
+        .decode(parts[1].trim_end_matches('='))
+        .ok()?;
+    match serde_json::from_slice(&payload).ok()? {
+        serde_json::Value::Object(claims) => Some(claims),
+        _ => None,
+    }
+}
+
 fn eval_replace(
     value: &Value,
     variables: &HashMap<String, Value>,
@@ -603,7 +664,10 @@ pub mod tests {
         .err()
         .unwrap();
         assert_eq!(error.source_info, SourceInfo::new(1, 7, 1, 20));
-        assert_eq!(error.inner, RunnerError::InvalidRegex {});
+        assert_eq!(
+            error.inner,
+            RunnerError::InvalidRegex("repetition operator missing expression".to_string())
+        );
     }
 
     #[test]
@@ -763,6 +827,58 @@ pub mod tests {
         }
     }
 
+    #[test]
+    pub fn eval_filter_jwt_claim() {
+        let variables = HashMap::new();
+        let filter = |name: &str| Filter {
+            source_info: SourceInfo::new(1, 1, 1, 1),
+            value: FilterValue::JwtClaim {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(0, 0, 0, 0),
+                },
+                name: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: name.to_string(),
+                        encoded: name.to_string(),
+                    }],
+                    source_info: SourceInfo::new(0, 0, 0, 0),
+                },
+            },
+        };
+        // {"alg":"none","typ":"JWT"}.{"sub":"1234567890","name":"John Doe","exp":1700000000}
+        let token = Value::String("eyJhbGciOiJub25lIiwidHlwIjoiSldUIn0.eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIiwiZXhwIjoxNzAwMDAwMDAwfQ.".to_string());
+
+        assert_eq!(
+            eval_filter(&filter("sub"), &token, &variables, false).unwrap(),
+            Value::String("1234567890".to_string())
+        );
+        assert_eq!(
+            eval_filter(&filter("exp"), &token, &variables, false).unwrap(),
+            Value::Integer(1700000000)
+        );
+        assert_eq!(
+            eval_filter(&filter("iss"), &token, &variables, false)
+                .err()
+                .unwrap()
+                .inner,
+            RunnerError::FilterJwtClaimNotFound("iss".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter("sub"),
+                &Value::String("not.a-jwt".to_string()),
+                &variables,
+                false
+            )
+            .err()
+            .unwrap()
+            .inner,
+            RunnerError::FilterInvalidInput("string <not.a-jwt>".to_string())
+        );
+    }
+
     #[test]
     pub fn eval_filter_nth() {
         let variables = HashMap::new();
@@ -23,6 +23,7 @@ use hurl_core::ast::VersionValue::VersionAnyLegacy;
 use hurl_core::ast::*;
 use hurl_core::error::Error;
 use hurl_core::parser;
+use hurl_core::parser::ParseMode;
 
 use crate::http;
 use crate::http::Call;
@@ -78,14 +79,15 @@ pub fn run(
     let logger = Logger::from(logger_options);
 
     // Try to parse the content
-    let hurl_file = parser::parse_hurl_file(content);
-    let hurl_file = match hurl_file {
+    let hurl_file = parser::parse_hurl_file_with_mode(content, ParseMode::Strict);
+    let (hurl_file, warnings) = match hurl_file {
         Ok(h) => h,
         Err(e) => {
             logger.error_rich(content, &e);
             return Err(e.description());
         }
     };
+    warn_parsing(&warnings, &logger);
 
     log_run_info(&hurl_file, runner_options, variables, &logger);
 
@@ -94,8 +96,24 @@ pub fn run(
     let mut http_client = http::Client::new(cookie_input_file);
     let mut entries = vec![];
     let mut variables = variables.clone();
+
+    // File settings are evaluated once, and are the default options of every entry.
+    let runner_options = match &hurl_file.settings {
+        Some(settings) => {
+            match entry::get_settings_options(settings, runner_options, &mut variables, &logger) {
+                Ok(options) => options,
+                Err(e) => {
+                    logger.error_rich(content, &e);
+                    return Err(e.description());
+                }
+            }
+        }
+        None => runner_options.clone(),
+    };
+    let runner_options = &runner_options;
     let mut entry_index = 1;
     let mut retry_count = 1;
+    let mut repeat_count = 1;
     let n = if let Some(to_entry) = runner_options.to_entry {
         to_entry
     } else {
@@ -108,6 +126,8 @@ pub fn run(
             break;
         }
         let entry = &hurl_file.entries[entry_index - 1];
+        let resolved_entry = resolve_response_bodies(entry, entry_index, &entries);
+        let entry = resolved_entry.as_ref().unwrap_or(entry);
 
         // We compute the new logger for this entry, before entering into the `run`
         // function because entry options can modify the logger and we want the preamble
@@ -205,9 +225,25 @@ pub fn run(
             break;
         }
 
+        // The entry is executed again until its repeat count is reached.
+        let repeat = match &options {
+            Ok(options) => options.repeat,
+            Err(_) => runner_options.repeat,
+        };
+        if repeat_count < repeat {
+            logger.debug("");
+            logger.debug_important(
+                format!("Repeat entry {entry_index} (x{})", repeat_count + 1).as_str(),
+            );
+            repeat_count += 1;
+            retry_count = 1;
+            continue;
+        }
+
         // We pass to the next entry
         entry_index += 1;
         retry_count = 1;
+        repeat_count = 1;
     }
 
     let time_in_ms = start.elapsed().as_millis();
@@ -221,6 +257,100 @@ pub fn run(
     })
 }
 
+/// Returns a copy of `entry` where the bodies referencing a previous response (`from-response N;`)
+/// are replaced by the bytes of this response, or `None` if `entry` has no such body.
+///
+/// A resolved request body keeps the content type of the referenced response, through a
+/// `Content-Type` header, unless the request already sets one. Bodies whose referenced entry has
+/// not been run, or has no response, are left unresolved.
+fn resolve_response_bodies(
+    entry: &Entry,
+    entry_index: usize,
+    entries: &[EntryResult],
+) -> Option<Entry> {
+    // Resolves `body` and returns the content type of the referenced response, if any.
+    let resolve = |body: &mut Body| -> Option<String> {
+        let Bytes::FromResponse(index) = body.value else {
+            return None;
+        };
+        let response = entry_index
+            .checked_sub(index)
+            .and_then(|i| entries.iter().rev().find(|e| e.entry_index == i))
+            .and_then(|e| e.calls.last())
+            .map(|call| &call.response)?;
+        let value = response.uncompress_body().ok()?;
+        let space = Whitespace {
+            value: String::new(),
+            source_info: body.space0.source_info.clone(),
+        };
+        body.value = Bytes::Hex(Hex {
+            space0: space.clone(),
+            encoded: hex::encode(&value),
+            value,
+            space1: space,
+        });
+        response.content_type()
+    };
+    let is_reference =
+        |body: Option<&Body>| matches!(body, Some(b) if matches!(b.value, Bytes::FromResponse(_)));
+    let response_body = entry.response.as_ref().and_then(|r| r.body.as_ref());
+    if !is_reference(entry.request.body.as_ref()) && !is_reference(response_body) {
+        return None;
+    }
+    let mut resolved = entry.clone();
+    if let Some(body) = &mut resolved.request.body {
+        let has_content_type = resolved
+            .request
+            .headers
+            .iter()
+            .any(|h| h.key.value.eq_ignore_ascii_case("Content-Type"));
+        if let Some(content_type) = resolve(body) {
+            if !has_content_type {
+                let source_info = body.space0.source_info.clone();
+                let header = content_type_header(&content_type, source_info);
+                resolved.request.headers.push(header);
+            }
+        }
+    }
+    if let Some(body) = resolved.response.as_mut().and_then(|r| r.body.as_mut()) {
+        resolve(body);
+    }
+    Some(resolved)
+}
+
+/// Returns a `Content-Type` header with the value `content_type`, located at `source_info`.
+fn content_type_header(content_type: &str, source_info: SourceInfo) -> KeyValue {
+    let space = Whitespace {
+        value: String::new(),
+        source_info: source_info.clone(),
+    };
+    KeyValue {
+        line_terminators: vec![],
+        space0: space.clone(),
+        key: EncodedString {
+            value: "Content-Type".to_string(),
+            encoded: "Content-Type".to_string(),
+            quotes: false,
+            source_info: source_info.clone(),
+        },
+        space1: space.clone(),
+        space2: space.clone(),
+        value: Template {
+            delimiter: None,
+            elements: vec![TemplateElement::String {
+                value: content_type.to_string(),
+                encoded: content_type.to_string(),
+            }],
+            source_info,
+        },
+        line_terminator0: LineTerminator {
+            space0: space.clone(),
+            comment: None,
+            newline: space,
+        },
+    }
+}
+
 /// Returns `true` if all the entries ar successful, `false` otherwise.
 ///
 /// For a given list of entry, only the last one on the same index is checked.
@@ -242,6 +372,17 @@ fn is_success(entries: &[EntryResult]) -> bool {
     true
 }
 
+/// Logs the recoverable issues found while parsing, like duplicated headers.
+fn warn_parsing(warnings: &[parser::Error], logger: &Logger) {
+    let filename = &logger.filename;
+    for warning in warnings {
+        let line = warning.pos.line;
+        let column = warning.pos.column;
+        let message = warning.fixme();
+        logger.warning(format!("{filename}:{line}:{column} {message}").as_str());
+    }
+}
+
 /// Logs deprecated syntax and provides alternatives.
 fn warn_deprecated(entry: &Entry, logger: &Logger) {
     // HTTP/* is used instead of HTTP.
@@ -377,3 +518,79 @@ fn get_entry_logger(entry: &Entry, logger_options: &LoggerOptions) -> Logger {
         .build();
     Logger::from(&entry_logger_options)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{Header, Response};
+
+    fn entry_result(entry_index: usize, response: Response) -> EntryResult {
+        EntryResult {
+            entry_index,
+            calls: vec![Call {
+                request: http::Request {
+                    url: "http://localhost/a".to_string(),
+                    method: "GET".to_string(),
+                    headers: vec![],
+                    body: vec![],
+                },
+                response,
+                timings: http::Timings::default(),
+            }],
+            captures: vec![],
+            asserts: vec![],
+            errors: vec![],
+            time_in_ms: 0,
+            compressed: false,
+        }
+    }
+
+    #[test]
+    fn test_resolve_response_bodies() {
+        let s = r#"GET http://localhost/a
+
+POST http://localhost/b
+from-response 1;
+
+POST http://localhost/c
+Content-Type: text/plain
+from-response 2;
+"#;
+        let hurl_file = parser::parse_hurl_file(s).unwrap();
+        let response = Response {
+            headers: vec![Header::new("Content-Type", "application/json")],
+            body: b"{\"id\":1}".to_vec(),
+            ..Default::default()
+        };
+        let entries = vec![entry_result(1, response)];
+
+        assert!(resolve_response_bodies(&hurl_file.entries[0], 1, &entries).is_none());
+
+        let resolved = resolve_response_bodies(&hurl_file.entries[1], 2, &entries).unwrap();
+        let Some(Body {
+            value: Bytes::Hex(hex),
+            ..
+        }) = &resolved.request.body
+        else {
+            panic!("expecting a resolved body");
+        };
+        assert_eq!(hex.value, b"{\"id\":1}");
+        let headers = &resolved.request.headers;
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers[0].key.value, "Content-Type");
+        assert_eq!(headers[0].value.to_string(), "application/json");
+
+        // An explicit content type is kept.
+        let resolved = resolve_response_bodies(&hurl_file.entries[2], 3, &entries).unwrap();
+        let headers = &resolved.request.headers;
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers[0].value.to_string(), "text/plain");
+
+        // The referenced entry has not been run.
+        let resolved = resolve_response_bodies(&hurl_file.entries[1], 2, &[]).unwrap();
+        assert!(matches!(
+            resolved.request.body.unwrap().value,
+            Bytes::FromResponse(1)
+        ));
+    }
+}
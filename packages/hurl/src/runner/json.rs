@@ -40,7 +40,9 @@ pub fn eval_json_value(
             Ok(format!("\"{s}\""))
         }
         JsonValue::Boolean(v) => Ok(v.to_string()),
-        JsonValue::List { space0, elements } => {
+        JsonValue::List {
+            space0, elements, ..
+        } => {
             let mut elems_string = vec![];
             for element in elements {
                 let s = eval_json_list_element(element, variables, keep_whitespace)?;
@@ -52,7 +54,9 @@ pub fn eval_json_value(
                 Ok(format!("[{}]", elems_string.join(",")))
             }
         }
-        JsonValue::Object { space0, elements } => {
+        JsonValue::Object {
+            space0, elements, ..
+        } => {
             let mut elems_string = vec![];
             for element in elements {
                 let s = eval_json_object_element(element, variables, keep_whitespace)?;
@@ -93,7 +97,7 @@ pub fn eval_json_value(
 
 /// Evaluates a JSON list to a string given a set of `variables`.
 /// If `keep_whitespace` is true, whitespace is preserved from the JSonValue, otherwise
-/// it is trimmed.
+/// it is trimmed. Comments are never sent.
 fn eval_json_list_element(
     element: &JsonListElement,
     variables: &HashMap<String, Value>,
@@ -221,6 +225,7 @@ mod tests {
             space0: "\n    ".to_string(),
             elements: vec![JsonObjectElement {
                 space0: "".to_string(),
+                comments: vec![],
                 name: Template {
                     delimiter: None,
                     elements: vec![TemplateElement::String {
@@ -241,6 +246,7 @@ mod tests {
                 }),
                 space3: "\n".to_string(),
             }],
+            comments: vec![],
         }
     }
 
@@ -290,6 +296,7 @@ mod tests {
                 &JsonValue::List {
                     space0: "".to_string(),
                     elements: vec![],
+                    comments: vec![],
                 },
                 &variables,
                 true,
@@ -305,20 +312,24 @@ mod tests {
                     elements: vec![
                         JsonListElement {
                             space0: "".to_string(),
+                            comments: vec![],
                             value: JsonValue::Number("1".to_string()),
                             space1: "".to_string(),
                         },
                         JsonListElement {
                             space0: " ".to_string(),
+                            comments: vec![],
                             value: JsonValue::Number("-2".to_string()),
                             space1: "".to_string(),
                         },
                         JsonListElement {
                             space0: " ".to_string(),
+                            comments: vec![],
                             value: JsonValue::Number("3.0".to_string()),
                             space1: "".to_string(),
                         },
                     ],
+                    comments: vec![],
                 },
                 &variables,
                 true
@@ -342,15 +353,18 @@ mod tests {
                     elements: vec![
                         JsonListElement {
                             space0: "".to_string(),
+                            comments: vec![],
                             value: JsonValue::String(template),
                             space1: "".to_string(),
                         },
                         JsonListElement {
                             space0: " ".to_string(),
+                            comments: vec![],
                             value: json_hello_world_value(),
                             space1: "".to_string(),
                         },
                     ],
+                    comments: vec![],
                 },
                 &variables,
                 true
@@ -368,6 +382,7 @@ mod tests {
                 &JsonValue::Object {
                     space0: "".to_string(),
                     elements: vec![],
+                    comments: vec![],
                 },
                 &variables,
                 true
@@ -475,4 +490,30 @@ mod tests {
             r#"{"firstName":"John"}"#.to_string()
         );
     }
+
+    #[test]
+    fn test_comments_not_evaluated() {
+        let variables = HashMap::new();
+        let value = JsonValue::List {
+            space0: "".to_string(),
+            elements: vec![JsonListElement {
+                space0: " ".to_string(),
+                comments: vec![JsonComment {
+                    value: " one".to_string(),
+                    space0: "\n ".to_string(),
+                }],
+                value: JsonValue::Number("1".to_string()),
+                space1: "".to_string(),
+            }],
+            comments: vec![],
+        };
+        assert_eq!(
+            eval_json_value(&value, &variables, true).unwrap(),
+            "[ 1]".to_string()
+        );
+        assert_eq!(
+            eval_json_value(&value, &variables, false).unwrap(),
+            "[1]".to_string()
+        );
+    }
 }
@@ -60,11 +60,12 @@ mod tests {
     use std::collections::HashMap;
 
     use hurl_core::ast::{
-        GraphQl, GraphQlVariables, JsonObjectElement, JsonValue, MultilineString, SourceInfo,
-        Template, TemplateElement, Whitespace,
+        Expr, GraphQl, GraphQlVariables, JsonObjectElement, JsonValue, MultilineString, SourceInfo,
+        Template, TemplateElement, Variable, Whitespace,
     };
 
     use crate::runner::multiline::eval_multiline;
+    use crate::runner::Value;
 
     fn whitespace() -> Whitespace {
         Whitespace {
@@ -130,6 +131,7 @@ mod tests {
                 elements: vec![
                     JsonObjectElement {
                         space0: "".to_string(),
+                        comments: vec![],
                         name: Template {
                             delimiter: Some('"'),
                             elements: vec![TemplateElement::String {
@@ -152,6 +154,7 @@ mod tests {
                     },
                     JsonObjectElement {
                         space0: "".to_string(),
+                        comments: vec![],
                         name: Template {
                             delimiter: Some('"'),
                             elements: vec![TemplateElement::String {
@@ -166,6 +169,7 @@ mod tests {
                         space3: "".to_string(),
                     },
                 ],
+                comments: vec![],
             },
             whitespace: whitespace(),
         };
@@ -186,4 +190,45 @@ mod tests {
         let body = eval_multiline(&multiline, &hurl_variables).unwrap();
         assert_eq!(body, r#"{"query":"{\n  human(id: \"1000\") {\n    name\n    height(unit: FOOT)\n  }\n}","variables":{"episode":"JEDI","withFriends":false}}"#.to_string())
     }
+
+    #[test]
+    fn eval_graphql_multiline_with_template() {
+        let mut variables = HashMap::new();
+        variables.insert("id".to_string(), Value::String("1000".to_string()));
+        let multiline = MultilineString::GraphQl(GraphQl {
+            space: whitespace(),
+            newline: newline(),
+            value: Template {
+                delimiter: None,
+                elements: vec![
+                    TemplateElement::String {
+                        value: "{ human(id: \"".to_string(),
+                        encoded: "{ human(id: \"".to_string(),
+                    },
+                    TemplateElement::Expression(Expr {
+                        space0: Whitespace {
+                            value: String::new(),
+                            source_info: empty_source_info(),
+                        },
+                        variable: Variable {
+                            name: "id".to_string(),
+                            source_info: empty_source_info(),
+                        },
+                        space1: Whitespace {
+                            value: String::new(),
+                            source_info: empty_source_info(),
+                        },
+                    }),
+                    TemplateElement::String {
+                        value: "\") { name } }\n".to_string(),
+                        encoded: "\") { name } }\n".to_string(),
+                    },
+                ],
+                source_info: empty_source_info(),
+            },
+            variables: None,
+        });
+        let body = eval_multiline(&multiline, &variables).unwrap();
+        assert_eq!(body, r#"{"query":"{ human(id: \"1000\") { name } }"}"#);
+    }
 }
@@ -17,13 +17,16 @@
  */
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt;
 
 use hurl_core::ast::*;
+use hurl_core::parser::regex_error_message;
 use regex;
 
 use crate::runner::core::{Error, PredicateResult};
+use crate::runner::json::eval_json_value;
 use crate::runner::predicate_value::eval_predicate_value;
-use crate::runner::template::eval_template;
+use crate::runner::template::{eval_expression, eval_template};
 use crate::runner::value::Value;
 use crate::runner::RunnerError;
 
@@ -186,15 +189,29 @@ fn expected(
             Ok(format!("less than or equals to <{}>", value.expected()))
         }
 
-        PredicateFuncValue::CountEqual {
-            value: expected, ..
-        } => {
-            let expected = if let PredicateValue::Integer(expected) = expected {
-                expected
-            } else {
-                panic!();
-            };
-            Ok(format!("count equals to <{expected}>"))
+        PredicateFuncValue::CountEqual { value, .. } => {
+            let value = eval_predicate_value(value, variables)?;
+            Ok(format!("count equals to <{value}>"))
+        }
+        PredicateFuncValue::CountGreaterThan { value, .. } => {
+            let value = eval_predicate_value(value, variables)?;
+            Ok(count_expected("greater than", value))
+        }
+        PredicateFuncValue::CountGreaterThanOrEqual { value, .. } => {
+            let value = eval_predicate_value(value, variables)?;
+            Ok(count_expected("greater than or equals to", value))
+        }
+        PredicateFuncValue::CountLessThan { value, .. } => {
+            let value = eval_predicate_value(value, variables)?;
+            Ok(count_expected("less than", value))
+        }
+        PredicateFuncValue::CountLessThanOrEqual { value, .. } => {
+            let value = eval_predicate_value(value, variables)?;
+            Ok(count_expected("less than or equals to", value))
+        }
+        PredicateFuncValue::LengthEqual { value, .. } => {
+            let value = eval_predicate_value(value, variables)?;
+            Ok(format!("length equals to <{value}>"))
         }
         PredicateFuncValue::StartWith {
             value: expected, ..
@@ -224,13 +241,54 @@ fn expected(
             let expected = eval_predicate_value_template(expected, variables)?;
             Ok(format!("matches regex <{expected}>"))
         }
+        PredicateFuncValue::EqualIgnoreOrder { value, .. } => {
+            let value = eval_json_value(value, variables, false)?;
+            Ok(format!("equals ignoring order <{value}>"))
+        }
+        PredicateFuncValue::MatchSchema { .. } => Ok("matches schema".to_string()),
+        PredicateFuncValue::MatchTemplate { value, .. } => {
+            let value = eval_template(value, variables)?;
+            Ok(format!("matches template <{value}>"))
+        }
+        PredicateFuncValue::MatchAll { value, .. } => {
+            let value = eval_json_value(value, variables, false)?;
+            Ok(format!("matches all regexes <{value}>"))
+        }
+        PredicateFuncValue::MatchAny { value, .. } => {
+            let value = eval_json_value(value, variables, false)?;
+            Ok(format!("matches any regex <{value}>"))
+        }
+        PredicateFuncValue::IsCloseTo { value, epsilon, .. } => {
+            Ok(close_to_expected(value, epsilon))
+        }
         PredicateFuncValue::IsInteger {} => Ok("integer".to_string()),
         PredicateFuncValue::IsFloat {} => Ok("float".to_string()),
+        PredicateFuncValue::IsNumber {} => Ok("number".to_string()),
         PredicateFuncValue::IsBoolean {} => Ok("boolean".to_string()),
         PredicateFuncValue::IsString {} => Ok("string".to_string()),
         PredicateFuncValue::IsCollection {} => Ok("collection".to_string()),
+        PredicateFuncValue::IsSorted {} => Ok("sorted".to_string()),
+        PredicateFuncValue::IsSortedDescending {} => Ok("sorted descending".to_string()),
         PredicateFuncValue::Exist {} => Ok("something".to_string()),
         PredicateFuncValue::IsEmpty {} => Ok("empty".to_string()),
+        PredicateFuncValue::IsValidJson {} => Ok("valid JSON".to_string()),
+    }
+}
+
+fn close_to_expected(value: &PredicateValue, epsilon: &PredicateValue) -> String {
+    let (_, value) = predicate_number(value);
+    let (_, epsilon) = predicate_number(epsilon);
+    format!("close to <{value}> within <{epsilon}>")
+}
+
+/// Returns the value of a number predicate value and its representation in the Hurl file.
+fn predicate_number(predicate_value: &PredicateValue) -> (f64, String) {
+    match predicate_value {
+        PredicateValue::Integer(value) => (*value as f64, value.to_string()),
+        PredicateValue::Float(value) => (value.value, value.encoded.clone()),
+        PredicateValue::Size(value) => (value.bytes() as f64, value.to_string()),
+        // All others value should have failed in parsing:
+        _ => panic!("expect a number predicate value"),
     }
 }
 
@@ -320,6 +378,65 @@ fn eval_something(
             }),
         },
 
+        PredicateFuncValue::CountGreaterThan {
+            value: PredicateValue::Integer(expected_value),
+            ..
+        } => Ok(assert_count(
+            value,
+            count_expected("greater than", expected_value),
+            |count| count > *expected_value,
+        )),
+        PredicateFuncValue::CountGreaterThanOrEqual {
+            value: PredicateValue::Integer(expected_value),
+            ..
+        } => Ok(assert_count(
+            value,
+            count_expected("greater than or equals to", expected_value),
+            |count| count >= *expected_value,
+        )),
+        PredicateFuncValue::CountLessThan {
+            value: PredicateValue::Integer(expected_value),
+            ..
+        } => Ok(assert_count(
+            value,
+            count_expected("less than", expected_value),
+            |count| count < *expected_value,
+        )),
+        PredicateFuncValue::CountLessThanOrEqual {
+            value: PredicateValue::Integer(expected_value),
+            ..
+        } => Ok(assert_count(
+            value,
+            count_expected("less than or equals to", expected_value),
+            |count| count <= *expected_value,
+        )),
+
+        PredicateFuncValue::LengthEqual {
+            value: PredicateValue::Integer(expected_value),
+            ..
+        } => {
+            let expected = format!("length equals to <{expected_value}>");
+            let length = match value {
+                Value::String(s) => s.chars().count(),
+                Value::List(values) => values.len(),
+                Value::Object(values) => values.len(),
+                _ => {
+                    return Ok(AssertResult {
+                        success: false,
+                        actual: value.display(),
+                        expected,
+                        type_mismatch: true,
+                    })
+                }
+            };
+            Ok(AssertResult {
+                success: length as i64 == *expected_value,
+                actual: length.to_string(),
+                expected,
+                type_mismatch: false,
+            })
+        }
+
         // starts with string or bytes
         PredicateFuncValue::StartWith {
             value: expected, ..
@@ -418,10 +535,10 @@ fn eval_something(
                     let expected = eval_template(template, variables)?;
                     match regex::Regex::new(expected.as_str()) {
                         Ok(re) => re,
-                        Err(_) => {
+                        Err(e) => {
                             return Err(Error {
                                 source_info: predicate_func.source_info.clone(),
-                                inner: RunnerError::InvalidRegex(),
+                                inner: RunnerError::InvalidRegex(regex_error_message(e)),
                                 assert: false,
                             });
                         }
@@ -446,6 +563,119 @@ fn eval_something(
             }
         }
 
+        PredicateFuncValue::EqualIgnoreOrder {
+            value: expected, ..
+        } => {
+            let expected = eval_json_value(expected, variables, false)?;
+            let elements = match serde_json::from_str(&expected) {
+                Ok(serde_json::Value::Array(elements)) => elements,
+                _ => {
+                    return Err(Error {
+                        source_info: predicate_func.source_info.clone(),
+                        inner: RunnerError::InvalidJson { value: expected },
+                        assert: false,
+                    });
+                }
+            };
+            let elements = elements.iter().map(Value::from_json).collect::<Vec<_>>();
+            let expected = format!("equals ignoring order <{expected}>");
+            Ok(assert_equal_ignore_order(value, &elements, expected))
+        }
+
+        PredicateFuncValue::MatchSchema { value: schema, .. } => {
+            let schema = match schema {
+                SchemaSource::Inline(schema) => schema,
+                // Schema files are read and inlined before the predicate is evaluated.
+                SchemaSource::File { filename, .. } => {
+                    return Err(Error {
+                        source_info: filename.source_info.clone(),
+                        inner: RunnerError::FileReadAccess {
+                            value: filename.to_string(),
+                        },
+                        assert: false,
+                    });
+                }
+            };
+            let schema = eval_json_value(schema, variables, false)?;
+            let schema = match serde_json::from_str(&schema) {
+                Ok(schema) => schema,
+                Err(_) => {
+                    return Err(Error {
+                        source_info: predicate_func.source_info.clone(),
+                        inner: RunnerError::InvalidJson { value: schema },
+                        assert: false,
+                    });
+                }
+            };
+            Ok(AssertResult {
+                success: match_schema(value, &schema),
+                actual: value.display(),
+                expected: "matches schema".to_string(),
+                type_mismatch: false,
+            })
+        }
+
+        PredicateFuncValue::MatchTemplate {
+            value: template, ..
+        } => {
+            let regex = eval_template_regex(template, variables)?;
+            let expected = format!("matches template <{}>", eval_template(template, variables)?);
+            Ok(assert_match_regexes(value, expected, |actual| {
+                regex.is_match(actual)
+            }))
+        }
+
+        PredicateFuncValue::MatchAll {
+            value: patterns, ..
+        } => {
+            let regexes = eval_regexes(patterns, variables, &predicate_func.source_info)?;
+            let patterns = eval_json_value(patterns, variables, false)?;
+            let expected = format!("matches all regexes <{patterns}>");
+            Ok(assert_match_regexes(value, expected, |actual| {
+                regexes.iter().all(|regex| regex.is_match(actual))
+            }))
+        }
+
+        PredicateFuncValue::MatchAny {
+            value: patterns, ..
+        } => {
+            let regexes = eval_regexes(patterns, variables, &predicate_func.source_info)?;
+            let patterns = eval_json_value(patterns, variables, false)?;
+            let expected = format!("matches any regex <{patterns}>");
+            Ok(assert_match_regexes(value, expected, |actual| {
+                regexes.iter().any(|regex| regex.is_match(actual))
+            }))
+        }
+
+        PredicateFuncValue::IsCloseTo {
+            value: expected_value,
+            epsilon,
+            ..
+        } => {
+            let expected = close_to_expected(expected_value, epsilon);
+            let (expected_value, _) = predicate_number(expected_value);
+            let (epsilon, _) = predicate_number(epsilon);
+            let actual = match value {
+                Value::Integer(actual) => Some(*actual as f64),
+                Value::Float(actual) => Some(*actual),
+                _ => None,
+            };
+            match actual {
+                Some(actual) => Ok(AssertResult {
+                    success: (actual - expected_value).abs() <= epsilon,
+                    actual: value.display(),
+                    expected,
+                    type_mismatch: false,
+                }),
+                None => Ok(AssertResult {
+                    success: false,
+                    actual: value.display(),
+                    expected,
+                    type_mismatch: true,
+                }),
+            }
+        }
+
         // types
         PredicateFuncValue::IsInteger {} => Ok(AssertResult {
             success: matches!(value, Value::Integer(_)),
@@ -459,6 +689,12 @@ fn eval_something(
             expected: "float".to_string(),
             type_mismatch: false,
         }),
+        PredicateFuncValue::IsNumber {} => Ok(AssertResult {
+            success: matches!(value, Value::Integer(_) | Value::Float(_)),
+            actual: value.display(),
+            expected: "number".to_string(),
+            type_mismatch: false,
+        }),
         PredicateFuncValue::IsBoolean {} => Ok(AssertResult {
             success: matches!(value, Value::Bool(_)),
             actual: value.display(),
@@ -480,6 +716,10 @@ fn eval_something(
             expected: "collection".to_string(),
             type_mismatch: false,
         }),
+        PredicateFuncValue::IsSorted {} => Ok(assert_sorted(value, "sorted", Ordering::Greater)),
+        PredicateFuncValue::IsSortedDescending {} => {
+            Ok(assert_sorted(value, "sorted descending", Ordering::Less))
+        }
 
         // exists
         PredicateFuncValue::Exist {} => match value {
@@ -530,6 +770,25 @@ fn eval_something(
                 type_mismatch: true,
             }),
         },
+
+        // valid JSON
+        PredicateFuncValue::IsValidJson {} => {
+            let parsed = match value {
+                Value::String(data) => {
+                    Some(serde_json::from_str::<serde_json::Value>(data).is_ok())
+                }
+                Value::Bytes(data) => {
+                    Some(serde_json::from_slice::<serde_json::Value>(data).is_ok())
+                }
+                _ => None,
+            };
+            Ok(AssertResult {
+                success: parsed == Some(true),
+                actual: value.display(),
+                expected: "valid JSON".to_string(),
+                type_mismatch: parsed.is_none(),
+            })
+        }
         _ => panic!(),
     }
 }
@@ -826,6 +1085,201 @@ fn compare_float(f1: f64, f2: f64) -> i32 {
     }
 }
 
+/// Returns the expected message of a count `comparison` (like "greater than") with `expected`.
+fn count_expected(comparison: &str, expected: impl fmt::Display) -> String {
+    format!("count {comparison} <{expected}>")
+}
+
+/// Compares the size of the collection `value` with `predicate`.
+fn assert_count(value: &Value, expected: String, predicate: impl Fn(i64) -> bool) -> AssertResult {
+    let count = match value {
+        Value::List(values) => values.len(),
+        Value::Nodeset(n) => *n,
+        Value::Bytes(data) => data.len(),
+        _ => {
+            return AssertResult {
+                success: false,
+                actual: value.display(),
+                expected,
+                type_mismatch: true,
+            }
+        }
+    };
+    AssertResult {
+        success: predicate(count as i64),
+        actual: count.to_string(),
+        expected,
+        type_mismatch: false,
+    }
+}
+
+/// Compiles the `template` of a `matchesTemplate` predicate to a regex matching a whole string,
+/// where each wildcard matches any run of chars and the other parts are matched literally.
+fn eval_template_regex(
+    template: &Template,
+    variables: &HashMap<String, Value>,
+) -> Result<regex::Regex, Error> {
+    let mut pattern = "^".to_string();
+    for element in &template.elements {
+        match element {
+            TemplateElement::String { value, .. } if value == TEMPLATE_WILDCARD => {
+                pattern.push_str("(?s:.*)");
+            }
+            TemplateElement::String { value, .. } => pattern.push_str(&regex::escape(value)),
+            TemplateElement::Expression(expr) => {
+                pattern.push_str(&regex::escape(&eval_expression(expr, variables)?));
+            }
+        }
+    }
+    pattern.push('$');
+    // Every part other than the wildcards is escaped, the pattern is always valid.
+    Ok(regex::Regex::new(&pattern).unwrap())
+}
+
+/// Checks that the collection `value` never has an element followed by a smaller one (or a
+/// greater one, depending on the `unexpected` ordering between two consecutive elements).
+/// Numbers are compared numerically and strings lexicographically; any other collection,
+/// including a mix of numbers and strings, is a type mismatch.
+fn assert_sorted(value: &Value, expected: &str, unexpected: Ordering) -> AssertResult {
+    let sorted = match value {
+        Value::List(values) => is_sorted(values, unexpected),
+        _ => None,
+    };
+    match sorted {
+        Some(success) => AssertResult {
+            success,
+            actual: value.display(),
+            expected: expected.to_string(),
+            type_mismatch: false,
+        },
+        None => AssertResult {
+            success: false,
+            actual: value.display(),
+            expected: expected.to_string(),
+            type_mismatch: true,
+        },
+    }
+}
+
+/// Returns whether `values` are sorted, or `None` if they can not be compared together.
+fn is_sorted(values: &[Value], unexpected: Ordering) -> Option<bool> {
+    let numbers = values
+        .iter()
+        .map(|value| match value {
+            Value::Integer(value) => Some(*value as f64),
+            Value::Float(value) => Some(*value),
+            _ => None,
+        })
+        .collect::<Option<Vec<f64>>>();
+    if let Some(numbers) = numbers {
+        return Some(
+            numbers
+                .windows(2)
+                .all(|pair| pair[0].partial_cmp(&pair[1]) != Some(unexpected)),
+        );
+    }
+    let strings = values
+        .iter()
+        .map(|value| match value {
+            Value::String(value) => Some(value.as_str()),
+            _ => None,
+        })
+        .collect::<Option<Vec<&str>>>();
+    strings.map(|strings| {
+        strings
+            .windows(2)
+            .all(|pair| pair[0].cmp(pair[1]) != unexpected)
+    })
+}
+
+/// Compiles the list of regex `patterns` of a `matchesAll` or `matchesAny` predicate.
+fn eval_regexes(
+    patterns: &JsonValue,
+    variables: &HashMap<String, Value>,
+    source_info: &SourceInfo,
+) -> Result<Vec<regex::Regex>, Error> {
+    let elements = match patterns {
+        JsonValue::List { elements, .. } => elements,
+        _ => panic!("expect a list of regexes"), // should have failed in parsing
+    };
+    let mut regexes = vec![];
+    for element in elements {
+        let pattern = match &element.value {
+            JsonValue::String(template) => eval_template(template, variables)?,
+            _ => panic!("expect a string regex"), // should have failed in parsing
+        };
+        match regex::Regex::new(&pattern) {
+            Ok(regex) => regexes.push(regex),
+            Err(e) => {
+                return Err(Error {
+                    source_info: source_info.clone(),
+                    inner: RunnerError::InvalidRegex(regex_error_message(e)),
+                    assert: false,
+                })
+            }
+        }
+    }
+    Ok(regexes)
+}
+
+fn assert_match_regexes(
+    value: &Value,
+    expected: String,
+    is_match: impl Fn(&str) -> bool,
+) -> AssertResult {
+    match value {
+        Value::String(actual) => AssertResult {
+            success: is_match(actual),
+            actual: value.display(),
+            expected,
+            type_mismatch: false,
+        },
+        _ => AssertResult {
+            success: false,
+            actual: value.display(),
+            expected,
+            type_mismatch: true,
+        },
+    }
+}
+
+/// Asserts that the collection `value` has the same elements as `elements`, in any order.
+///
+/// Each element is compared like the `equals` predicate, and must match a distinct element of
+/// the collection, so that duplicated elements are counted.
+fn assert_equal_ignore_order(value: &Value, elements: &[Value], expected: String) -> AssertResult {
+    match value {
+        Value::List(values) => {
+            let mut unmatched = values.iter().collect::<Vec<&Value>>();
+            let success = values.len() == elements.len()
+                && elements.iter().all(|element| {
+                    match unmatched
+                        .iter()
+                        .position(|v| assert_values_equal(v, element).success)
+                    {
+                        Some(index) => {
+                            unmatched.swap_remove(index);
+                            true
+                        }
+                        None => false,
+                    }
+                });
+            AssertResult {
+                success,
+                actual: value.display(),
+                expected,
+                type_mismatch: false,
+            }
+        }
+        _ => AssertResult {
+            success: false,
+            actual: value.display(),
+            expected,
+            type_mismatch: true,
+        },
+    }
+}
+
 fn assert_include(value: &Value, element: &Value) -> AssertResult {
     let expected = format!("includes {}", element.display());
     match value {
@@ -854,6 +1308,83 @@ fn assert_include(value: &Value, element: &Value) -> AssertResult {
     }
 }
 
+/// Validates `value` against a JSON `schema`.
+///
+/// Only a subset of JSON Schema is supported: `type`, `enum`, `const`, `required`,
+/// `properties`, `additionalProperties` (as a boolean) and `items`. Other keywords are ignored.
+fn match_schema(value: &Value, schema: &serde_json::Value) -> bool {
+    let schema = match schema {
+        serde_json::Value::Bool(b) => return *b,
+        serde_json::Value::Object(schema) => schema,
+        _ => return false,
+    };
+    if let Some(types) = schema.get("type") {
+        let matches_type = |t: &serde_json::Value| match (t.as_str(), value) {
+            (Some("null"), Value::Null)
+            | (Some("boolean"), Value::Bool(_))
+            | (Some("integer"), Value::Integer(_))
+            | (Some("number"), Value::Integer(_) | Value::Float(_))
+            | (Some("string"), Value::String(_))
+            | (Some("array"), Value::List(_))
+            | (Some("object"), Value::Object(_)) => true,
+            (Some("integer"), Value::Float(f)) => f.fract() == 0.0,
+            _ => false,
+        };
+        let valid = match types {
+            serde_json::Value::Array(types) => types.iter().any(matches_type),
+            t => matches_type(t),
+        };
+        if !valid {
+            return false;
+        }
+    }
+    if let Some(serde_json::Value::Array(values)) = schema.get("enum") {
+        if !values.iter().any(|v| Value::from_json(v) == *value) {
+            return false;
+        }
+    }
+    if let Some(v) = schema.get("const") {
+        if Value::from_json(v) != *value {
+            return false;
+        }
+    }
+    match value {
+        Value::Object(members) => {
+            let get = |name: &str| members.iter().find(|(k, _)| k == name).map(|(_, v)| v);
+            if let Some(serde_json::Value::Array(required)) = schema.get("required") {
+                if required
+                    .iter()
+                    .any(|name| name.as_str().map_or(true, |name| get(name).is_none()))
+                {
+                    return false;
+                }
+            }
+            let properties = schema.get("properties").and_then(|p| p.as_object());
+            if let Some(properties) = properties {
+                for (name, property_schema) in properties {
+                    if let Some(member) = get(name) {
+                        if !match_schema(member, property_schema) {
+                            return false;
+                        }
+                    }
+                }
+            }
+            if let Some(serde_json::Value::Bool(false)) = schema.get("additionalProperties") {
+                let known = |name: &String| properties.map_or(false, |p| p.contains_key(name));
+                if members.iter().any(|(name, _)| !known(name)) {
+                    return false;
+                }
+            }
+            true
+        }
+        Value::List(values) => match schema.get("items") {
+            Some(items) => values.iter().all(|v| match_schema(v, items)),
+            None => true,
+        },
+        _ => true,
+    }
+}
+
 fn contains(haystack: &[u8], needle: &[u8]) -> bool {
     haystack
         .windows(needle.len())
@@ -1088,6 +1619,42 @@ mod tests {
         assert_eq!(assert_result.expected.as_str(), "int <1>");
     }
 
+    #[test]
+    fn test_predicate_value_negative_integers() {
+        let variables = HashMap::new();
+        let whitespace = Whitespace {
+            value: String::from(" "),
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+        let equal = PredicateFunc {
+            value: PredicateFuncValue::Equal {
+                space0: whitespace.clone(),
+                value: PredicateValue::Integer(-5),
+                operator: true,
+            },
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+        let assert_result = eval_something(&equal, &variables, &Value::Integer(-5)).unwrap();
+        assert!(assert_result.success);
+        assert_eq!(assert_result.expected.as_str(), "int <-5>");
+        let assert_result = eval_something(&equal, &variables, &Value::Integer(5)).unwrap();
+        assert!(!assert_result.success);
+
+        let greater = PredicateFunc {
+            value: PredicateFuncValue::GreaterThan {
+                space0: whitespace,
+                value: PredicateValue::Integer(-10),
+                operator: true,
+            },
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+        let assert_result = eval_something(&greater, &variables, &Value::Integer(-5)).unwrap();
+        assert!(assert_result.success);
+        assert_eq!(assert_result.expected.as_str(), "greater than int <-10>");
+        let assert_result = eval_something(&greater, &variables, &Value::Float(-10.5)).unwrap();
+        assert!(!assert_result.success);
+    }
+
     #[test]
     fn test_predicate_value_equals_booleans() {
         let variables = HashMap::new();
@@ -1517,6 +2084,34 @@ mod tests {
         assert_eq!(assert_result.expected.as_str(), "0");
     }
 
+    #[test]
+    fn test_predicate_is_valid_json() {
+        let variables = HashMap::new();
+        let predicate = PredicateFunc {
+            value: PredicateFuncValue::IsValidJson {},
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+
+        let value = Value::String("{\"id\": 1, \"tags\": [\"a\"]}".to_string());
+        let assert_result = eval_something(&predicate, &variables, &value).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.expected.as_str(), "valid JSON");
+
+        let value = Value::Bytes(b"[1, 2]".to_vec());
+        let assert_result = eval_something(&predicate, &variables, &value).unwrap();
+        assert!(assert_result.success);
+
+        let value = Value::String("<html>Hello</html>".to_string());
+        let assert_result = eval_something(&predicate, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+
+        let assert_result = eval_something(&predicate, &variables, &Value::Integer(1)).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+    }
+
     #[test]
     fn test_predicate_is_empty_are_true() {
         let variables = HashMap::new();
@@ -1536,6 +2131,85 @@ mod tests {
         assert_eq!(assert_result.expected.as_str(), "0");
     }
 
+    #[test]
+    fn test_predicate_count_comparisons() {
+        let variables = HashMap::new();
+        let space0 = Whitespace {
+            value: String::from(" "),
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+        // A collection of size 3
+        let value = Value::List(vec![
+            Value::Integer(1),
+            Value::Integer(2),
+            Value::Integer(3),
+        ]);
+        let count_predicate = |predicate_func_value: PredicateFuncValue| {
+            eval_something(
+                &PredicateFunc {
+                    value: predicate_func_value,
+                    source_info: SourceInfo::new(0, 0, 0, 0),
+                },
+                &variables,
+                &value,
+            )
+            .unwrap()
+        };
+
+        for (n, success) in [(2, true), (3, false)] {
+            let assert_result = count_predicate(PredicateFuncValue::CountGreaterThan {
+                space0: space0.clone(),
+                value: PredicateValue::Integer(n),
+            });
+            assert_eq!(assert_result.success, success);
+            assert_eq!(assert_result.actual, "3");
+            assert_eq!(assert_result.expected, format!("count greater than <{n}>"));
+        }
+        for (n, success) in [(3, true), (4, false)] {
+            let assert_result = count_predicate(PredicateFuncValue::CountGreaterThanOrEqual {
+                space0: space0.clone(),
+                value: PredicateValue::Integer(n),
+            });
+            assert_eq!(assert_result.success, success);
+        }
+        for (n, success) in [(4, true), (3, false)] {
+            let assert_result = count_predicate(PredicateFuncValue::CountLessThan {
+                space0: space0.clone(),
+                value: PredicateValue::Integer(n),
+            });
+            assert_eq!(assert_result.success, success);
+        }
+        for (n, success) in [(3, true), (2, false)] {
+            let assert_result = count_predicate(PredicateFuncValue::CountLessThanOrEqual {
+                space0: space0.clone(),
+                value: PredicateValue::Integer(n),
+            });
+            assert_eq!(assert_result.success, success);
+            assert_eq!(
+                assert_result.expected,
+                format!("count less than or equals to <{n}>")
+            );
+        }
+
+        let predicate_func = PredicateFunc {
+            value: PredicateFuncValue::CountGreaterThan {
+                space0,
+                value: PredicateValue::Integer(1),
+            },
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+        let assert_result =
+            eval_something(&predicate_func, &variables, &Value::Bool(true)).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+
+        // No value
+        let assert_result = eval_predicate_func(&predicate_func, &variables, &None).unwrap();
+        assert!(!assert_result.success);
+        assert_eq!(assert_result.actual, "none");
+        assert_eq!(assert_result.expected, "count greater than <1>");
+    }
+
     #[test]
     fn test_predicate_count_equals() {
         let variables = HashMap::new();
@@ -1608,6 +2282,362 @@ mod tests {
         assert!(!assert_result.type_mismatch);
         assert_eq!(assert_result.actual.as_str(), "float <1.0>");
         assert_eq!(assert_result.expected.as_str(), "integer");
+
+        let is_number = PredicateFunc {
+            value: PredicateFuncValue::IsNumber {},
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+        for value in [Value::Integer(1), Value::Float(1.5)] {
+            let assert_result = eval_something(&is_number, &variables, &value).unwrap();
+            assert!(assert_result.success);
+            assert!(!assert_result.type_mismatch);
+            assert_eq!(assert_result.expected.as_str(), "number");
+        }
+
+        let assert_result = eval_something(
+            &PredicateFunc {
+                value: PredicateFuncValue::IsInteger {},
+                source_info: SourceInfo::new(0, 0, 0, 0),
+            },
+            &variables,
+            &Value::Float(1.5),
+        )
+        .unwrap();
+        assert!(!assert_result.success);
+
+        let assert_result =
+            eval_something(&is_number, &variables, &Value::String("1".to_string())).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+    }
+
+    #[test]
+    fn test_predicate_size() {
+        // bytes count < 1KB
+        let variables = HashMap::new();
+        let less_than = PredicateFunc {
+            value: PredicateFuncValue::LessThan {
+                space0: whitespace(),
+                value: PredicateValue::Size(Size {
+                    value: 1,
+                    unit: SizeUnit::Kilobyte,
+                }),
+                operator: true,
+            },
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+        let assert_result = eval_something(&less_than, &variables, &Value::Integer(1000)).unwrap();
+        assert!(assert_result.success);
+        assert_eq!(assert_result.expected.as_str(), "less than int <1024>");
+
+        let assert_result = eval_something(&less_than, &variables, &Value::Integer(1024)).unwrap();
+        assert!(!assert_result.success);
+    }
+
+    #[test]
+    fn test_predicate_length_equal() {
+        let variables = HashMap::new();
+        let length_equal = PredicateFunc {
+            value: PredicateFuncValue::LengthEqual {
+                space0: whitespace(),
+                value: PredicateValue::Integer(2),
+            },
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+
+        for value in [
+            Value::String("é!".to_string()),
+            Value::List(vec![Value::Integer(1), Value::Null]),
+            Value::Object(vec![
+                ("a".to_string(), Value::Integer(1)),
+                ("b".to_string(), Value::Integer(2)),
+            ]),
+        ] {
+            let assert_result = eval_something(&length_equal, &variables, &value).unwrap();
+            assert!(assert_result.success);
+            assert!(!assert_result.type_mismatch);
+            assert_eq!(assert_result.actual.as_str(), "2");
+            assert_eq!(assert_result.expected.as_str(), "length equals to <2>");
+        }
+
+        let assert_result =
+            eval_something(&length_equal, &variables, &Value::String("abc".to_string())).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual.as_str(), "3");
+
+        let assert_result = eval_something(&length_equal, &variables, &Value::Integer(2)).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+    }
+
+    #[test]
+    fn test_predicate_sorted() {
+        let variables = HashMap::new();
+        let sorted = PredicateFunc {
+            value: PredicateFuncValue::IsSorted {},
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+        let sorted_descending = PredicateFunc {
+            value: PredicateFuncValue::IsSortedDescending {},
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+
+        let value = Value::List(vec![
+            Value::Integer(1),
+            Value::Float(1.5),
+            Value::Integer(2),
+            Value::Integer(2),
+        ]);
+        let assert_result = eval_something(&sorted, &variables, &value).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.expected.as_str(), "sorted");
+        let assert_result = eval_something(&sorted_descending, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.expected.as_str(), "sorted descending");
+
+        let value = Value::List(vec![
+            Value::String("b".to_string()),
+            Value::String("a".to_string()),
+            Value::String("c".to_string()),
+        ]);
+        let assert_result = eval_something(&sorted, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        let assert_result = eval_something(&sorted_descending, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+
+        let value = Value::List(vec![Value::Integer(1), Value::String("a".to_string())]);
+        let assert_result = eval_something(&sorted, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+
+        let assert_result = eval_something(&sorted, &variables, &Value::Integer(1)).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+    }
+
+    #[test]
+    fn test_predicate_equal_ignore_order() {
+        let variables = HashMap::new();
+        let elements = JsonValue::List {
+            space0: "".to_string(),
+            elements: ["1", "2", "3.0"]
+                .iter()
+                .map(|number| JsonListElement {
+                    space0: "".to_string(),
+                    comments: vec![],
+                    value: JsonValue::Number(number.to_string()),
+                    space1: "".to_string(),
+                })
+                .collect(),
+            comments: vec![],
+        };
+        let predicate_func = PredicateFunc {
+            value: PredicateFuncValue::EqualIgnoreOrder {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(0, 0, 0, 0),
+                },
+                value: elements,
+            },
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+
+        let value = Value::List(vec![
+            Value::Integer(3),
+            Value::Integer(1),
+            Value::Integer(2),
+        ]);
+        let assert_result = eval_something(&predicate_func, &variables, &value).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual.as_str(), "[int <3>, int <1>, int <2>]");
+        assert_eq!(
+            assert_result.expected.as_str(),
+            "equals ignoring order <[1,2,3.0]>"
+        );
+
+        for values in [
+            vec![Value::Integer(1), Value::Integer(2)],
+            vec![Value::Integer(1), Value::Integer(2), Value::Integer(2)],
+            vec![
+                Value::Integer(1),
+                Value::Integer(2),
+                Value::Integer(3),
+                Value::Integer(4),
+            ],
+        ] {
+            let value = Value::List(values);
+            let assert_result = eval_something(&predicate_func, &variables, &value).unwrap();
+            assert!(!assert_result.success);
+            assert!(!assert_result.type_mismatch);
+        }
+
+        let value = Value::Integer(1);
+        let assert_result = eval_something(&predicate_func, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+    }
+
+    #[test]
+    fn test_predicate_match_all() {
+        let variables = HashMap::new();
+        let patterns = JsonValue::List {
+            space0: "".to_string(),
+            elements: ["^a", "b", "c$"]
+                .iter()
+                .map(|pattern| JsonListElement {
+                    space0: "".to_string(),
+                    comments: vec![],
+                    value: JsonValue::String(Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: pattern.to_string(),
+                            encoded: pattern.to_string(),
+                        }],
+                        source_info: SourceInfo::new(0, 0, 0, 0),
+                    }),
+                    space1: "".to_string(),
+                })
+                .collect(),
+            comments: vec![],
+        };
+        let match_all = PredicateFunc {
+            value: PredicateFuncValue::MatchAll {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(0, 0, 0, 0),
+                },
+                value: patterns.clone(),
+            },
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+        let match_any = PredicateFunc {
+            value: PredicateFuncValue::MatchAny {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(0, 0, 0, 0),
+                },
+                value: patterns,
+            },
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+
+        // All patterns match
+        let value = Value::String("abc".to_string());
+        let assert_result = eval_something(&match_all, &variables, &value).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(
+            assert_result.expected.as_str(),
+            r#"matches all regexes <["^a","b","c$"]>"#
+        );
+        let assert_result = eval_something(&match_any, &variables, &value).unwrap();
+        assert!(assert_result.success);
+
+        // Only some patterns match
+        let value = Value::String("ab".to_string());
+        let assert_result = eval_something(&match_all, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        let assert_result = eval_something(&match_any, &variables, &value).unwrap();
+        assert!(assert_result.success);
+        assert_eq!(
+            assert_result.expected.as_str(),
+            r#"matches any regex <["^a","b","c$"]>"#
+        );
+
+        // No pattern matches
+        let value = Value::String("xyz".to_string());
+        let assert_result = eval_something(&match_all, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+        let assert_result = eval_something(&match_any, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+
+        let assert_result = eval_something(&match_any, &variables, &Value::Integer(1)).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+
+        // A pattern rendered at runtime can still be invalid
+        let match_all = PredicateFunc {
+            value: PredicateFuncValue::MatchAll {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(0, 0, 0, 0),
+                },
+                value: JsonValue::List {
+                    space0: "".to_string(),
+                    elements: vec![JsonListElement {
+                        space0: "".to_string(),
+                        comments: vec![],
+                        value: JsonValue::String(Template {
+                            delimiter: Some('"'),
+                            elements: vec![TemplateElement::String {
+                                value: "[x".to_string(),
+                                encoded: "[x".to_string(),
+                            }],
+                            source_info: SourceInfo::new(0, 0, 0, 0),
+                        }),
+                        space1: "".to_string(),
+                    }],
+                    comments: vec![],
+                },
+            },
+            source_info: SourceInfo::new(1, 1, 1, 15),
+        };
+        let error = eval_something(&match_all, &variables, &value).unwrap_err();
+        assert_eq!(error.source_info, SourceInfo::new(1, 1, 1, 15));
+        assert_eq!(
+            error.inner,
+            RunnerError::InvalidRegex("unclosed character class".to_string())
+        );
+    }
+
+    #[test]
+    fn test_predicate_close_to() {
+        let variables = HashMap::new();
+        let close_to = PredicateFunc {
+            value: PredicateFuncValue::IsCloseTo {
+                space0: whitespace(),
+                value: PredicateValue::Float(Float {
+                    value: 1.5,
+                    encoded: "1.5".to_string(),
+                }),
+                space1: whitespace(),
+                space2: whitespace(),
+                epsilon: PredicateValue::Float(Float {
+                    value: 0.01,
+                    encoded: "0.01".to_string(),
+                }),
+            },
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+
+        for value in [Value::Float(1.501), Value::Float(1.499)] {
+            let assert_result = eval_something(&close_to, &variables, &value).unwrap();
+            assert!(assert_result.success);
+            assert!(!assert_result.type_mismatch);
+            assert_eq!(
+                assert_result.expected.as_str(),
+                "close to <1.5> within <0.01>"
+            );
+        }
+
+        for value in [Value::Float(1.6), Value::Integer(2)] {
+            let assert_result = eval_something(&close_to, &variables, &value).unwrap();
+            assert!(!assert_result.success);
+            assert!(!assert_result.type_mismatch);
+        }
+
+        let assert_result =
+            eval_something(&close_to, &variables, &Value::String("1.5".to_string())).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
     }
 
     #[test]
@@ -1705,6 +2735,46 @@ mod tests {
         assert!(eval_predicate(&predicate, &variables, &None).is_ok());
     }
 
+    #[test]
+    fn test_predicate_equal_multiline() {
+        let variables = HashMap::new();
+        let whitespace = Whitespace {
+            value: String::from(" "),
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+        let predicate_func = PredicateFunc {
+            value: PredicateFuncValue::Equal {
+                space0: whitespace.clone(),
+                value: PredicateValue::MultilineString(MultilineString::Text(Text {
+                    charset: None,
+                    content_type: None,
+                    space: whitespace.clone(),
+                    newline: whitespace,
+                    value: Template {
+                        delimiter: None,
+                        elements: vec![TemplateElement::String {
+                            value: "expected multi\nline\n".to_string(),
+                            encoded: "expected multi\nline\n".to_string(),
+                        }],
+                        source_info: SourceInfo::new(0, 0, 0, 0),
+                    },
+                })),
+                operator: true,
+            },
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+
+        let value = Value::String("expected multi\nline\n".to_string());
+        let assert_result = eval_something(&predicate_func, &variables, &value).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+
+        let value = Value::String("expected multi\nline".to_string());
+        let assert_result = eval_something(&predicate_func, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+    }
+
     #[test]
     fn test_predicate_match() {
         let variables = HashMap::new();
@@ -1732,4 +2802,117 @@ mod tests {
         assert_eq!(assert_result.actual.as_str(), "string <aa>");
         assert_eq!(assert_result.expected.as_str(), "matches regex <a{3}>");
     }
+
+    #[test]
+    fn test_predicate_match_template() {
+        let mut variables = HashMap::new();
+        variables.insert("prefix".to_string(), Value::String("a.b".to_string()));
+        let string = |value: &str| TemplateElement::String {
+            value: value.to_string(),
+            encoded: value.to_string(),
+        };
+        let predicate_func = |elements| PredicateFunc {
+            value: PredicateFuncValue::MatchTemplate {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(0, 0, 0, 0),
+                },
+                value: Template {
+                    delimiter: Some('"'),
+                    elements,
+                    source_info: SourceInfo::new(0, 0, 0, 0),
+                },
+            },
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+
+        // matchesTemplate "id-{{*}}"
+        let id = predicate_func(vec![string("id-"), string(TEMPLATE_WILDCARD)]);
+        let assert_result =
+            eval_something(&id, &variables, &Value::String("id-123".to_string())).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(
+            assert_result.expected.as_str(),
+            "matches template <id-{{*}}>"
+        );
+        let assert_result =
+            eval_something(&id, &variables, &Value::String("uid-123".to_string())).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        let assert_result = eval_something(&id, &variables, &Value::Integer(1)).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+
+        // matchesTemplate "{{prefix}}-{{*}}", the rendered variable is matched literally.
+        let prefix = predicate_func(vec![
+            TemplateElement::Expression(Expr {
+                space0: Whitespace {
+                    value: "".to_string(),
+                    source_info: SourceInfo::new(0, 0, 0, 0),
+                },
+                variable: Variable {
+                    name: String::from("prefix"),
+                    source_info: SourceInfo::new(0, 0, 0, 0),
+                },
+                space1: Whitespace {
+                    value: "".to_string(),
+                    source_info: SourceInfo::new(0, 0, 0, 0),
+                },
+            }),
+            string("-"),
+            string(TEMPLATE_WILDCARD),
+        ]);
+        let assert_result =
+            eval_something(&prefix, &variables, &Value::String("a.b-1".to_string())).unwrap();
+        assert!(assert_result.success);
+        let assert_result =
+            eval_something(&prefix, &variables, &Value::String("axb-1".to_string())).unwrap();
+        assert!(!assert_result.success);
+    }
+
+    #[test]
+    fn test_predicate_match_schema() {
+        let variables = HashMap::new();
+        let mut reader = hurl_core::parser::Reader::new(
+            r#"{"type": "object", "required": ["id"], "properties": {"id": {"type": "integer"}, "tags": {"type": "array", "items": {"type": "string"}}}}"#,
+        );
+        let predicate_func = PredicateFunc {
+            value: PredicateFuncValue::MatchSchema {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(0, 0, 0, 0),
+                },
+                value: SchemaSource::Inline(hurl_core::parser::parse_json(&mut reader).unwrap()),
+            },
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+
+        let value = Value::Object(vec![
+            ("id".to_string(), Value::Integer(1)),
+            (
+                "tags".to_string(),
+                Value::List(vec![Value::String("a".to_string())]),
+            ),
+        ]);
+        let assert_result = eval_something(&predicate_func, &variables, &value).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.expected.as_str(), "matches schema");
+
+        let value = Value::Object(vec![("id".to_string(), Value::String("1".to_string()))]);
+        let assert_result = eval_something(&predicate_func, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+
+        let value = Value::Object(vec![(
+            "tags".to_string(),
+            Value::List(vec![Value::String("a".to_string())]),
+        )]);
+        let assert_result = eval_something(&predicate_func, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+
+        let value = Value::List(vec![]);
+        let assert_result = eval_something(&predicate_func, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+    }
 }
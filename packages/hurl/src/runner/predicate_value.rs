@@ -49,5 +49,6 @@ pub fn eval_predicate_value(
             Ok(value)
         }
         PredicateValue::Regex(regex) => Ok(Value::Regex(regex.inner.clone())),
+        PredicateValue::Size(size) => Ok(Value::Integer(size.bytes() as i64)),
     }
 }
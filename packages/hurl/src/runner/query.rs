@@ -18,6 +18,7 @@
 use std::collections::HashMap;
 
 use hurl_core::ast::*;
+use hurl_core::parser::regex_error_message;
 use regex::Regex;
 use sha2::Digest;
 
@@ -38,6 +39,7 @@ pub fn eval_query(
     match query.value.clone() {
         QueryValue::Status {} => eval_query_status(http_response),
         QueryValue::Url {} => eval_query_url(http_response),
+        QueryValue::Charset {} => eval_query_charset(http_response),
         QueryValue::Header { name, .. } => eval_query_header(http_response, &name, variables),
         QueryValue::Cookie {
             expr: CookiePath { name, attribute },
@@ -73,6 +75,10 @@ fn eval_query_url(response: &http::Response) -> QueryResult {
     Ok(Some(Value::String(response.url.clone())))
 }
 
+fn eval_query_charset(response: &http::Response) -> QueryResult {
+    Ok(response.charset().map(Value::String))
+}
+
 fn eval_query_header(
     response: &http::Response,
     header: &Template,
@@ -210,13 +216,14 @@ fn eval_query_jsonpath(
         Ok(v) => v,
     };
 
-    let results = jsonpath_query.eval(&value);
+    // The query result borrows from the response body, values are only converted at the end.
+    let results = jsonpath_query.eval_ref(&value);
     match results {
         None => Ok(None),
-        Some(jsonpath::JsonpathResult::SingleEntry(value)) => Ok(Some(Value::from_json(&value))),
-        Some(jsonpath::JsonpathResult::Collection(values)) => {
-            Ok(Some(Value::from_json(&serde_json::Value::Array(values))))
-        }
+        Some(jsonpath::JsonpathResultRef::SingleEntry(value)) => Ok(Some(Value::from_json(&value))),
+        Some(jsonpath::JsonpathResultRef::Collection(values)) => Ok(Some(Value::List(
+            values.iter().map(|value| Value::from_json(value)).collect(),
+        ))),
     }
 }
 
@@ -241,11 +248,11 @@ fn eval_query_regex(
             let value = eval_template(t, variables)?;
             match Regex::new(value.as_str()) {
                 Ok(re) => re,
-                Err(_) => {
+                Err(e) => {
                     let source_info = t.source_info.clone();
                     return Err(Error {
                         source_info,
-                        inner: RunnerError::InvalidRegex(),
+                        inner: RunnerError::InvalidRegex(regex_error_message(e)),
                         assert: false,
                     });
                 }
@@ -1108,7 +1115,41 @@ pub mod tests {
             .err()
             .unwrap();
         assert_eq!(error.source_info, SourceInfo::new(1, 7, 1, 10));
-        assert_eq!(error.inner, RunnerError::InvalidRegex());
+        assert_eq!(
+            error.inner,
+            RunnerError::InvalidRegex("repetition operator missing expression".to_string())
+        );
+    }
+
+    #[test]
+    fn test_query_charset() {
+        let variables = HashMap::new();
+        let query = Query {
+            source_info: SourceInfo::new(0, 0, 0, 0),
+            value: QueryValue::Charset {},
+        };
+        let http_response = http::Response {
+            headers: vec![http::Header::new(
+                "Content-Type",
+                "text/html; charset=ISO-8859-1",
+            )],
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query(&query, &variables, &http_response)
+                .unwrap()
+                .unwrap(),
+            Value::String("ISO-8859-1".to_string())
+        );
+
+        let http_response = http::Response {
+            headers: vec![http::Header::new("Content-Type", "text/html")],
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query(&query, &variables, &http_response).unwrap(),
+            None
+        );
     }
 
     #[test]
@@ -18,6 +18,7 @@
 use std::collections::HashMap;
 
 use hurl_core::ast::RegexValue;
+use hurl_core::parser::regex_error_message;
 use regex::Regex;
 
 use crate::runner::template::eval_template;
@@ -32,9 +33,9 @@ pub fn eval_regex_value(
             let value = eval_template(t, variables)?;
             match Regex::new(value.as_str()) {
                 Ok(re) => Ok(re),
-                Err(_) => Err(Error {
+                Err(e) => Err(Error {
                     source_info: t.source_info.clone(),
-                    inner: RunnerError::InvalidRegex(),
+                    inner: RunnerError::InvalidRegex(regex_error_message(e)),
                     assert: false,
                 }),
             }
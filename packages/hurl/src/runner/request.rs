@@ -22,7 +22,7 @@ use base64::Engine;
 use hurl_core::ast::*;
 
 use crate::http;
-use crate::runner::body::eval_body;
+use crate::runner::body::{eval_body, template_json_value};
 use crate::runner::core::Error;
 use crate::runner::multipart::eval_multipart_param;
 use crate::runner::template::eval_template;
@@ -100,6 +100,15 @@ pub fn eval_request(
         Some("application/x-www-form-urlencoded".to_string())
     } else if !multipart.is_empty() {
         Some("multipart/form-data".to_string())
+    } else if let Some(content_type) = body_content_type(request) {
+        Some(content_type)
+    } else if let Some(Body {
+        value: Bytes::Template(template),
+        ..
+    }) = &request.body
+    {
+        // Only objects and lists are sent as JSON, other values are sent as is.
+        template_json_value(template, variables).map(|_| "application/json".to_string())
     } else if let Some(Body {
         value:
             Bytes::Json { .. }
@@ -132,6 +141,19 @@ pub fn eval_request(
     })
 }
 
+/// Returns the content type explicitly set on the multiline string body of `request`, if any.
+fn body_content_type(request: &Request) -> Option<String> {
+    match &request.body {
+        Some(Body {
+            value: Bytes::MultilineString(multiline),
+            ..
+        }) => multiline
+            .content_type()
+            .map(|content_type| content_type.value.clone()),
+        _ => None,
+    }
+}
+
 /// Experimental feature
 /// @cookie_storage_add
 pub fn cookie_storage_set(request: &Request) -> Option<String> {
@@ -178,6 +200,7 @@ fn eval_method(method: &Method) -> http::Method {
         Method::Unlock => http::Method::Unlock,
         Method::Propfind => http::Method::Propfind,
         Method::View => http::Method::View,
+        Method::Custom(name) => http::Method::Custom(name.clone()),
     }
 }
 
@@ -358,6 +381,72 @@ mod tests {
         assert_eq!(http_request, http::query_http_request());
     }
 
+    #[test]
+    fn test_template_body_request() {
+        // Resends a JSON object captured from a previous response.
+        let mut variables = HashMap::new();
+        variables.insert(
+            String::from("base_url"),
+            Value::String(String::from("http://localhost:8000")),
+        );
+        variables.insert(
+            String::from("payload"),
+            Value::Object(vec![
+                ("id".to_string(), Value::Integer(1)),
+                (
+                    "tags".to_string(),
+                    Value::List(vec![Value::String("new".to_string())]),
+                ),
+            ]),
+        );
+        let request = Request {
+            method: Method::Post,
+            body: Some(Body {
+                line_terminators: vec![],
+                space0: whitespace(),
+                value: Bytes::Template(Template {
+                    delimiter: None,
+                    elements: vec![TemplateElement::Expression(Expr {
+                        space0: whitespace(),
+                        variable: Variable {
+                            name: String::from("payload"),
+                            source_info: SourceInfo::new(2, 3, 2, 10),
+                        },
+                        space1: whitespace(),
+                    })],
+                    source_info: SourceInfo::new(2, 1, 2, 12),
+                }),
+                line_terminator0: LineTerminator {
+                    space0: whitespace(),
+                    comment: None,
+                    newline: whitespace(),
+                },
+            }),
+            ..hello_request()
+        };
+        let http_request = eval_request(&request, &variables, &ContextDir::default()).unwrap();
+        assert_eq!(
+            http_request.body,
+            http::Body::Text(r#"{"id":1,"tags":["new"]}"#.to_string())
+        );
+        assert_eq!(
+            http_request.content_type,
+            Some("application/json".to_string())
+        );
+
+        // A string is sent as is, without content type.
+        variables.insert(
+            String::from("payload"),
+            Value::String(String::from("<user id=\"1\"/>")),
+        );
+        let http_request = eval_request(&request, &variables, &ContextDir::default()).unwrap();
+        assert_eq!(
+            http_request.body,
+            http::Body::Text(r#"<user id="1"/>"#.to_string())
+        );
+        assert_eq!(http_request.content_type, None);
+    }
+
     #[test]
     fn clear_cookie_store() {
         assert!(!cookie_storage_clear(&hello_request()));
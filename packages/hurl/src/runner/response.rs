@@ -21,7 +21,7 @@ use hurl_core::ast::*;
 
 use crate::http;
 use crate::runner::assert::eval_assert;
-use crate::runner::body::eval_body;
+use crate::runner::body::{eval_body, eval_template_body};
 use crate::runner::capture::eval_capture;
 use crate::runner::core::*;
 use crate::runner::json::eval_json_value;
@@ -46,10 +46,10 @@ pub fn eval_version_status_asserts(
     });
 
     let status = &response.status;
-    if let StatusValue::Specific(v) = status.value {
+    if status.value != StatusValue::Any {
         asserts.push(AssertResult::Status {
             actual: http_response.status as u64,
-            expected: v,
+            expected: status.value.clone(),
             source_info: status.source_info.clone(),
         });
     }
@@ -131,7 +131,7 @@ pub fn eval_asserts(
     }
 
     for assert in response.asserts().iter() {
-        let assert_result = eval_assert(assert, variables, http_response);
+        let assert_result = eval_assert(assert, variables, http_response, context_dir);
         asserts.push(assert_result);
     }
     asserts
@@ -186,11 +186,12 @@ fn eval_implicit_body_asserts(
                 source_info: spec_body.space0.source_info.clone(),
             }
         }
-        Bytes::OnelineString(value) => {
-            let expected = match eval_template(value, variables) {
-                Ok(s) => Ok(Value::String(s)),
-                Err(e) => Err(e),
+        Bytes::OnelineString(value) | Bytes::Template(value) => {
+            let expected = match &spec_body.value {
+                Bytes::Template(_) => eval_template_body(value, variables),
+                _ => eval_template(value, variables),
             };
+            let expected = expected.map(Value::String);
             let actual = match http_response.text() {
                 Ok(s) => Ok(Value::String(s)),
                 Err(e) => Err(Error {
@@ -284,7 +285,7 @@ fn eval_implicit_body_asserts(
                 },
             }
         }
-        Bytes::File { .. } => {
+        Bytes::File { .. } | Bytes::FromResponse(_) => {
             let expected = match eval_body(spec_body, variables, context_dir) {
                 Ok(body) => Ok(Value::Bytes(body.bytes())),
                 Err(e) => Err(e),
@@ -416,7 +417,7 @@ mod tests {
                 },
                 AssertResult::Status {
                     actual: 200,
-                    expected: 200,
+                    expected: StatusValue::Specific(200),
                     source_info: SourceInfo::new(2, 10, 2, 13),
                 },
             ]
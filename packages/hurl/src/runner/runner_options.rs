@@ -39,6 +39,7 @@ pub struct RunnerOptionsBuilder {
     post_entry: Option<fn() -> bool>,
     pre_entry: Option<fn(Entry) -> bool>,
     proxy: Option<String>,
+    repeat: usize,
     resolves: Vec<String>,
     retry: Retry,
     retry_interval: Duration,
@@ -69,6 +70,7 @@ impl Default for RunnerOptionsBuilder {
             post_entry: None,
             pre_entry: None,
             proxy: None,
+            repeat: 1,
             resolves: vec![],
             retry: Retry::None,
             retry_interval: Duration::from_millis(1000),
@@ -209,6 +211,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the number of times each entry is executed.
+    ///
+    /// Default is 1.
+    pub fn repeat(&mut self, repeat: usize) -> &mut Self {
+        self.repeat = repeat;
+        self
+    }
+
     /// Provides a custom address for a specific host and port pair.
     pub fn resolves(&mut self, resolves: &[String]) -> &mut Self {
         self.resolves = resolves.to_vec();
@@ -282,6 +292,7 @@ impl RunnerOptionsBuilder {
             post_entry: self.post_entry,
             pre_entry: self.pre_entry,
             proxy: self.proxy.clone(),
+            repeat: self.repeat,
             resolves: self.resolves.clone(),
             retry: self.retry,
             retry_interval: self.retry_interval,
@@ -313,6 +324,7 @@ pub struct RunnerOptions {
     pub(crate) post_entry: Option<fn() -> bool>,
     pub(crate) pre_entry: Option<fn(Entry) -> bool>,
     pub(crate) proxy: Option<String>,
+    pub(crate) repeat: usize,
     pub(crate) resolves: Vec<String>,
     pub(crate) retry: Retry,
     pub(crate) retry_interval: Duration,
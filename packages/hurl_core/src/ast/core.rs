@@ -22,14 +22,89 @@ use crate::ast::json;
 ///
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct HurlFile {
+    pub settings: Option<Settings>,
     pub entries: Vec<Entry>,
     pub line_terminators: Vec<LineTerminator>,
 }
 
+impl HurlFile {
+    /// Returns the URL of every request of this file, in order.
+    pub fn urls(&self) -> Vec<&Template> {
+        self.entries.iter().map(|e| &e.request.url).collect()
+    }
+
+    /// Returns the entry (and its index) whose source span contains the position `pos`.
+    ///
+    /// Positions in the line terminators following the last entry belong to no entry.
+    pub fn entry_at(&self, pos: Pos) -> Option<(usize, &Entry)> {
+        let pos = (pos.line, pos.column);
+        self.entries.iter().enumerate().find(|(_, entry)| {
+            let source_info = entry.source_info();
+            let start = (source_info.start.line, source_info.start.column);
+            let end = (source_info.end.line, source_info.end.column);
+            start <= pos && pos < end
+        })
+    }
+
+    /// Returns the entries whose request URL contains `url_substring`, in order.
+    ///
+    /// Literal parts of the URL are matched on their value; template expressions can not be
+    /// rendered without variables and are matched on their source text, like `{{host}}`.
+    pub fn find_by_url(&self, url_substring: &str) -> Vec<&Entry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.request.url.to_string().contains(url_substring))
+            .collect()
+    }
+
+    /// Returns the asserts of every entry of this file, in order, with the index of their entry.
+    pub fn all_asserts(&self) -> Vec<(usize, &Assert)> {
+        let mut asserts = vec![];
+        for (index, entry) in self.entries.iter().enumerate() {
+            if let Some(response) = &entry.response {
+                for section in &response.sections {
+                    if let SectionValue::Asserts(values) = &section.value {
+                        asserts.extend(values.iter().map(|assert| (index, assert)));
+                    }
+                }
+            }
+        }
+        asserts
+    }
+}
+
+/// File level options, declared in a `[Settings]` section before the first entry and
+/// applied to every entry of the file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Settings {
+    pub line_terminators: Vec<LineTerminator>,
+    pub space0: Whitespace,
+    pub line_terminator0: LineTerminator,
+    pub options: Vec<EntryOption>,
+    pub source_info: SourceInfo,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Entry {
     pub request: Request,
     pub response: Option<Response>,
+    /// Optional label of this entry, declared with a `# @name <label>` comment before the request.
+    pub name: Option<String>,
+}
+
+impl Entry {
+    /// Returns the source span of this entry, from the start of its request to the end of
+    /// its response (or request, if there is no response).
+    pub fn source_info(&self) -> SourceInfo {
+        let end = match &self.response {
+            Some(response) => response.source_info.end.clone(),
+            None => self.request.source_info.end.clone(),
+        };
+        SourceInfo {
+            start: self.request.source_info.start.clone(),
+            end,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -47,6 +122,15 @@ pub struct Request {
 }
 
 impl Request {
+    /// Returns the source span of the request line (method, URL and line terminator), excluding
+    /// the line terminators before it and the headers, sections and body after it.
+    pub fn request_line_span(&self) -> SourceInfo {
+        SourceInfo {
+            start: self.space0.source_info.start.clone(),
+            end: self.line_terminator0.newline.source_info.end.clone(),
+        }
+    }
+
     pub fn querystring_params(&self) -> Vec<KeyValue> {
         for section in &self.sections {
             if let SectionValue::QueryParams(params) = &section.value {
@@ -145,6 +229,7 @@ pub enum Method {
     Unlock,
     Propfind,
     View,
+    Custom(String), // any other uppercase method, like `MKCALENDAR` or `QUERY`
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -172,6 +257,8 @@ pub struct Status {
 pub enum StatusValue {
     Any,
     Specific(u64),
+    Class(u8),       // any status code with this hundreds digit, like `2xx`
+    Range(u64, u64), // any status code between these two codes inclusive, like `200-204`
 }
 
 pub type Header = KeyValue;
@@ -307,6 +394,7 @@ pub struct Query {
 pub enum QueryValue {
     Status {},
     Url {},
+    Charset {},
     Header {
         space0: Whitespace,
         name: Template,
@@ -429,6 +517,31 @@ pub enum PredicateValue {
     Base64(Base64),
     Expression(Expr),
     Regex(Regex),
+    Size(Size),
+}
+
+/// A number of bytes written with a size unit, like `512KB`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Size {
+    pub value: u64,
+    pub unit: SizeUnit,
+}
+
+impl Size {
+    /// Returns the number of bytes of this size.
+    pub fn bytes(&self) -> u64 {
+        let factor = match self.unit {
+            SizeUnit::Kilobyte => 1024,
+            SizeUnit::Megabyte => 1024 * 1024,
+        };
+        self.value.saturating_mul(factor)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SizeUnit {
+    Kilobyte,
+    Megabyte,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -444,6 +557,12 @@ pub enum PredicateFuncValue {
         value: PredicateValue,
         operator: bool,
     },
+    /// A collection having the same elements as a list in any order, like
+    /// `equalsIgnoreOrder [1, 2, 3]`.
+    EqualIgnoreOrder {
+        space0: Whitespace,
+        value: json::Value,
+    },
     GreaterThan {
         space0: Whitespace,
         value: PredicateValue,
@@ -468,6 +587,27 @@ pub enum PredicateFuncValue {
         space0: Whitespace,
         value: PredicateValue,
     },
+    CountGreaterThan {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
+    CountGreaterThanOrEqual {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
+    CountLessThan {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
+    CountLessThanOrEqual {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
+    /// Length of a string (in chars), an array or an object (in keys).
+    LengthEqual {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
     StartWith {
         space0: Whitespace,
         value: PredicateValue,
@@ -488,13 +628,60 @@ pub enum PredicateFuncValue {
         space0: Whitespace,
         value: PredicateValue,
     },
+    MatchSchema {
+        space0: Whitespace,
+        value: SchemaSource,
+    },
+    /// A string matching a template where [`TEMPLATE_WILDCARD`] matches any run of chars,
+    /// like `matchesTemplate "id-{{*}}"`.
+    MatchTemplate {
+        space0: Whitespace,
+        value: Template,
+    },
+    /// A string matching every regex of a list, like `matchesAll ["^a", "z$"]`.
+    MatchAll {
+        space0: Whitespace,
+        value: json::Value,
+    },
+    /// A string matching at least one regex of a list, like `matchesAny ["^a", "z$"]`.
+    MatchAny {
+        space0: Whitespace,
+        value: json::Value,
+    },
+    /// A number close to `value`, within `epsilon`, like `isCloseTo 3.14 within 0.01`.
+    IsCloseTo {
+        space0: Whitespace,
+        value: PredicateValue,
+        space1: Whitespace,
+        space2: Whitespace,
+        epsilon: PredicateValue,
+    },
     IsInteger {},
     IsFloat {},
+    IsNumber {},
     IsBoolean {},
     IsString {},
     IsCollection {},
+    IsSorted {},
+    IsSortedDescending {},
     Exist {},
     IsEmpty {},
+    IsValidJson {},
+}
+
+/// The wildcard of a `matchesTemplate` predicate, kept as a string element of its template.
+pub const TEMPLATE_WILDCARD: &str = "{{*}}";
+
+/// The JSON Schema of a `matchesSchema` predicate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SchemaSource {
+    /// An inline schema, like `matchesSchema {"type": "object"}`.
+    Inline(json::Value),
+    /// A schema read from a file, like `matchesSchema file "schema.json"`.
+    File {
+        space0: Whitespace,
+        filename: Template,
+    },
 }
 
 //
@@ -513,6 +700,10 @@ pub enum MultilineString {
 impl MultilineString {
     pub fn lang(&self) -> &'static str {
         match self {
+            // A plain text with a charset is written with an explicit `text` lang.
+            MultilineString::Text(Text {
+                charset: Some(_), ..
+            }) => "text",
             MultilineString::OneLineText(_) | MultilineString::Text(_) => "",
             MultilineString::Json(_) => "json",
             MultilineString::Xml(_) => "xml",
@@ -529,15 +720,58 @@ impl MultilineString {
             MultilineString::GraphQl(text) => text.value.clone(),
         }
     }
+
+    pub fn charset(&self) -> Option<&MultilineCharset> {
+        match self {
+            MultilineString::Text(text)
+            | MultilineString::Json(text)
+            | MultilineString::Xml(text) => text.charset.as_ref(),
+            MultilineString::OneLineText(_) | MultilineString::GraphQl(_) => None,
+        }
+    }
+
+    pub fn content_type(&self) -> Option<&MultilineContentType> {
+        match self {
+            MultilineString::Text(text)
+            | MultilineString::Json(text)
+            | MultilineString::Xml(text) => text.content_type.as_ref(),
+            MultilineString::OneLineText(_) | MultilineString::GraphQl(_) => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Text {
+    pub charset: Option<MultilineCharset>,
+    pub content_type: Option<MultilineContentType>,
     pub space: Whitespace,
     pub newline: Whitespace,
     pub value: Template,
 }
 
+/// An explicit charset qualifier on a multiline string fence, like ```` ```text; charset=utf-16 ````.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultilineCharset {
+    pub space0: Whitespace,
+    pub value: Charset,
+}
+
+/// An explicit content type on a multiline string fence, overriding the one inferred from
+/// its lang, like ```` ```json; content-type=application/vnd.api+json ````.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultilineContentType {
+    pub space0: Whitespace,
+    pub value: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Charset {
+    Utf8,
+    Utf16,
+    Utf16Be,
+    Utf16Le,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GraphQl {
     pub space: Whitespace,
@@ -575,6 +809,38 @@ pub struct Template {
     pub source_info: SourceInfo,
 }
 
+impl Template {
+    /// Returns a new template, appending the elements of `other` to the elements of this template.
+    ///
+    /// Adjacent string elements are merged, and the source info spans both templates.
+    pub fn concat(&self, other: &Template) -> Template {
+        let mut elements = self.elements.clone();
+        for element in &other.elements {
+            match (elements.last_mut(), element) {
+                (
+                    Some(TemplateElement::String { value, encoded }),
+                    TemplateElement::String {
+                        value: other_value,
+                        encoded: other_encoded,
+                    },
+                ) => {
+                    value.push_str(other_value);
+                    encoded.push_str(other_encoded);
+                }
+                _ => elements.push(element.clone()),
+            }
+        }
+        Template {
+            delimiter: self.delimiter,
+            elements,
+            source_info: SourceInfo {
+                start: self.source_info.start.clone(),
+                end: other.source_info.end.clone(),
+            },
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum TemplateElement {
     // TODO: explain the difference between value and encoded
@@ -638,14 +904,20 @@ pub struct LineTerminator {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
 pub enum Bytes {
     Json(json::Value),
     Xml(String),
     MultilineString(MultilineString),
     OnelineString(Template),
+    /// A body made of a single expression, like `{{payload}}`, sent as is.
+    Template(Template),
     Base64(Base64),
     File(File),
     Hex(Hex),
+    /// The response body of a previous entry, like `from-response 1;` (1 being the previous
+    /// entry), resolved when the file is run.
+    FromResponse(usize),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -720,11 +992,17 @@ pub enum EntryOption {
     ClientCert(ClientCertOption),
     ClientKey(ClientKeyOption),
     Compressed(CompressedOption),
+    ConnectTimeout(ConnectTimeoutOption),
+    Curl(CurlOption),
     Insecure(InsecureOption),
     FollowLocation(FollowLocationOption),
     MaxRedirect(MaxRedirectOption),
+    NoProxy(NoProxyOption),
+    Proxy(ProxyOption),
+    Repeat(RepeatOption),
     Retry(RetryOption),
     RetryInterval(RetryIntervalOption),
+    Timeout(TimeoutOption),
     Variable(VariableOption),
     Verbose(VerboseOption),
     VeryVerbose(VeryVerboseOption),
@@ -780,6 +1058,65 @@ pub struct ClientKeyOption {
     pub line_terminator0: LineTerminator,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RepeatOption {
+    pub line_terminators: Vec<LineTerminator>,
+    pub space0: Whitespace,
+    pub space1: Whitespace,
+    pub space2: Whitespace,
+    pub value: usize,
+    pub line_terminator0: LineTerminator,
+}
+
+/// Maximum time of a request, like `timeout: 30s`, a zero duration meaning no timeout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimeoutOption {
+    pub line_terminators: Vec<LineTerminator>,
+    pub space0: Whitespace,
+    pub space1: Whitespace,
+    pub space2: Whitespace,
+    pub value: Duration,
+    pub line_terminator0: LineTerminator,
+}
+
+/// Maximum time of the connection phase of a request, like `connect-timeout: 5s`, a zero
+/// duration meaning no timeout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConnectTimeoutOption {
+    pub line_terminators: Vec<LineTerminator>,
+    pub space0: Whitespace,
+    pub space1: Whitespace,
+    pub space2: Whitespace,
+    pub value: Duration,
+    pub line_terminator0: LineTerminator,
+}
+
+/// A duration, like `30s`, in milliseconds when no unit is given.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Duration {
+    pub value: u64,
+    pub unit: Option<DurationUnit>,
+}
+
+impl Duration {
+    /// Returns the number of milliseconds of this duration.
+    pub fn millis(&self) -> u64 {
+        let factor = match self.unit {
+            None | Some(DurationUnit::Millisecond) => 1,
+            Some(DurationUnit::Second) => 1000,
+            Some(DurationUnit::Minute) => 60 * 1000,
+        };
+        self.value.saturating_mul(factor)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DurationUnit {
+    Millisecond,
+    Second,
+    Minute,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RetryIntervalOption {
     pub line_terminators: Vec<LineTerminator>,
@@ -840,6 +1177,46 @@ pub struct MaxRedirectOption {
     pub line_terminator0: LineTerminator,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProxyOption {
+    pub line_terminators: Vec<LineTerminator>,
+    pub space0: Whitespace,
+    pub space1: Whitespace,
+    pub space2: Whitespace,
+    pub value: Proxy,
+    pub line_terminator0: LineTerminator,
+}
+
+/// A proxy, written as `[protocol://]host[:port]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proxy {
+    pub protocol: Option<String>,
+    pub host: String,
+    pub port: Option<u16>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CurlOption {
+    pub line_terminators: Vec<LineTerminator>,
+    pub space0: Whitespace,
+    pub space1: Whitespace,
+    pub space2: Whitespace,
+    /// Extra curl arguments, kept verbatim as written on the line (ex: `--compressed --http2`).
+    pub value: String,
+    pub line_terminator0: LineTerminator,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoProxyOption {
+    pub line_terminators: Vec<LineTerminator>,
+    pub space0: Whitespace,
+    pub space1: Whitespace,
+    pub space2: Whitespace,
+    /// Hosts which do not use a proxy, written as a comma separated list.
+    pub value: Vec<String>,
+    pub line_terminator0: LineTerminator,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct VariableOption {
     pub line_terminators: Vec<LineTerminator>,
@@ -884,6 +1261,10 @@ pub enum FilterValue {
     },
     HtmlEscape,
     HtmlUnescape,
+    JwtClaim {
+        space0: Whitespace,
+        name: Template,
+    },
     Nth {
         space0: Whitespace,
         n: u64,
@@ -38,6 +38,7 @@ impl fmt::Display for Method {
             Method::Unlock => "UNLOCK",
             Method::Propfind => "PROPFIND",
             Method::View => "VIEW",
+            Method::Custom(name) => name,
         };
         write!(f, "{s}")
     }
@@ -73,6 +74,8 @@ impl fmt::Display for StatusValue {
         match self {
             StatusValue::Any => write!(f, "*"),
             StatusValue::Specific(v) => write!(f, "{v}"),
+            StatusValue::Class(v) => write!(f, "{v}xx"),
+            StatusValue::Range(start, end) => write!(f, "{start}-{end}"),
         }
     }
 }
@@ -152,6 +155,52 @@ impl fmt::Display for Regex {
     }
 }
 
+impl fmt::Display for Charset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            Charset::Utf8 => "utf-8",
+            Charset::Utf16 => "utf-16",
+            Charset::Utf16Be => "utf-16be",
+            Charset::Utf16Le => "utf-16le",
+        };
+        write!(f, "{value}")
+    }
+}
+
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = match self.unit {
+            SizeUnit::Kilobyte => "KB",
+            SizeUnit::Megabyte => "MB",
+        };
+        write!(f, "{}{unit}", self.value)
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = match self.unit {
+            None => "",
+            Some(DurationUnit::Millisecond) => "ms",
+            Some(DurationUnit::Second) => "s",
+            Some(DurationUnit::Minute) => "m",
+        };
+        write!(f, "{}{unit}", self.value)
+    }
+}
+
+impl fmt::Display for MultilineCharset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ";{}charset={}", self.space0.value, self.value)
+    }
+}
+
+impl fmt::Display for MultilineContentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ";{}content-type={}", self.space0.value, self.value)
+    }
+}
+
 impl fmt::Display for MultilineString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let body = match self {
@@ -222,19 +271,49 @@ impl PredicateFuncValue {
                 }
             }
             PredicateFuncValue::CountEqual { .. } => "countEquals".to_string(),
+            PredicateFuncValue::LengthEqual { .. } => "lengthEquals".to_string(),
+            PredicateFuncValue::CountGreaterThan { .. } => "countGreaterThan".to_string(),
+            PredicateFuncValue::CountGreaterThanOrEqual { .. } => {
+                "countGreaterThanOrEquals".to_string()
+            }
+            PredicateFuncValue::CountLessThan { .. } => "countLessThan".to_string(),
+            PredicateFuncValue::CountLessThanOrEqual { .. } => "countLessThanOrEquals".to_string(),
             PredicateFuncValue::StartWith { .. } => "startsWith".to_string(),
             PredicateFuncValue::EndWith { .. } => "endsWith".to_string(),
             PredicateFuncValue::Contain { .. } => "contains".to_string(),
             PredicateFuncValue::Include { .. } => "includes".to_string(),
             PredicateFuncValue::Match { .. } => "matches".to_string(),
+            PredicateFuncValue::MatchSchema { .. } => "matchesSchema".to_string(),
+            PredicateFuncValue::EqualIgnoreOrder { .. } => "equalsIgnoreOrder".to_string(),
+            PredicateFuncValue::MatchTemplate { .. } => "matchesTemplate".to_string(),
+            PredicateFuncValue::MatchAll { .. } => "matchesAll".to_string(),
+            PredicateFuncValue::MatchAny { .. } => "matchesAny".to_string(),
             PredicateFuncValue::IsInteger { .. } => "isInteger".to_string(),
             PredicateFuncValue::IsFloat { .. } => "isFloat".to_string(),
+            PredicateFuncValue::IsCloseTo { .. } => "isCloseTo".to_string(),
+            PredicateFuncValue::IsNumber { .. } => "isNumber".to_string(),
             PredicateFuncValue::IsBoolean { .. } => "isBoolean".to_string(),
             PredicateFuncValue::IsString { .. } => "isString".to_string(),
             PredicateFuncValue::IsCollection { .. } => "isCollection".to_string(),
+            PredicateFuncValue::IsSorted { .. } => "isSorted".to_string(),
+            PredicateFuncValue::IsSortedDescending { .. } => "isSortedDescending".to_string(),
             PredicateFuncValue::Exist { .. } => "exists".to_string(),
             PredicateFuncValue::IsEmpty { .. } => "isEmpty".to_string(),
+            PredicateFuncValue::IsValidJson { .. } => "isValidJson".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Proxy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(protocol) = &self.protocol {
+            write!(f, "{protocol}://")?;
         }
+        write!(f, "{}", self.host)?;
+        if let Some(port) = self.port {
+            write!(f, ":{port}")?;
+        }
+        Ok(())
     }
 }
 
@@ -330,6 +409,43 @@ mod tests {
         assert_eq!(hello_template().to_string(), "Hello {{name}}!");
     }
 
+    #[test]
+    fn test_template_concat() {
+        let template = Template {
+            delimiter: None,
+            elements: vec![TemplateElement::String {
+                value: " Bye".to_string(),
+                encoded: " Bye".to_string(),
+            }],
+            source_info: SourceInfo::new(2, 1, 2, 5),
+        };
+        let hello = Template {
+            source_info: SourceInfo::new(1, 1, 1, 16),
+            ..hello_template()
+        };
+        let concat = hello.concat(&template);
+        assert_eq!(concat.to_string(), "Hello {{name}}! Bye");
+        assert_eq!(
+            concat.elements,
+            vec![
+                TemplateElement::String {
+                    value: "Hello ".to_string(),
+                    encoded: "Hello ".to_string(),
+                },
+                TemplateElement::Expression(variable_expr()),
+                TemplateElement::String {
+                    value: "! Bye".to_string(),
+                    encoded: "! Bye".to_string(),
+                },
+            ]
+        );
+        assert_eq!(concat.source_info, SourceInfo::new(1, 1, 2, 5));
+
+        let concat = template.concat(&hello);
+        assert_eq!(concat.to_string(), " ByeHello {{name}}!");
+        assert_eq!(concat.elements.len(), 3);
+    }
+
     #[test]
     fn test_cookie_path() {
         assert_eq!(
@@ -35,10 +35,14 @@ pub enum Value {
     List {
         space0: String,
         elements: Vec<ListElement>,
+        /// Comments after the last element (or in an empty list), before the closing bracket.
+        comments: Vec<Comment>,
     },
     Object {
         space0: String,
         elements: Vec<ObjectElement>,
+        /// Comments after the last element (or in an empty object), before the closing brace.
+        comments: Vec<Comment>,
     },
     Null {},
 }
@@ -60,6 +64,7 @@ impl Value {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ListElement {
     pub space0: String,
+    pub comments: Vec<Comment>,
     pub value: Value,
     pub space1: String,
 }
@@ -67,6 +72,7 @@ pub struct ListElement {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ObjectElement {
     pub space0: String,
+    pub comments: Vec<Comment>,
     pub name: Template,
     pub space1: String,
     pub space2: String,
@@ -74,6 +80,16 @@ pub struct ObjectElement {
     pub space3: String,
 }
 
+/// A JSON line comment (`// comment`) attached to the element that follows it.
+///
+/// Comments are not part of the JSON value: they are only kept so that the body can be
+/// formatted back as it was written.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comment {
+    pub value: String,
+    pub space0: String,
+}
+
 impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let s = match self {
@@ -87,19 +103,29 @@ impl fmt::Display for Value {
                     "false".to_string()
                 }
             }
-            Value::List { space0, elements } => {
+            Value::List {
+                space0,
+                elements,
+                comments,
+            } => {
                 let elements = elements
                     .iter()
                     .map(|e| e.to_string())
                     .collect::<Vec<String>>();
-                format!("[{}{}]", space0, elements.join(","))
+                let comments = comments.iter().map(|c| c.to_string()).collect::<String>();
+                format!("[{}{}{}]", space0, elements.join(","), comments)
             }
-            Value::Object { space0, elements } => {
+            Value::Object {
+                space0,
+                elements,
+                comments,
+            } => {
                 let elements = elements
                     .iter()
                     .map(|e| e.to_string())
                     .collect::<Vec<String>>();
-                format!("{{{}{}}}", space0, elements.join(","))
+                let comments = comments.iter().map(|c| c.to_string()).collect::<String>();
+                format!("{{{}{}{}}}", space0, elements.join(","), comments)
             }
             Value::Null { .. } => "null".to_string(),
         };
@@ -111,6 +137,9 @@ impl fmt::Display for ListElement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut s = "".to_string();
         s.push_str(self.space0.as_str());
+        for comment in &self.comments {
+            s.push_str(comment.to_string().as_str());
+        }
         s.push_str(self.value.to_string().as_str());
         s.push_str(self.space1.as_str());
         write!(f, "{s}")
@@ -121,6 +150,9 @@ impl fmt::Display for ObjectElement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut s = "".to_string();
         s.push_str(self.space0.as_str());
+        for comment in &self.comments {
+            s.push_str(comment.to_string().as_str());
+        }
         s.push('"');
         s.push_str(self.name.to_string().as_str());
         s.push('"');
@@ -133,6 +165,12 @@ impl fmt::Display for ObjectElement {
     }
 }
 
+impl fmt::Display for Comment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "//{}{}", self.value, self.space0)
+    }
+}
+
 impl Value {
     pub fn encoded(&self) -> String {
         match self {
@@ -146,19 +184,29 @@ impl Value {
                     "false".to_string()
                 }
             }
-            Value::List { space0, elements } => {
+            Value::List {
+                space0,
+                elements,
+                comments,
+            } => {
                 let elements = elements
                     .iter()
                     .map(|e| e.encoded())
                     .collect::<Vec<String>>();
-                format!("[{}{}]", space0, elements.join(","))
+                let comments = comments.iter().map(|c| c.to_string()).collect::<String>();
+                format!("[{}{}{}]", space0, elements.join(","), comments)
             }
-            Value::Object { space0, elements } => {
+            Value::Object {
+                space0,
+                elements,
+                comments,
+            } => {
                 let elements = elements
                     .iter()
                     .map(|e| e.encoded())
                     .collect::<Vec<String>>();
-                format!("{{{}{}}}", space0, elements.join(","))
+                let comments = comments.iter().map(|c| c.to_string()).collect::<String>();
+                format!("{{{}{}{}}}", space0, elements.join(","), comments)
             }
             Value::Null { .. } => "null".to_string(),
         }
@@ -169,6 +217,9 @@ impl ListElement {
     fn encoded(&self) -> String {
         let mut s = "".to_string();
         s.push_str(self.space0.as_str());
+        for comment in &self.comments {
+            s.push_str(comment.to_string().as_str());
+        }
         s.push_str(self.value.encoded().as_str());
         s.push_str(self.space1.as_str());
         s
@@ -179,6 +230,9 @@ impl ObjectElement {
     fn encoded(&self) -> String {
         let mut s = "".to_string();
         s.push_str(self.space0.as_str());
+        for comment in &self.comments {
+            s.push_str(comment.to_string().as_str());
+        }
         s.push_str(self.name.encoded().as_str());
         s.push_str(self.space1.as_str());
         s.push(':');
@@ -257,6 +311,7 @@ mod tests {
             Value::List {
                 space0: "".to_string(),
                 elements: vec![],
+                comments: vec![],
             }
             .to_string()
         );
@@ -267,20 +322,24 @@ mod tests {
                 elements: vec![
                     ListElement {
                         space0: "".to_string(),
+                        comments: vec![],
                         value: Value::Number("1".to_string()),
                         space1: "".to_string(),
                     },
                     ListElement {
                         space0: " ".to_string(),
+                        comments: vec![],
                         value: Value::Number("2".to_string()),
                         space1: "".to_string(),
                     },
                     ListElement {
                         space0: " ".to_string(),
+                        comments: vec![],
                         value: Value::Number("3".to_string()),
                         space1: "".to_string(),
                     }
                 ],
+                comments: vec![],
             }
             .to_string()
         );
@@ -289,6 +348,7 @@ mod tests {
             Value::Object {
                 space0: "".to_string(),
                 elements: vec![],
+                comments: vec![],
             }
             .to_string()
         );
@@ -298,6 +358,7 @@ mod tests {
                 space0: "".to_string(),
                 elements: vec![ObjectElement {
                     space0: " ".to_string(),
+                    comments: vec![],
                     name: Template {
                         delimiter: Some('"'),
                         elements: vec![TemplateElement::String {
@@ -311,6 +372,7 @@ mod tests {
                     value: Value::Number("123".to_string()),
                     space3: " ".to_string(),
                 }],
+                comments: vec![],
             }
             .to_string()
         );
@@ -18,9 +18,12 @@
 
 pub use self::core::*;
 pub use self::json::{
-    ListElement as JsonListElement, ObjectElement as JsonObjectElement, Value as JsonValue,
+    Comment as JsonComment, ListElement as JsonListElement, ObjectElement as JsonObjectElement,
+    Value as JsonValue,
 };
 
 mod core;
 mod display;
 mod json;
+mod normalize;
+mod rename;
@@ -0,0 +1,982 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use crate::ast::core::*;
+use crate::ast::json;
+
+impl HurlFile {
+    /// Returns a copy of this file without any formatting information.
+    ///
+    /// Whitespaces are emptied, source infos are zeroed and empty lines are removed, so that
+    /// two files differing only by their formatting have equal normalized ASTs. Comments are kept.
+    pub fn normalize(&self) -> HurlFile {
+        HurlFile {
+            settings: self.settings.normalize(),
+            entries: self.entries.normalize(),
+            line_terminators: normalize_line_terminators(&self.line_terminators),
+        }
+    }
+}
+
+trait Normalize {
+    fn normalize(&self) -> Self;
+}
+
+impl<T: Normalize> Normalize for Vec<T> {
+    fn normalize(&self) -> Self {
+        self.iter().map(|e| e.normalize()).collect()
+    }
+}
+
+impl<T: Normalize> Normalize for Option<T> {
+    fn normalize(&self) -> Self {
+        self.as_ref().map(|e| e.normalize())
+    }
+}
+
+fn empty_source_info() -> SourceInfo {
+    SourceInfo::new(0, 0, 0, 0)
+}
+
+fn empty_whitespace() -> Whitespace {
+    Whitespace {
+        value: String::new(),
+        source_info: empty_source_info(),
+    }
+}
+
+/// Removes empty lines, only the lines with a comment are kept.
+fn normalize_line_terminators(line_terminators: &[LineTerminator]) -> Vec<LineTerminator> {
+    line_terminators
+        .iter()
+        .filter(|lt| lt.comment.is_some())
+        .map(|lt| lt.normalize())
+        .collect()
+}
+
+impl Normalize for Settings {
+    fn normalize(&self) -> Self {
+        Settings {
+            line_terminators: normalize_line_terminators(&self.line_terminators),
+            space0: empty_whitespace(),
+            line_terminator0: self.line_terminator0.normalize(),
+            options: self.options.normalize(),
+            source_info: empty_source_info(),
+        }
+    }
+}
+
+impl Normalize for Entry {
+    fn normalize(&self) -> Self {
+        Entry {
+            request: self.request.normalize(),
+            response: self.response.normalize(),
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl Normalize for Request {
+    fn normalize(&self) -> Self {
+        Request {
+            line_terminators: normalize_line_terminators(&self.line_terminators),
+            space0: empty_whitespace(),
+            method: self.method.clone(),
+            space1: empty_whitespace(),
+            url: self.url.normalize(),
+            line_terminator0: self.line_terminator0.normalize(),
+            headers: self.headers.normalize(),
+            sections: self.sections.normalize(),
+            body: self.body.normalize(),
+            source_info: empty_source_info(),
+        }
+    }
+}
+
+impl Normalize for Response {
+    fn normalize(&self) -> Self {
+        Response {
+            line_terminators: normalize_line_terminators(&self.line_terminators),
+            version: Version {
+                value: self.version.value.clone(),
+                source_info: empty_source_info(),
+            },
+            space0: empty_whitespace(),
+            status: Status {
+                value: self.status.value.clone(),
+                source_info: empty_source_info(),
+            },
+            space1: empty_whitespace(),
+            line_terminator0: self.line_terminator0.normalize(),
+            headers: self.headers.normalize(),
+            sections: self.sections.normalize(),
+            body: self.body.normalize(),
+            source_info: empty_source_info(),
+        }
+    }
+}
+
+impl Normalize for Body {
+    fn normalize(&self) -> Self {
+        Body {
+            line_terminators: normalize_line_terminators(&self.line_terminators),
+            space0: empty_whitespace(),
+            value: self.value.normalize(),
+            line_terminator0: self.line_terminator0.normalize(),
+        }
+    }
+}
+
+impl Normalize for Section {
+    fn normalize(&self) -> Self {
+        Section {
+            line_terminators: normalize_line_terminators(&self.line_terminators),
+            space0: empty_whitespace(),
+            line_terminator0: self.line_terminator0.normalize(),
+            value: self.value.normalize(),
+            source_info: empty_source_info(),
+        }
+    }
+}
+
+impl Normalize for SectionValue {
+    fn normalize(&self) -> Self {
+        match self {
+            SectionValue::QueryParams(params) => SectionValue::QueryParams(params.normalize()),
+            SectionValue::BasicAuth(kv) => SectionValue::BasicAuth(kv.normalize()),
+            SectionValue::FormParams(params) => SectionValue::FormParams(params.normalize()),
+            SectionValue::MultipartFormData(params) => {
+                SectionValue::MultipartFormData(params.normalize())
+            }
+            SectionValue::Cookies(cookies) => SectionValue::Cookies(cookies.normalize()),
+            SectionValue::Captures(captures) => SectionValue::Captures(captures.normalize()),
+            SectionValue::Asserts(asserts) => SectionValue::Asserts(asserts.normalize()),
+            SectionValue::Options(options) => SectionValue::Options(options.normalize()),
+        }
+    }
+}
+
+impl Normalize for Cookie {
+    fn normalize(&self) -> Self {
+        Cookie {
+            line_terminators: normalize_line_terminators(&self.line_terminators),
+            space0: empty_whitespace(),
+            name: self.name.normalize(),
+            space1: empty_whitespace(),
+            space2: empty_whitespace(),
+            value: self.value.normalize(),
+            line_terminator0: self.line_terminator0.normalize(),
+        }
+    }
+}
+
+impl Normalize for KeyValue {
+    fn normalize(&self) -> Self {
+        KeyValue {
+            line_terminators: normalize_line_terminators(&self.line_terminators),
+            space0: empty_whitespace(),
+            key: self.key.normalize(),
+            space1: empty_whitespace(),
+            space2: empty_whitespace(),
+            value: self.value.normalize(),
+            line_terminator0: self.line_terminator0.normalize(),
+        }
+    }
+}
+
+impl Normalize for MultipartParam {
+    fn normalize(&self) -> Self {
+        match self {
+            MultipartParam::Param(param) => MultipartParam::Param(param.normalize()),
+            MultipartParam::FileParam(param) => MultipartParam::FileParam(param.normalize()),
+        }
+    }
+}
+
+impl Normalize for FileParam {
+    fn normalize(&self) -> Self {
+        FileParam {
+            line_terminators: normalize_line_terminators(&self.line_terminators),
+            space0: empty_whitespace(),
+            key: self.key.normalize(),
+            space1: empty_whitespace(),
+            space2: empty_whitespace(),
+            value: FileValue {
+                space0: empty_whitespace(),
+                filename: self.value.filename.normalize(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                content_type: self.value.content_type.clone(),
+            },
+            line_terminator0: self.line_terminator0.normalize(),
+        }
+    }
+}
+
+impl Normalize for Capture {
+    fn normalize(&self) -> Self {
+        Capture {
+            line_terminators: normalize_line_terminators(&self.line_terminators),
+            space0: empty_whitespace(),
+            name: self.name.normalize(),
+            space1: empty_whitespace(),
+            space2: empty_whitespace(),
+            query: self.query.normalize(),
+            filters: self.filters.normalize(),
+            line_terminator0: self.line_terminator0.normalize(),
+        }
+    }
+}
+
+impl Normalize for Assert {
+    fn normalize(&self) -> Self {
+        Assert {
+            line_terminators: normalize_line_terminators(&self.line_terminators),
+            space0: empty_whitespace(),
+            query: self.query.normalize(),
+            filters: self.filters.normalize(),
+            space1: empty_whitespace(),
+            predicate: self.predicate.normalize(),
+            line_terminator0: self.line_terminator0.normalize(),
+        }
+    }
+}
+
+impl Normalize for Query {
+    fn normalize(&self) -> Self {
+        let value = match &self.value {
+            QueryValue::Header { name, .. } => QueryValue::Header {
+                space0: empty_whitespace(),
+                name: name.normalize(),
+            },
+            QueryValue::Cookie { expr, .. } => QueryValue::Cookie {
+                space0: empty_whitespace(),
+                expr: CookiePath {
+                    name: expr.name.normalize(),
+                    attribute: expr.attribute.as_ref().map(|attribute| CookieAttribute {
+                        space0: empty_whitespace(),
+                        name: attribute.name.clone(),
+                        space1: empty_whitespace(),
+                    }),
+                },
+            },
+            QueryValue::Xpath { expr, .. } => QueryValue::Xpath {
+                space0: empty_whitespace(),
+                expr: expr.normalize(),
+            },
+            QueryValue::Jsonpath { expr, .. } => QueryValue::Jsonpath {
+                space0: empty_whitespace(),
+                expr: expr.normalize(),
+            },
+            QueryValue::Regex { value, .. } => QueryValue::Regex {
+                space0: empty_whitespace(),
+                value: value.normalize(),
+            },
+            QueryValue::Variable { name, .. } => QueryValue::Variable {
+                space0: empty_whitespace(),
+                name: name.normalize(),
+            },
+            QueryValue::Certificate { attribute_name, .. } => QueryValue::Certificate {
+                space0: empty_whitespace(),
+                attribute_name: attribute_name.clone(),
+            },
+            QueryValue::Status {}
+            | QueryValue::Url {}
+            | QueryValue::Charset {}
+            | QueryValue::Body {}
+            | QueryValue::Duration {}
+            | QueryValue::Bytes {}
+            | QueryValue::Sha256 {}
+            | QueryValue::Md5 {} => self.value.clone(),
+        };
+        Query {
+            source_info: empty_source_info(),
+            value,
+        }
+    }
+}
+
+impl Normalize for RegexValue {
+    fn normalize(&self) -> Self {
+        match self {
+            RegexValue::Template(template) => RegexValue::Template(template.normalize()),
+            RegexValue::Regex(regex) => RegexValue::Regex(regex.clone()),
+        }
+    }
+}
+
+impl Normalize for (Whitespace, Filter) {
+    fn normalize(&self) -> Self {
+        (empty_whitespace(), self.1.normalize())
+    }
+}
+
+impl Normalize for Filter {
+    fn normalize(&self) -> Self {
+        let value = match &self.value {
+            FilterValue::Format { fmt, .. } => FilterValue::Format {
+                space0: empty_whitespace(),
+                fmt: fmt.normalize(),
+            },
+            FilterValue::JwtClaim { name, .. } => FilterValue::JwtClaim {
+                space0: empty_whitespace(),
+                name: name.normalize(),
+            },
+            FilterValue::Nth { n, .. } => FilterValue::Nth {
+                space0: empty_whitespace(),
+                n: *n,
+            },
+            FilterValue::Regex { value, .. } => FilterValue::Regex {
+                space0: empty_whitespace(),
+                value: value.normalize(),
+            },
+            FilterValue::Replace {
+                old_value,
+                new_value,
+                ..
+            } => FilterValue::Replace {
+                space0: empty_whitespace(),
+                old_value: old_value.normalize(),
+                space1: empty_whitespace(),
+                new_value: new_value.normalize(),
+            },
+            FilterValue::Split { sep, .. } => FilterValue::Split {
+                space0: empty_whitespace(),
+                sep: sep.normalize(),
+            },
+            FilterValue::ToDate { fmt, .. } => FilterValue::ToDate {
+                space0: empty_whitespace(),
+                fmt: fmt.normalize(),
+            },
+            FilterValue::Count
+            | FilterValue::DaysAfterNow
+            | FilterValue::DaysBeforeNow
+            | FilterValue::HtmlEscape
+            | FilterValue::HtmlUnescape
+            | FilterValue::ToInt
+            | FilterValue::UrlDecode
+            | FilterValue::UrlEncode => self.value.clone(),
+        };
+        Filter {
+            source_info: empty_source_info(),
+            value,
+        }
+    }
+}
+
+impl Normalize for Predicate {
+    fn normalize(&self) -> Self {
+        Predicate {
+            not: self.not,
+            space0: empty_whitespace(),
+            predicate_func: PredicateFunc {
+                source_info: empty_source_info(),
+                value: self.predicate_func.value.normalize(),
+            },
+        }
+    }
+}
+
+impl Normalize for SchemaSource {
+    fn normalize(&self) -> Self {
+        match self {
+            SchemaSource::Inline(value) => SchemaSource::Inline(value.normalize()),
+            SchemaSource::File { filename, .. } => SchemaSource::File {
+                space0: empty_whitespace(),
+                filename: filename.normalize(),
+            },
+        }
+    }
+}
+
+impl Normalize for PredicateFuncValue {
+    fn normalize(&self) -> Self {
+        let space0 = empty_whitespace();
+        match self {
+            PredicateFuncValue::Equal {
+                value, operator, ..
+            } => PredicateFuncValue::Equal {
+                space0,
+                value: value.normalize(),
+                operator: *operator,
+            },
+            PredicateFuncValue::NotEqual {
+                value, operator, ..
+            } => PredicateFuncValue::NotEqual {
+                space0,
+                value: value.normalize(),
+                operator: *operator,
+            },
+            PredicateFuncValue::GreaterThan {
+                value, operator, ..
+            } => PredicateFuncValue::GreaterThan {
+                space0,
+                value: value.normalize(),
+                operator: *operator,
+            },
+            PredicateFuncValue::GreaterThanOrEqual {
+                value, operator, ..
+            } => PredicateFuncValue::GreaterThanOrEqual {
+                space0,
+                value: value.normalize(),
+                operator: *operator,
+            },
+            PredicateFuncValue::LessThan {
+                value, operator, ..
+            } => PredicateFuncValue::LessThan {
+                space0,
+                value: value.normalize(),
+                operator: *operator,
+            },
+            PredicateFuncValue::LessThanOrEqual {
+                value, operator, ..
+            } => PredicateFuncValue::LessThanOrEqual {
+                space0,
+                value: value.normalize(),
+                operator: *operator,
+            },
+            PredicateFuncValue::CountEqual { value, .. } => PredicateFuncValue::CountEqual {
+                space0,
+                value: value.normalize(),
+            },
+            PredicateFuncValue::CountGreaterThan { value, .. } => {
+                PredicateFuncValue::CountGreaterThan {
+                    space0,
+                    value: value.normalize(),
+                }
+            }
+            PredicateFuncValue::CountGreaterThanOrEqual { value, .. } => {
+                PredicateFuncValue::CountGreaterThanOrEqual {
+                    space0,
+                    value: value.normalize(),
+                }
+            }
+            PredicateFuncValue::CountLessThan { value, .. } => PredicateFuncValue::CountLessThan {
+                space0,
+                value: value.normalize(),
+            },
+            PredicateFuncValue::CountLessThanOrEqual { value, .. } => {
+                PredicateFuncValue::CountLessThanOrEqual {
+                    space0,
+                    value: value.normalize(),
+                }
+            }
+            PredicateFuncValue::LengthEqual { value, .. } => PredicateFuncValue::LengthEqual {
+                space0,
+                value: value.normalize(),
+            },
+            PredicateFuncValue::StartWith { value, .. } => PredicateFuncValue::StartWith {
+                space0,
+                value: value.normalize(),
+            },
+            PredicateFuncValue::EndWith { value, .. } => PredicateFuncValue::EndWith {
+                space0,
+                value: value.normalize(),
+            },
+            PredicateFuncValue::Contain { value, .. } => PredicateFuncValue::Contain {
+                space0,
+                value: value.normalize(),
+            },
+            PredicateFuncValue::Include { value, .. } => PredicateFuncValue::Include {
+                space0,
+                value: value.normalize(),
+            },
+            PredicateFuncValue::Match { value, .. } => PredicateFuncValue::Match {
+                space0,
+                value: value.normalize(),
+            },
+            PredicateFuncValue::MatchSchema { value, .. } => PredicateFuncValue::MatchSchema {
+                space0,
+                value: value.normalize(),
+            },
+            PredicateFuncValue::MatchTemplate { value, .. } => PredicateFuncValue::MatchTemplate {
+                space0,
+                value: value.normalize(),
+            },
+            PredicateFuncValue::EqualIgnoreOrder { value, .. } => {
+                PredicateFuncValue::EqualIgnoreOrder {
+                    space0,
+                    value: value.normalize(),
+                }
+            }
+            PredicateFuncValue::MatchAll { value, .. } => PredicateFuncValue::MatchAll {
+                space0,
+                value: value.normalize(),
+            },
+            PredicateFuncValue::MatchAny { value, .. } => PredicateFuncValue::MatchAny {
+                space0,
+                value: value.normalize(),
+            },
+            PredicateFuncValue::IsCloseTo { value, epsilon, .. } => PredicateFuncValue::IsCloseTo {
+                space0,
+                value: value.normalize(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                epsilon: epsilon.normalize(),
+            },
+            PredicateFuncValue::IsInteger {}
+            | PredicateFuncValue::IsFloat {}
+            | PredicateFuncValue::IsNumber {}
+            | PredicateFuncValue::IsBoolean {}
+            | PredicateFuncValue::IsString {}
+            | PredicateFuncValue::IsCollection {}
+            | PredicateFuncValue::IsSorted {}
+            | PredicateFuncValue::IsSortedDescending {}
+            | PredicateFuncValue::Exist {}
+            | PredicateFuncValue::IsEmpty {}
+            | PredicateFuncValue::IsValidJson {} => self.clone(),
+        }
+    }
+}
+
+impl Normalize for PredicateValue {
+    fn normalize(&self) -> Self {
+        match self {
+            PredicateValue::String(template) => PredicateValue::String(template.normalize()),
+            PredicateValue::MultilineString(multiline) => {
+                PredicateValue::MultilineString(multiline.normalize())
+            }
+            PredicateValue::Hex(hex) => PredicateValue::Hex(hex.normalize()),
+            PredicateValue::Base64(base64) => PredicateValue::Base64(base64.normalize()),
+            PredicateValue::Expression(expr) => PredicateValue::Expression(expr.normalize()),
+            PredicateValue::Integer(_)
+            | PredicateValue::Float(_)
+            | PredicateValue::Bool(_)
+            | PredicateValue::Null {}
+            | PredicateValue::Regex(_)
+            | PredicateValue::Size(_) => self.clone(),
+        }
+    }
+}
+
+impl Normalize for MultilineString {
+    fn normalize(&self) -> Self {
+        match self {
+            MultilineString::OneLineText(template) => {
+                MultilineString::OneLineText(template.normalize())
+            }
+            MultilineString::Text(text) => MultilineString::Text(text.normalize()),
+            MultilineString::Json(text) => MultilineString::Json(text.normalize()),
+            MultilineString::Xml(text) => MultilineString::Xml(text.normalize()),
+            MultilineString::GraphQl(graphql) => MultilineString::GraphQl(GraphQl {
+                space: empty_whitespace(),
+                newline: empty_whitespace(),
+                value: graphql.value.normalize(),
+                variables: graphql
+                    .variables
+                    .as_ref()
+                    .map(|variables| GraphQlVariables {
+                        space: empty_whitespace(),
+                        value: variables.value.normalize(),
+                        whitespace: empty_whitespace(),
+                    }),
+            }),
+        }
+    }
+}
+
+impl Normalize for Text {
+    fn normalize(&self) -> Self {
+        Text {
+            charset: self.charset.as_ref().map(|charset| MultilineCharset {
+                space0: empty_whitespace(),
+                value: charset.value.clone(),
+            }),
+            content_type: self
+                .content_type
+                .as_ref()
+                .map(|content_type| MultilineContentType {
+                    space0: empty_whitespace(),
+                    value: content_type.value.clone(),
+                }),
+            space: empty_whitespace(),
+            newline: empty_whitespace(),
+            value: self.value.normalize(),
+        }
+    }
+}
+
+impl Normalize for Bytes {
+    fn normalize(&self) -> Self {
+        match self {
+            Bytes::Json(value) => Bytes::Json(value.normalize()),
+            Bytes::Xml(value) => Bytes::Xml(value.clone()),
+            Bytes::MultilineString(multiline) => Bytes::MultilineString(multiline.normalize()),
+            Bytes::OnelineString(template) => Bytes::OnelineString(template.normalize()),
+            Bytes::Template(template) => Bytes::Template(template.normalize()),
+            Bytes::Base64(base64) => Bytes::Base64(base64.normalize()),
+            Bytes::File(file) => Bytes::File(File {
+                space0: empty_whitespace(),
+                filename: file.filename.normalize(),
+                space1: empty_whitespace(),
+            }),
+            Bytes::Hex(hex) => Bytes::Hex(hex.normalize()),
+            Bytes::FromResponse(index) => Bytes::FromResponse(*index),
+        }
+    }
+}
+
+impl Normalize for Base64 {
+    fn normalize(&self) -> Self {
+        Base64 {
+            space0: empty_whitespace(),
+            value: self.value.clone(),
+            encoded: self.encoded.clone(),
+            space1: empty_whitespace(),
+        }
+    }
+}
+
+impl Normalize for Hex {
+    fn normalize(&self) -> Self {
+        Hex {
+            space0: empty_whitespace(),
+            value: self.value.clone(),
+            encoded: self.encoded.clone(),
+            space1: empty_whitespace(),
+        }
+    }
+}
+
+impl Normalize for Template {
+    fn normalize(&self) -> Self {
+        let elements = self
+            .elements
+            .iter()
+            .map(|element| match element {
+                TemplateElement::String { .. } => element.clone(),
+                TemplateElement::Expression(expr) => TemplateElement::Expression(expr.normalize()),
+            })
+            .collect();
+        Template {
+            delimiter: self.delimiter,
+            elements,
+            source_info: empty_source_info(),
+        }
+    }
+}
+
+impl Normalize for Expr {
+    fn normalize(&self) -> Self {
+        Expr {
+            space0: empty_whitespace(),
+            variable: Variable {
+                name: self.variable.name.clone(),
+                source_info: empty_source_info(),
+            },
+            space1: empty_whitespace(),
+        }
+    }
+}
+
+impl Normalize for EncodedString {
+    fn normalize(&self) -> Self {
+        EncodedString {
+            value: self.value.clone(),
+            encoded: self.encoded.clone(),
+            quotes: self.quotes,
+            source_info: empty_source_info(),
+        }
+    }
+}
+
+impl Normalize for Filename {
+    fn normalize(&self) -> Self {
+        Filename {
+            value: self.value.clone(),
+            source_info: empty_source_info(),
+        }
+    }
+}
+
+impl Normalize for LineTerminator {
+    fn normalize(&self) -> Self {
+        LineTerminator {
+            space0: empty_whitespace(),
+            comment: self.comment.clone(),
+            newline: empty_whitespace(),
+        }
+    }
+}
+
+impl Normalize for EntryOption {
+    fn normalize(&self) -> Self {
+        match self {
+            EntryOption::CaCertificate(option) => EntryOption::CaCertificate(CaCertificateOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                filename: option.filename.normalize(),
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+            EntryOption::ClientCert(option) => EntryOption::ClientCert(ClientCertOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                filename: option.filename.normalize(),
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+            EntryOption::ClientKey(option) => EntryOption::ClientKey(ClientKeyOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                filename: option.filename.normalize(),
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+            EntryOption::Compressed(option) => EntryOption::Compressed(CompressedOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                value: option.value,
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+            EntryOption::Insecure(option) => EntryOption::Insecure(InsecureOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                value: option.value,
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+            EntryOption::FollowLocation(option) => {
+                EntryOption::FollowLocation(FollowLocationOption {
+                    line_terminators: normalize_line_terminators(&option.line_terminators),
+                    space0: empty_whitespace(),
+                    space1: empty_whitespace(),
+                    space2: empty_whitespace(),
+                    value: option.value,
+                    line_terminator0: option.line_terminator0.normalize(),
+                })
+            }
+            EntryOption::MaxRedirect(option) => EntryOption::MaxRedirect(MaxRedirectOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                value: option.value,
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+            EntryOption::Curl(option) => EntryOption::Curl(CurlOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                value: option.value.clone(),
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+            EntryOption::NoProxy(option) => EntryOption::NoProxy(NoProxyOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                value: option.value.clone(),
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+            EntryOption::Proxy(option) => EntryOption::Proxy(ProxyOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                value: option.value.clone(),
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+            EntryOption::Repeat(option) => EntryOption::Repeat(RepeatOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                value: option.value,
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+            EntryOption::Retry(option) => EntryOption::Retry(RetryOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                value: option.value,
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+            EntryOption::RetryInterval(option) => EntryOption::RetryInterval(RetryIntervalOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                value: option.value,
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+            EntryOption::Timeout(option) => EntryOption::Timeout(TimeoutOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                value: option.value.clone(),
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+            EntryOption::ConnectTimeout(option) => {
+                EntryOption::ConnectTimeout(ConnectTimeoutOption {
+                    line_terminators: normalize_line_terminators(&option.line_terminators),
+                    space0: empty_whitespace(),
+                    space1: empty_whitespace(),
+                    space2: empty_whitespace(),
+                    value: option.value.clone(),
+                    line_terminator0: option.line_terminator0.normalize(),
+                })
+            }
+            EntryOption::Variable(option) => EntryOption::Variable(VariableOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                value: option.value.normalize(),
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+            EntryOption::Verbose(option) => EntryOption::Verbose(VerboseOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                value: option.value,
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+            EntryOption::VeryVerbose(option) => EntryOption::VeryVerbose(VeryVerboseOption {
+                line_terminators: normalize_line_terminators(&option.line_terminators),
+                space0: empty_whitespace(),
+                space1: empty_whitespace(),
+                space2: empty_whitespace(),
+                value: option.value,
+                line_terminator0: option.line_terminator0.normalize(),
+            }),
+        }
+    }
+}
+
+impl Normalize for VariableDefinition {
+    fn normalize(&self) -> Self {
+        let value = match &self.value {
+            VariableValue::String(template) => VariableValue::String(template.normalize()),
+            VariableValue::Null {}
+            | VariableValue::Bool(_)
+            | VariableValue::Integer(_)
+            | VariableValue::Float(_) => self.value.clone(),
+        };
+        VariableDefinition {
+            name: self.name.clone(),
+            space0: empty_whitespace(),
+            space1: empty_whitespace(),
+            value,
+        }
+    }
+}
+
+impl Normalize for json::Value {
+    fn normalize(&self) -> Self {
+        match self {
+            json::Value::Expression(expr) => json::Value::Expression(expr.normalize()),
+            json::Value::String(template) => json::Value::String(template.normalize()),
+            json::Value::List {
+                elements, comments, ..
+            } => json::Value::List {
+                space0: String::new(),
+                elements: elements
+                    .iter()
+                    .map(|element| json::ListElement {
+                        space0: String::new(),
+                        comments: element.comments.normalize(),
+                        value: element.value.normalize(),
+                        space1: String::new(),
+                    })
+                    .collect(),
+                comments: comments.normalize(),
+            },
+            json::Value::Object {
+                elements, comments, ..
+            } => json::Value::Object {
+                space0: String::new(),
+                elements: elements
+                    .iter()
+                    .map(|element| json::ObjectElement {
+                        space0: String::new(),
+                        comments: element.comments.normalize(),
+                        name: element.name.normalize(),
+                        space1: String::new(),
+                        space2: String::new(),
+                        value: element.value.normalize(),
+                        space3: String::new(),
+                    })
+                    .collect(),
+                comments: comments.normalize(),
+            },
+            json::Value::Number(_) | json::Value::Boolean(_) | json::Value::Null {} => self.clone(),
+        }
+    }
+}
+
+impl Normalize for json::Comment {
+    fn normalize(&self) -> Self {
+        json::Comment {
+            value: self.value.clone(),
+            space0: String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse_hurl_file;
+
+    #[test]
+    fn test_normalize() {
+        let s1 = r#"GET http://localhost:8000/hello
+x-foo: bar
+[QueryStringParams]
+id: 1
+
+HTTP 200
+[Asserts]
+jsonpath "$.name" == "Bob" # check name
+body contains "{{user}}"
+"#;
+        let s2 = r#"
+
+   GET    http://localhost:8000/hello
+  x-foo:   bar
+
+[QueryStringParams]
+    id:1
+HTTP   200
+
+[Asserts]
+    jsonpath   "$.name"   ==   "Bob"   # check name
+
+
+  body contains "{{ user }}"
+
+"#;
+        let file1 = parse_hurl_file(s1).unwrap();
+        let file2 = parse_hurl_file(s2).unwrap();
+        assert_ne!(file1, file2);
+        assert_eq!(file1.normalize(), file2.normalize());
+
+        let s3 = "GET http://localhost:8000/hello\nx-foo: baz\n";
+        let file3 = parse_hurl_file(s3).unwrap();
+        assert_ne!(file1.normalize(), file3.normalize());
+    }
+}
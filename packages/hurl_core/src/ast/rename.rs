@@ -0,0 +1,474 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashSet;
+
+use crate::ast::core::*;
+use crate::ast::json;
+
+impl HurlFile {
+    /// Renames the variable `from` to `to` in every expression `{{from}}` of this file.
+    ///
+    /// Expressions are renamed wherever they appear (urls, headers, sections, bodies, predicates
+    /// and filters), but the definitions of the variable, like captures, are kept. Source infos
+    /// are not updated.
+    pub fn rename_variable(&mut self, from: &str, to: &str) {
+        let mut rename = |variable: &mut Variable| {
+            if variable.name == from {
+                variable.name = to.to_string();
+            }
+        };
+        self.settings.visit_variables(&mut rename);
+        self.entries.visit_variables(&mut rename);
+    }
+
+    /// Returns the names of the variables captured by an entry, but never used in an expression
+    /// `{{name}}` of the following asserts and entries, in order.
+    pub fn unused_captures(&self) -> Vec<String> {
+        // Entries are visited once, from the last to the first, collecting the variables used
+        // after the current entry.
+        let mut used = HashSet::new();
+        let mut unused_per_entry = vec![];
+        for entry in self.entries.iter().rev() {
+            if let Some(response) = &entry.response {
+                response.for_each_variable(&mut |variable| {
+                    used.insert(variable.name.clone());
+                });
+                let unused = response
+                    .captures()
+                    .into_iter()
+                    .map(|capture| capture.name.value)
+                    .filter(|name| !used.contains(name))
+                    .collect::<Vec<_>>();
+                unused_per_entry.push(unused);
+            }
+            entry.request.for_each_variable(&mut |variable| {
+                used.insert(variable.name.clone());
+            });
+        }
+        let mut unused: Vec<String> = vec![];
+        for name in unused_per_entry.into_iter().rev().flatten() {
+            if !unused.contains(&name) {
+                unused.push(name);
+            }
+        }
+        unused
+    }
+}
+
+/// Defines the trait `$visit_trait`, visiting the variables of every expression `{{name}}` of a
+/// node (and its children) with `$visit`, mutably if `mut` is given.
+macro_rules! visit_variables {
+    ($visit_trait:ident, $visit:ident $(, $mut:tt)?) => {
+        trait $visit_trait {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable));
+        }
+
+        impl<T: $visit_trait> $visit_trait for [T] {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                for e in self {
+                    e.$visit(f);
+                }
+            }
+        }
+
+        impl<T: $visit_trait> $visit_trait for Option<T> {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                if let Some(e) = self {
+                    e.$visit(f);
+                }
+            }
+        }
+
+        impl $visit_trait for Settings {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                self.options.$visit(f);
+            }
+        }
+
+        impl $visit_trait for Entry {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                self.request.$visit(f);
+                self.response.$visit(f);
+            }
+        }
+
+        impl $visit_trait for Request {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                self.url.$visit(f);
+                self.headers.$visit(f);
+                self.sections.$visit(f);
+                self.body.$visit(f);
+            }
+        }
+
+        impl $visit_trait for Response {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                self.headers.$visit(f);
+                self.sections.$visit(f);
+                self.body.$visit(f);
+            }
+        }
+
+        impl $visit_trait for Body {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                self.value.$visit(f);
+            }
+        }
+
+        impl $visit_trait for Section {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                match & $($mut)? self.value {
+                    SectionValue::QueryParams(params) | SectionValue::FormParams(params) => {
+                        params.$visit(f)
+                    }
+                    SectionValue::BasicAuth(param) => param.$visit(f),
+                    SectionValue::MultipartFormData(params) => params.$visit(f),
+                    SectionValue::Cookies(cookies) => cookies.$visit(f),
+                    SectionValue::Captures(captures) => captures.$visit(f),
+                    SectionValue::Asserts(asserts) => asserts.$visit(f),
+                    SectionValue::Options(options) => options.$visit(f),
+                }
+            }
+        }
+
+        impl $visit_trait for Cookie {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                self.value.$visit(f);
+            }
+        }
+
+        impl $visit_trait for KeyValue {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                self.value.$visit(f);
+            }
+        }
+
+        impl $visit_trait for MultipartParam {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                if let MultipartParam::Param(param) = self {
+                    param.$visit(f);
+                }
+            }
+        }
+
+        impl $visit_trait for Capture {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                self.query.$visit(f);
+                self.filters.$visit(f);
+            }
+        }
+
+        impl $visit_trait for Assert {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                self.query.$visit(f);
+                self.filters.$visit(f);
+                self.predicate.predicate_func.value.$visit(f);
+            }
+        }
+
+        impl $visit_trait for Query {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                match & $($mut)? self.value {
+                    QueryValue::Header { name: template, .. }
+                    | QueryValue::Xpath { expr: template, .. }
+                    | QueryValue::Jsonpath { expr: template, .. }
+                    | QueryValue::Variable { name: template, .. } => template.$visit(f),
+                    QueryValue::Cookie { expr, .. } => expr.name.$visit(f),
+                    QueryValue::Regex { value, .. } => value.$visit(f),
+                    QueryValue::Status {}
+                    | QueryValue::Url {}
+                    | QueryValue::Charset {}
+                    | QueryValue::Body {}
+                    | QueryValue::Duration {}
+                    | QueryValue::Bytes {}
+                    | QueryValue::Sha256 {}
+                    | QueryValue::Md5 {}
+                    | QueryValue::Certificate { .. } => {}
+                }
+            }
+        }
+
+        impl $visit_trait for RegexValue {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                if let RegexValue::Template(template) = self {
+                    template.$visit(f);
+                }
+            }
+        }
+
+        impl $visit_trait for (Whitespace, Filter) {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                match & $($mut)? self.1.value {
+                    FilterValue::Format { fmt: template, .. }
+                    | FilterValue::JwtClaim { name: template, .. }
+                    | FilterValue::Split { sep: template, .. }
+                    | FilterValue::ToDate { fmt: template, .. } => template.$visit(f),
+                    FilterValue::Regex { value, .. } => value.$visit(f),
+                    FilterValue::Replace {
+                        old_value,
+                        new_value,
+                        ..
+                    } => {
+                        old_value.$visit(f);
+                        new_value.$visit(f);
+                    }
+                    FilterValue::Count
+                    | FilterValue::DaysAfterNow
+                    | FilterValue::DaysBeforeNow
+                    | FilterValue::HtmlEscape
+                    | FilterValue::HtmlUnescape
+                    | FilterValue::Nth { .. }
+                    | FilterValue::ToInt
+                    | FilterValue::UrlDecode
+                    | FilterValue::UrlEncode => {}
+                }
+            }
+        }
+
+        impl $visit_trait for PredicateFuncValue {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                match self {
+                    PredicateFuncValue::Equal { value, .. }
+                    | PredicateFuncValue::NotEqual { value, .. }
+                    | PredicateFuncValue::GreaterThan { value, .. }
+                    | PredicateFuncValue::GreaterThanOrEqual { value, .. }
+                    | PredicateFuncValue::LessThan { value, .. }
+                    | PredicateFuncValue::LessThanOrEqual { value, .. }
+                    | PredicateFuncValue::StartWith { value, .. }
+                    | PredicateFuncValue::EndWith { value, .. }
+                    | PredicateFuncValue::Contain { value, .. }
+                    | PredicateFuncValue::Include { value, .. }
+                    | PredicateFuncValue::Match { value, .. } => value.$visit(f),
+                    PredicateFuncValue::MatchSchema { value, .. } => match value {
+                        SchemaSource::Inline(value) => value.$visit(f),
+                        SchemaSource::File { filename, .. } => filename.$visit(f),
+                    },
+                    PredicateFuncValue::MatchTemplate { value, .. } => value.$visit(f),
+                    PredicateFuncValue::EqualIgnoreOrder { value, .. }
+                    | PredicateFuncValue::MatchAll { value, .. }
+                    | PredicateFuncValue::MatchAny { value, .. } => value.$visit(f),
+                    PredicateFuncValue::CountEqual { .. }
+                    | PredicateFuncValue::CountGreaterThan { .. }
+                    | PredicateFuncValue::CountGreaterThanOrEqual { .. }
+                    | PredicateFuncValue::CountLessThan { .. }
+                    | PredicateFuncValue::CountLessThanOrEqual { .. }
+                    | PredicateFuncValue::LengthEqual { .. }
+                    | PredicateFuncValue::IsCloseTo { .. }
+                    | PredicateFuncValue::IsInteger {}
+                    | PredicateFuncValue::IsFloat {}
+                    | PredicateFuncValue::IsNumber {}
+                    | PredicateFuncValue::IsBoolean {}
+                    | PredicateFuncValue::IsString {}
+                    | PredicateFuncValue::IsCollection {}
+                    | PredicateFuncValue::IsSorted {}
+                    | PredicateFuncValue::IsSortedDescending {}
+                    | PredicateFuncValue::Exist {}
+                    | PredicateFuncValue::IsEmpty {}
+                    | PredicateFuncValue::IsValidJson {} => {}
+                }
+            }
+        }
+
+        impl $visit_trait for PredicateValue {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                match self {
+                    PredicateValue::String(template) => template.$visit(f),
+                    PredicateValue::MultilineString(multiline) => multiline.$visit(f),
+                    PredicateValue::Expression(expr) => expr.$visit(f),
+                    PredicateValue::Integer(_)
+                    | PredicateValue::Float(_)
+                    | PredicateValue::Bool(_)
+                    | PredicateValue::Null {}
+                    | PredicateValue::Hex(_)
+                    | PredicateValue::Base64(_)
+                    | PredicateValue::Regex(_)
+                    | PredicateValue::Size(_) => {}
+                }
+            }
+        }
+
+        impl $visit_trait for MultilineString {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                match self {
+                    MultilineString::OneLineText(template) => template.$visit(f),
+                    MultilineString::Text(text)
+                    | MultilineString::Json(text)
+                    | MultilineString::Xml(text) => text.value.$visit(f),
+                    MultilineString::GraphQl(graphql) => {
+                        graphql.value.$visit(f);
+                        if let Some(variables) = & $($mut)? graphql.variables {
+                            variables.value.$visit(f);
+                        }
+                    }
+                }
+            }
+        }
+
+        impl $visit_trait for Bytes {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                match self {
+                    Bytes::Json(value) => value.$visit(f),
+                    Bytes::MultilineString(multiline) => multiline.$visit(f),
+                    Bytes::OnelineString(template) | Bytes::Template(template) => {
+                        template.$visit(f)
+                    }
+                    Bytes::Xml(_)
+                    | Bytes::Base64(_)
+                    | Bytes::File(_)
+                    | Bytes::Hex(_)
+                    | Bytes::FromResponse(_) => {}
+                }
+            }
+        }
+
+        impl $visit_trait for Template {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                for element in & $($mut)? self.elements {
+                    if let TemplateElement::Expression(expr) = element {
+                        expr.$visit(f);
+                    }
+                }
+            }
+        }
+
+        impl $visit_trait for Expr {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                f(& $($mut)? self.variable);
+            }
+        }
+
+        impl $visit_trait for EntryOption {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                if let EntryOption::Variable(option) = self {
+                    if let VariableValue::String(template) = & $($mut)? option.value.value {
+                        template.$visit(f);
+                    }
+                }
+            }
+        }
+
+        impl $visit_trait for json::Value {
+            fn $visit(& $($mut)? self, f: &mut dyn FnMut(& $($mut)? Variable)) {
+                match self {
+                    json::Value::Expression(expr) => expr.$visit(f),
+                    json::Value::String(template) => template.$visit(f),
+                    json::Value::List { elements, .. } => {
+                        for element in elements {
+                            element.value.$visit(f);
+                        }
+                    }
+                    json::Value::Object { elements, .. } => {
+                        for element in elements {
+                            element.name.$visit(f);
+                            element.value.$visit(f);
+                        }
+                    }
+                    json::Value::Number(_) | json::Value::Boolean(_) | json::Value::Null {} => {}
+                }
+            }
+        }
+    };
+}
+
+visit_variables!(VisitVariables, visit_variables, mut);
+visit_variables!(ForEachVariable, for_each_variable);
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse_hurl_file;
+
+    #[test]
+    fn test_rename_variable() {
+        let s = r#"GET {{base}}/api?id={{id}}
+x-base: {{base}}
+[QueryStringParams]
+base: {{base}}
+{
+  "url": "{{base}}",
+  "{{base}}": {{base}},
+  "list": [{{base}}, {{id}}]
+}
+
+HTTP 200
+[Captures]
+next: header "{{base}}" split "{{base}}"
+[Asserts]
+jsonpath "$.url" == "{{base}}/api"
+body contains "{{base}}"
+"#;
+        let expected = r#"GET {{host}}/api?id={{id}}
+x-base: {{host}}
+[QueryStringParams]
+base: {{host}}
+{
+  "url": "{{host}}",
+  "{{host}}": {{host}},
+  "list": [{{host}}, {{id}}]
+}
+
+HTTP 200
+[Captures]
+next: header "{{host}}" split "{{host}}"
+[Asserts]
+jsonpath "$.url" == "{{host}}/api"
+body contains "{{host}}"
+"#;
+        let mut hurl_file = parse_hurl_file(s).unwrap();
+        hurl_file.rename_variable("base", "host");
+        assert_eq!(
+            hurl_file.normalize(),
+            parse_hurl_file(expected).unwrap().normalize()
+        );
+    }
+
+    #[test]
+    fn test_unused_captures() {
+        let s = r#"POST {{host}}/login
+HTTP 200
+[Captures]
+token: header "x-token"
+session: cookie "session"
+
+GET {{host}}/users
+Authorization: Bearer {{token}}
+HTTP 200
+"#;
+        let hurl_file = parse_hurl_file(s).unwrap();
+        assert_eq!(hurl_file.unused_captures(), vec!["session"]);
+
+        let s = r#"GET {{host}}/a
+HTTP 200
+[Captures]
+id: jsonpath "$.id"
+count: jsonpath "$.count"
+
+GET {{host}}/b
+HTTP 200
+[Captures]
+id: jsonpath "$.id"
+other: jsonpath "$.other"
+[Asserts]
+jsonpath "$.count" == {{count}}
+
+GET {{host}}/c/{{id}}
+HTTP 200
+"#;
+        let hurl_file = parse_hurl_file(s).unwrap();
+        assert_eq!(hurl_file.unused_captures(), vec!["other"]);
+    }
+}
@@ -39,7 +39,9 @@ impl Error for parser::Error {
         match self.clone().inner {
             ParseError::Method { .. } => "Parsing method".to_string(),
             ParseError::Version { .. } => "Parsing version".to_string(),
+            ParseError::KeywordCase { .. } => "Parsing keyword".to_string(),
             ParseError::Status { .. } => "Parsing status code".to_string(),
+            ParseError::StatusRange { .. } => "Parsing status code".to_string(),
             ParseError::Filename { .. } => "Parsing filename".to_string(),
             ParseError::Expecting { .. } => "Parsing literal".to_string(),
             ParseError::Space { .. } => "Parsing space".to_string(),
@@ -49,10 +51,14 @@ impl Error for parser::Error {
             ParseError::XPathExpr { .. } => "Parsing XPath expression".to_string(),
             ParseError::TemplateVariable { .. } => "Parsing template variable".to_string(),
             ParseError::Json { .. } => "Parsing JSON".to_string(),
+            ParseError::JsonMaxDepth { .. } => "Parsing JSON".to_string(),
+            ParseError::JsonComment => "Parsing JSON".to_string(),
             ParseError::Predicate { .. } => "Parsing predicate".to_string(),
             ParseError::PredicateValue { .. } => "Parsing predicate value".to_string(),
             ParseError::RegexExpr { .. } => "Parsing regex".to_string(),
             ParseError::DuplicateSection { .. } => "Parsing section".to_string(),
+            ParseError::MisplacedSettings => "Parsing section".to_string(),
+            ParseError::MultipleBodies => "Parsing body".to_string(),
             ParseError::RequestSection { .. } => "Parsing section".to_string(),
             ParseError::ResponseSection { .. } => "Parsing section".to_string(),
             ParseError::EscapeChar { .. } => "Parsing escape character".to_string(),
@@ -60,21 +66,28 @@ impl Error for parser::Error {
             ParseError::OddNumberOfHexDigits { .. } => "Parsing hex bytearray".to_string(),
             ParseError::UrlIllegalCharacter(_) => "Parsing URL".to_string(),
             ParseError::Multiline => "Parsing multiline".to_string(),
+            ParseError::Charset { .. } => "Parsing charset".to_string(),
+            ParseError::ContentType { .. } => "Parsing content type".to_string(),
+            ParseError::FromResponse { .. } => "Parsing response reference".to_string(),
+            ParseError::SizeUnit { .. } => "Parsing size".to_string(),
+            ParseError::Proxy { .. } => "Parsing proxy".to_string(),
             ParseError::GraphQlVariables => "Parsing GraphQL variables".to_string(),
+            ParseError::DuplicateHeader { .. } => "Parsing header".to_string(),
+            ParseError::DurationUnit { .. } => "Parsing duration".to_string(),
             _ => format!("{self:?}"),
         }
     }
 
     fn fixme(&self) -> String {
         match self.inner.clone() {
-            ParseError::Method { name }
-            => format!("the HTTP method is not valid. {}", did_you_mean(
-                &["GET", "HEAD", "POST", "PUT", "DELETE", "CONNECT", "OPTIONS", "TRACE", "PATCH", "LINK", "UNLINK", "PURGE", "LOCK", "UNLOCK", "PROPFIND", "VIEW"],
-                name.as_str(),
-                "Valid values are GET, HEAD, POST, PUT, DELETE, CONNECT, OPTIONS, TRACE, PATCH, LINK, UNLINK, PURGE, LOCK, UNLOCK, PROPFIND or VIEW",
-            )),
+            ParseError::Method { suggestion: Some(suggestion), .. }
+            => format!("the HTTP method is not valid. Did you mean {suggestion}?"),
+            ParseError::Method { suggestion: None, .. }
+            => "the HTTP method is not valid. Valid values are GET, HEAD, POST, PUT, DELETE, CONNECT, OPTIONS, TRACE, PATCH, LINK, UNLINK, PURGE, LOCK, UNLOCK, PROPFIND, VIEW or any other uppercase name".to_string(),
             ParseError::Version { .. } => "HTTP version must be HTTP, HTTP/1.0, HTTP/1.1 or HTTP/2".to_string(),
+            ParseError::KeywordCase { value } => format!("the keyword should be written <{value}>"),
             ParseError::Status { .. } => "HTTP status code is not valid".to_string(),
+            ParseError::StatusRange { start, end } => format!("the status range <{start}-{end}> is not valid, the first code must not be greater than the last one"),
             ParseError::Filename { .. } => "expecting a filename".to_string(),
             ParseError::Expecting { value } => format!("expecting '{value}'"),
             ParseError::Space { .. } => "expecting a space".to_string(),
@@ -94,15 +107,19 @@ impl Error for parser::Error {
             ParseError::XPathExpr { .. } => "expecting a XPath expression".to_string(),
             ParseError::TemplateVariable { .. } => "expecting a variable".to_string(),
             ParseError::Json { .. } => "JSON error".to_string(),
+            ParseError::JsonMaxDepth { max } => format!("the JSON value exceeds the maximum nesting depth of {max}"),
+            ParseError::JsonComment => "a JSON body can not start with a comment".to_string(),
             ParseError::Predicate { .. } => "expecting a predicate".to_string(),
             ParseError::PredicateValue { .. } => "invalid predicate value".to_string(),
             ParseError::RegexExpr { message } => format!("invalid Regex expression: {message}"),
             ParseError::DuplicateSection { .. } => "the section is already defined".to_string(),
+            ParseError::MisplacedSettings => "the [Settings] section must be placed before the first entry".to_string(),
+            ParseError::MultipleBodies => "only one body is allowed, the body is already defined".to_string(),
             ParseError::RequestSection { .. } => {
-                "this is not a valid section for a request".to_string()
+                "this is a response section, not a request one. Valid values are QueryStringParams, FormParams, MultipartFormData, Cookies or Options".to_string()
             }
             ParseError::ResponseSection { .. } => {
-                "this is not a valid section for a response".to_string()
+                "this is a request section, not a response one. Valid values are Captures or Asserts".to_string()
             }
             ParseError::EscapeChar { .. } => "the escaping sequence is not valid".to_string(),
             ParseError::InvalidCookieAttribute { .. } => {
@@ -113,7 +130,14 @@ impl Error for parser::Error {
             }
             ParseError::UrlIllegalCharacter(c) => format!("illegal character <{c}>"),
             ParseError::Multiline => "the multiline is not valid".to_string(),
+            ParseError::Proxy { value } => format!("proxy <{value}> is not valid, use [protocol://]host[:port]"),
+            ParseError::Charset { name } => format!("charset <{name}> is not supported, valid values are utf-8, utf-16, utf-16be and utf-16le"),
+            ParseError::ContentType { value } => format!("content type <{value}> is not valid, use type/subtype"),
+            ParseError::FromResponse { index } => format!("response <{index}> does not refer to a previous entry"),
+            ParseError::SizeUnit { name } => format!("size unit <{name}> is not valid, valid values are KB and MB"),
             ParseError::GraphQlVariables => "GraphQL variables is not a valid JSON object".to_string(),
+            ParseError::DuplicateHeader { name } => format!("the header <{name}> is already defined"),
+            ParseError::DurationUnit { name } => format!("duration unit <{name}> is not valid, valid values are ms, s and m"),
             _ => format!("{self:?}"),
 
         }
@@ -128,7 +152,8 @@ fn did_you_mean(valid_values: &[&str], actual: &str, default: &str) -> String {
     }
 }
 
-fn suggestion(valid_values: &[&str], actual: &str) -> Option<String> {
+/// Returns the first of `valid_values` close to `actual` (ignoring case), if any.
+pub(crate) fn suggestion(valid_values: &[&str], actual: &str) -> Option<String> {
     for value in valid_values {
         if levenshtein_distance(
             value.to_lowercase().as_str(),
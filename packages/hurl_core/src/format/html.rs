@@ -67,6 +67,9 @@ impl HtmlFormatter {
     pub fn fmt_hurl_file(&mut self, hurl_file: &HurlFile) -> &str {
         self.buffer.clear();
         self.fmt_pre_open("language-hurl");
+        if let Some(settings) = &hurl_file.settings {
+            self.fmt_settings(settings);
+        }
         hurl_file.entries.iter().for_each(|e| self.fmt_entry(e));
         self.fmt_lts(&hurl_file.line_terminators);
         self.fmt_pre_close();
@@ -170,6 +173,19 @@ impl HtmlFormatter {
         self.fmt_section_value(&section.value);
     }
 
+    fn fmt_settings(&mut self, settings: &Settings) {
+        self.fmt_lts(&settings.line_terminators);
+        self.fmt_space(&settings.space0);
+        self.fmt_span_open("line");
+        self.fmt_span("section-header", "[Settings]");
+        self.fmt_span_close();
+        self.fmt_lt(&settings.line_terminator0);
+        settings
+            .options
+            .iter()
+            .for_each(|option| self.fmt_entry_option(option));
+    }
+
     fn fmt_section_value(&mut self, section_value: &SectionValue) {
         match section_value {
             SectionValue::Asserts(items) => items.iter().for_each(|item| self.fmt_assert(item)),
@@ -206,11 +222,17 @@ impl HtmlFormatter {
             EntryOption::ClientCert(option) => self.fmt_client_cert_option(option),
             EntryOption::ClientKey(option) => self.fmt_client_key_option(option),
             EntryOption::Compressed(option) => self.fmt_compressed_option(option),
+            EntryOption::ConnectTimeout(option) => self.fmt_connect_timeout_option(option),
+            EntryOption::Curl(option) => self.fmt_curl_option(option),
             EntryOption::Insecure(option) => self.fmt_insecure_option(option),
             EntryOption::FollowLocation(option) => self.fmt_follow_location_option(option),
             EntryOption::MaxRedirect(option) => self.fmt_max_redirect_option(option),
+            EntryOption::NoProxy(option) => self.fmt_no_proxy_option(option),
+            EntryOption::Proxy(option) => self.fmt_proxy_option(option),
+            EntryOption::Repeat(option) => self.fmt_repeat_option(option),
             EntryOption::Retry(option) => self.fmt_retry_option(option),
             EntryOption::RetryInterval(option) => self.fmt_retry_interval_option(option),
+            EntryOption::Timeout(option) => self.fmt_timeout_option(option),
             EntryOption::Variable(option) => self.fmt_variable_option(option),
             EntryOption::Verbose(option) => self.fmt_verbose_option(option),
             EntryOption::VeryVerbose(option) => self.fmt_very_verbose_option(option),
@@ -308,6 +330,58 @@ impl HtmlFormatter {
         self.fmt_lt(&option.line_terminator0);
     }
 
+    fn fmt_curl_option(&mut self, option: &CurlOption) {
+        self.fmt_lts(&option.line_terminators);
+        self.fmt_span_open("line");
+        self.fmt_space(&option.space0);
+        self.fmt_string("curl");
+        self.fmt_space(&option.space1);
+        self.buffer.push(':');
+        self.fmt_space(&option.space2);
+        self.fmt_string(&option.value);
+        self.fmt_span_close();
+        self.fmt_lt(&option.line_terminator0);
+    }
+
+    fn fmt_no_proxy_option(&mut self, option: &NoProxyOption) {
+        self.fmt_lts(&option.line_terminators);
+        self.fmt_span_open("line");
+        self.fmt_space(&option.space0);
+        self.fmt_string("no-proxy");
+        self.fmt_space(&option.space1);
+        self.buffer.push(':');
+        self.fmt_space(&option.space2);
+        self.fmt_string(&option.value.join(","));
+        self.fmt_span_close();
+        self.fmt_lt(&option.line_terminator0);
+    }
+
+    fn fmt_proxy_option(&mut self, option: &ProxyOption) {
+        self.fmt_lts(&option.line_terminators);
+        self.fmt_span_open("line");
+        self.fmt_space(&option.space0);
+        self.fmt_string("proxy");
+        self.fmt_space(&option.space1);
+        self.buffer.push(':');
+        self.fmt_space(&option.space2);
+        self.fmt_string(&option.value.to_string());
+        self.fmt_span_close();
+        self.fmt_lt(&option.line_terminator0);
+    }
+
+    fn fmt_repeat_option(&mut self, option: &RepeatOption) {
+        self.fmt_lts(&option.line_terminators);
+        self.fmt_span_open("line");
+        self.fmt_space(&option.space0);
+        self.fmt_string("repeat");
+        self.fmt_space(&option.space1);
+        self.buffer.push(':');
+        self.fmt_space(&option.space2);
+        self.fmt_number(option.value);
+        self.fmt_span_close();
+        self.fmt_lt(&option.line_terminator0);
+    }
+
     fn fmt_retry_option(&mut self, option: &RetryOption) {
         self.fmt_lts(&option.line_terminators);
         self.fmt_span_open("line");
@@ -334,6 +408,32 @@ impl HtmlFormatter {
         self.fmt_lt(&option.line_terminator0);
     }
 
+    fn fmt_timeout_option(&mut self, option: &TimeoutOption) {
+        self.fmt_lts(&option.line_terminators);
+        self.fmt_span_open("line");
+        self.fmt_space(&option.space0);
+        self.fmt_string("timeout");
+        self.fmt_space(&option.space1);
+        self.buffer.push(':');
+        self.fmt_space(&option.space2);
+        self.fmt_number(&option.value);
+        self.fmt_span_close();
+        self.fmt_lt(&option.line_terminator0);
+    }
+
+    fn fmt_connect_timeout_option(&mut self, option: &ConnectTimeoutOption) {
+        self.fmt_lts(&option.line_terminators);
+        self.fmt_span_open("line");
+        self.fmt_space(&option.space0);
+        self.fmt_string("connect-timeout");
+        self.fmt_space(&option.space1);
+        self.buffer.push(':');
+        self.fmt_space(&option.space2);
+        self.fmt_number(&option.value);
+        self.fmt_span_close();
+        self.fmt_lt(&option.line_terminator0);
+    }
+
     fn fmt_variable_option(&mut self, option: &VariableOption) {
         self.fmt_lts(&option.line_terminators);
         self.fmt_span_open("line");
@@ -467,6 +567,7 @@ impl HtmlFormatter {
         match query_value {
             QueryValue::Status {} => self.fmt_span("query-type", "status"),
             QueryValue::Url {} => self.fmt_span("query-type", "url"),
+            QueryValue::Charset {} => self.fmt_span("query-type", "charset"),
             QueryValue::Header { space0, name } => {
                 self.fmt_span("query-type", "header");
                 self.fmt_space(space0);
@@ -592,6 +693,26 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_predicate_value(value);
             }
+            PredicateFuncValue::CountGreaterThan { space0, value, .. } => {
+                self.fmt_space(space0);
+                self.fmt_predicate_value(value);
+            }
+            PredicateFuncValue::CountGreaterThanOrEqual { space0, value, .. } => {
+                self.fmt_space(space0);
+                self.fmt_predicate_value(value);
+            }
+            PredicateFuncValue::CountLessThan { space0, value, .. } => {
+                self.fmt_space(space0);
+                self.fmt_predicate_value(value);
+            }
+            PredicateFuncValue::CountLessThanOrEqual { space0, value, .. } => {
+                self.fmt_space(space0);
+                self.fmt_predicate_value(value);
+            }
+            PredicateFuncValue::LengthEqual { space0, value } => {
+                self.fmt_space(space0);
+                self.fmt_predicate_value(value);
+            }
             PredicateFuncValue::Equal { space0, value, .. } => {
                 self.fmt_space(space0);
                 self.fmt_predicate_value(value);
@@ -636,13 +757,52 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_predicate_value(value);
             }
+            PredicateFuncValue::MatchSchema { space0, value } => {
+                self.fmt_space(space0);
+                match value {
+                    SchemaSource::Inline(value) => self.fmt_json_value(value),
+                    SchemaSource::File { space0, filename } => {
+                        self.fmt_span("predicate-type", "file");
+                        self.fmt_space(space0);
+                        self.fmt_template(filename);
+                    }
+                }
+            }
+            PredicateFuncValue::MatchTemplate { space0, value } => {
+                self.fmt_space(space0);
+                self.fmt_template(value);
+            }
+            PredicateFuncValue::EqualIgnoreOrder { space0, value }
+            | PredicateFuncValue::MatchAll { space0, value }
+            | PredicateFuncValue::MatchAny { space0, value } => {
+                self.fmt_space(space0);
+                self.fmt_json_value(value);
+            }
+            PredicateFuncValue::IsCloseTo {
+                space0,
+                value,
+                space1,
+                space2,
+                epsilon,
+            } => {
+                self.fmt_space(space0);
+                self.fmt_predicate_value(value);
+                self.fmt_space(space1);
+                self.fmt_span("predicate-type", "within");
+                self.fmt_space(space2);
+                self.fmt_predicate_value(epsilon);
+            }
             PredicateFuncValue::IsInteger {} => {}
             PredicateFuncValue::IsFloat {} => {}
+            PredicateFuncValue::IsNumber {} => {}
             PredicateFuncValue::IsBoolean {} => {}
             PredicateFuncValue::IsString {} => {}
             PredicateFuncValue::IsCollection {} => {}
+            PredicateFuncValue::IsSorted {} => {}
+            PredicateFuncValue::IsSortedDescending {} => {}
             PredicateFuncValue::Exist {} => {}
             PredicateFuncValue::IsEmpty {} => {}
+            PredicateFuncValue::IsValidJson {} => {}
         }
     }
 
@@ -658,6 +818,7 @@ impl HtmlFormatter {
             PredicateValue::Expression(value) => self.fmt_expr(value),
             PredicateValue::Null {} => self.fmt_span("null", "null"),
             PredicateValue::Regex(value) => self.fmt_regex(value),
+            PredicateValue::Size(value) => self.fmt_number(value),
         };
     }
 
@@ -729,6 +890,14 @@ impl HtmlFormatter {
         // </span>
         // ```
         let lang = multiline_string.lang();
+        let lang = match multiline_string.charset() {
+            Some(charset) => format!("{lang}{charset}"),
+            None => lang.to_string(),
+        };
+        let lang = match multiline_string.content_type() {
+            Some(content_type) => format!("{lang}{content_type}"),
+            None => lang,
+        };
         if as_body {
             let body = format!("```{lang}\n{multiline_string}```");
             let body = format_multilines(&body);
@@ -772,7 +941,14 @@ impl HtmlFormatter {
                 self.fmt_hex(value);
                 self.fmt_span_close();
             }
-            Bytes::OnelineString(value) => {
+            Bytes::FromResponse(index) => {
+                self.fmt_span_open("line");
+                self.buffer.push_str("from-response ");
+                self.fmt_number(index);
+                self.buffer.push(';');
+                self.fmt_span_close();
+            }
+            Bytes::OnelineString(value) | Bytes::Template(value) => {
                 self.fmt_span_open("line");
                 self.fmt_template(value);
                 self.fmt_span_close();
@@ -881,6 +1057,11 @@ impl HtmlFormatter {
             }
             FilterValue::HtmlEscape => self.fmt_span("filter-type", "htmlEscape"),
             FilterValue::HtmlUnescape => self.fmt_span("filter-type", "htmlUnescape"),
+            FilterValue::JwtClaim { space0, name } => {
+                self.fmt_span("filter-type", "jwtClaim");
+                self.fmt_space(space0);
+                self.fmt_template(name);
+            }
             FilterValue::Nth { space0, n: value } => {
                 self.fmt_span("filter-type", "nth");
                 self.fmt_space(space0);
@@ -1037,6 +1218,8 @@ mod tests {
         // line2
         // ```
         let multiline_string = MultilineString::Text(Text {
+            charset: None,
+            content_type: None,
             space: Whitespace {
                 value: "".to_string(),
                 source_info: SourceInfo {
@@ -1116,6 +1299,7 @@ mod tests {
             space0: "".to_string(),
             elements: vec![JsonObjectElement {
                 space0: "\n   ".to_string(),
+                comments: vec![],
                 name: Template {
                     delimiter: Some('"'),
                     elements: vec![TemplateElement::String {
@@ -1129,6 +1313,7 @@ mod tests {
                 value: JsonValue::Number("1".to_string()),
                 space3: "\n".to_string(),
             }],
+            comments: vec![],
         };
         fmt.fmt_json_value(&value);
         assert_eq!(
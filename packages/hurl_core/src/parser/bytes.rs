@@ -17,22 +17,25 @@
  */
 use crate::ast::*;
 use crate::parser::combinators::*;
-use crate::parser::json::parse as parse_json;
+use crate::parser::error::*;
+use crate::parser::json::{comments as json_comments, parse as parse_json};
 use crate::parser::multiline::multiline_string;
 use crate::parser::primitives::*;
-use crate::parser::reader::Reader;
+use crate::parser::reader::{ParseMode, Reader};
 use crate::parser::string::backtick_template;
-use crate::parser::{xml, ParseResult};
+use crate::parser::{expr, xml, ParseResult};
 
 pub fn bytes(reader: &mut Reader) -> ParseResult<'static, Bytes> {
     choice(
         &[
             multiline_string_bytes,
             string_bytes,
+            template_bytes,
             json_bytes,
             xml_bytes,
             base64_bytes,
             hex_bytes,
+            from_response_bytes,
             file_bytes,
         ],
         reader,
@@ -47,16 +50,52 @@ fn xml_bytes(reader: &mut Reader) -> ParseResult<'static, Bytes> {
 }
 
 fn json_bytes(reader: &mut Reader) -> ParseResult<'static, Bytes> {
-    match parse_json(reader) {
-        Err(e) => Err(e),
-        Ok(value) => Ok(Bytes::Json(value)),
+    // A JSON body can not start with a comment: it is rejected in strict mode, skipped with
+    // a warning in lenient mode.
+    let start = reader.state.clone();
+    let leading_comment = reader.peek_n(2) == "//";
+    if leading_comment {
+        if reader.mode != ParseMode::Lenient {
+            return Err(Error {
+                pos: start.pos,
+                recoverable: false,
+                inner: ParseError::JsonComment,
+            });
+        }
+        json_comments(reader);
+    }
+    let value = parse_json(reader)?;
+    if leading_comment {
+        reader.warnings.push(Error {
+            pos: start.pos,
+            recoverable: true,
+            inner: ParseError::JsonComment,
+        });
     }
+    Ok(Bytes::Json(value))
 }
 
 fn file_bytes(reader: &mut Reader) -> ParseResult<'static, Bytes> {
     file(reader).map(Bytes::File)
 }
 
+/// Parses a reference to the response body of a previous entry, like `from-response 1;`.
+fn from_response_bytes(reader: &mut Reader) -> ParseResult<'static, Bytes> {
+    try_literal("from-response", reader)?;
+    one_or_more_spaces(reader)?;
+    let pos = reader.state.pos.clone();
+    let index = nonrecover(integer, reader)?;
+    if index <= 0 {
+        return Err(Error {
+            pos,
+            recoverable: false,
+            inner: ParseError::FromResponse { index },
+        });
+    }
+    literal(";", reader)?;
+    Ok(Bytes::FromResponse(index as usize))
+}
+
 fn base64_bytes(reader: &mut Reader) -> ParseResult<'static, Bytes> {
     base64(reader).map(Bytes::Base64)
 }
@@ -73,9 +112,21 @@ fn string_bytes(reader: &mut Reader) -> ParseResult<'static, Bytes> {
     backtick_template(reader).map(Bytes::OnelineString)
 }
 
+fn template_bytes(reader: &mut Reader) -> ParseResult<'static, Bytes> {
+    let start = reader.state.clone();
+    let expr = expr::parse(reader)?;
+    Ok(Bytes::Template(Template {
+        delimiter: None,
+        elements: vec![TemplateElement::Expression(expr)],
+        source_info: SourceInfo {
+            start: start.pos,
+            end: reader.state.pos.clone(),
+        },
+    }))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::super::error::*;
     use super::*;
 
     #[test]
@@ -88,20 +139,24 @@ mod tests {
                 elements: vec![
                     JsonListElement {
                         space0: "".to_string(),
+                        comments: vec![],
                         value: JsonValue::Number("1".to_string()),
                         space1: "".to_string(),
                     },
                     JsonListElement {
                         space0: "".to_string(),
+                        comments: vec![],
                         value: JsonValue::Number("2".to_string()),
                         space1: "".to_string(),
                     },
                     JsonListElement {
                         space0: "".to_string(),
+                        comments: vec![],
                         value: JsonValue::Number("3".to_string()),
                         space1: "".to_string(),
                     },
                 ],
+                comments: vec![],
             })
         );
         assert_eq!(reader.state.cursor, 7);
@@ -112,6 +167,7 @@ mod tests {
             Bytes::Json(JsonValue::Object {
                 space0: " ".to_string(),
                 elements: vec![],
+                comments: vec![],
             })
         );
         assert_eq!(reader.state.cursor, 3);
@@ -178,6 +234,27 @@ mod tests {
         assert!(error.recoverable);
     }
 
+    #[test]
+    fn test_bytes_from_response() {
+        let mut reader = Reader::new("from-response 1;");
+        assert_eq!(bytes(&mut reader).unwrap(), Bytes::FromResponse(1));
+        assert_eq!(reader.state.cursor, 16);
+
+        for (s, index) in [("from-response 0;", 0), ("from-response -2;", -2)] {
+            let mut reader = Reader::new(s);
+            let error = bytes(&mut reader).err().unwrap();
+            assert_eq!(
+                error.pos,
+                Pos {
+                    line: 1,
+                    column: 15
+                }
+            );
+            assert!(!error.recoverable);
+            assert_eq!(error.inner, ParseError::FromResponse { index });
+        }
+    }
+
     #[test]
     fn test_json_bytes() {
         let mut reader = Reader::new("100");
@@ -187,6 +264,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_json_bytes_leading_comment() {
+        let mut reader = Reader::new("// comment\n{}");
+        let error = json_bytes(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 1 });
+        assert_eq!(error.inner, ParseError::JsonComment);
+        assert!(!error.recoverable);
+
+        let mut reader = Reader::with_mode("// comment\n// other\n{}", ParseMode::Lenient);
+        assert_eq!(
+            json_bytes(&mut reader).unwrap(),
+            Bytes::Json(JsonValue::Object {
+                space0: "".to_string(),
+                elements: vec![],
+                comments: vec![],
+            })
+        );
+        assert_eq!(reader.state.cursor, 22);
+        assert_eq!(
+            reader.warnings,
+            vec![Error {
+                pos: Pos { line: 1, column: 1 },
+                recoverable: true,
+                inner: ParseError::JsonComment,
+            }]
+        );
+    }
+
     #[test]
     fn test_bytes_string() {
         let mut reader = Reader::new("`foo`  ");
@@ -203,4 +308,54 @@ mod tests {
         );
         assert_eq!(reader.state.cursor, 5);
     }
+
+    #[test]
+    fn test_bytes_template() {
+        let mut reader = Reader::new("{{payload}}\n");
+        assert_eq!(
+            bytes(&mut reader).unwrap(),
+            Bytes::Template(Template {
+                delimiter: None,
+                elements: vec![TemplateElement::Expression(Expr {
+                    space0: Whitespace {
+                        value: String::new(),
+                        source_info: SourceInfo::new(1, 3, 1, 3),
+                    },
+                    variable: Variable {
+                        name: "payload".to_string(),
+                        source_info: SourceInfo::new(1, 3, 1, 10),
+                    },
+                    space1: Whitespace {
+                        value: String::new(),
+                        source_info: SourceInfo::new(1, 10, 1, 10),
+                    },
+                })],
+                source_info: SourceInfo::new(1, 1, 1, 12)
+            })
+        );
+        assert_eq!(reader.state.cursor, 11);
+    }
+
+    #[test]
+    fn test_bytes_graphql() {
+        let mut reader = Reader::new(
+            "```graphql\n{\n  human(id: \"{{id}}\") {\n    name\n  }\n}\nvariables {\n  \"episode\": \"JEDI\"\n}\n```",
+        );
+        let Bytes::MultilineString(MultilineString::GraphQl(graphql)) = bytes(&mut reader).unwrap()
+        else {
+            panic!("GraphQL body expected");
+        };
+        assert_eq!(
+            graphql.value.to_string(),
+            "{\n  human(id: \"{{id}}\") {\n    name\n  }\n}\n"
+        );
+        assert_eq!(graphql.value.elements.len(), 3);
+        assert!(matches!(
+            &graphql.value.elements[1],
+            TemplateElement::Expression(expr) if expr.variable.name == "id"
+        ));
+        assert_eq!(graphql.value.source_info, SourceInfo::new(2, 1, 7, 1));
+        assert!(graphql.variables.is_some());
+        assert!(reader.is_eof());
+    }
 }
@@ -19,13 +19,18 @@ use crate::parser::error::*;
 use crate::parser::reader::Reader;
 use crate::parser::{ParseFunc, ParseResult};
 
+/// Applies the parser function `f`, returning `None` if it fails with a recoverable error.
+///
+/// On failure, the reader is restored, including the warnings pushed by `f`.
 pub fn optional<'a, T>(f: ParseFunc<'a, T>, reader: &mut Reader) -> ParseResult<'a, Option<T>> {
     let start = reader.state.clone();
+    let warnings_len = reader.warnings.len();
     match f(reader) {
         Ok(r) => Ok(Some(r)),
         Err(e) => {
             if e.recoverable {
                 reader.state = start;
+                reader.warnings.truncate(warnings_len);
                 Ok(None)
             } else {
                 Err(e)
@@ -64,6 +69,7 @@ pub fn zero_or_more<'a, T>(f: ParseFunc<'a, T>, reader: &mut Reader) -> ParseRes
     let mut v: Vec<T> = Vec::new();
     loop {
         let initial_state = reader.state.clone();
+        let warnings_len = reader.warnings.len();
         if reader.is_eof() {
             return Ok(v);
         }
@@ -76,6 +82,7 @@ pub fn zero_or_more<'a, T>(f: ParseFunc<'a, T>, reader: &mut Reader) -> ParseRes
                 return if e.recoverable {
                     reader.state.pos = initial_state.pos;
                     reader.state.cursor = initial_state.cursor;
+                    reader.warnings.truncate(warnings_len);
                     Ok(v)
                 } else {
                     Err(e)
@@ -92,6 +99,7 @@ pub fn one_or_more<'a, T>(f: ParseFunc<'a, T>, reader: &mut Reader) -> ParseResu
             let mut v = vec![first];
             loop {
                 let initial_state = reader.state.clone();
+                let warnings_len = reader.warnings.len();
                 match f(reader) {
                     Ok(r) => {
                         v.push(r);
@@ -100,6 +108,7 @@ pub fn one_or_more<'a, T>(f: ParseFunc<'a, T>, reader: &mut Reader) -> ParseResu
                         return if e.recoverable {
                             reader.state.pos = initial_state.pos;
                             reader.state.cursor = initial_state.cursor;
+                            reader.warnings.truncate(warnings_len);
                             Ok(v)
                         } else {
                             Err(e)
@@ -120,10 +129,11 @@ pub fn one_or_more<'a, T>(f: ParseFunc<'a, T>, reader: &mut Reader) -> ParseResu
 }
 
 /// Tries to apply the list of parser functions `fs` until one of them succeeds.
-/// Typically this should be recoverable
+/// Typically this should be recoverable. The warnings pushed by a failed function are discarded.
 pub fn choice<'a, T>(fs: &[ParseFunc<'a, T>], reader: &mut Reader) -> ParseResult<'a, T> {
     for (pos, f) in fs.iter().enumerate() {
         let start = reader.state.clone();
+        let warnings_len = reader.warnings.len();
         if pos == fs.len() - 1 {
             return f(reader);
         }
@@ -132,6 +142,7 @@ pub fn choice<'a, T>(fs: &[ParseFunc<'a, T>], reader: &mut Reader) -> ParseResul
                 recoverable: true, ..
             }) => {
                 reader.state = start;
+                reader.warnings.truncate(warnings_len);
                 continue;
             }
             x => return x,
@@ -139,3 +150,48 @@ pub fn choice<'a, T>(fs: &[ParseFunc<'a, T>], reader: &mut Reader) -> ParseResul
     }
     panic!("You can't call choice with an empty vector of choice")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ParseMode;
+
+    /// Reads a lowercase `http` keyword with a warning, then fails as if no status followed.
+    fn lowercase_http_without_status(reader: &mut Reader) -> ParseResult<'static, ()> {
+        let start = reader.state.clone();
+        reader.read_n(4);
+        reader.warnings.push(Error {
+            pos: start.pos.clone(),
+            recoverable: true,
+            inner: ParseError::KeywordCase {
+                value: "HTTP".to_string(),
+            },
+        });
+        Err(Error {
+            pos: start.pos,
+            recoverable: true,
+            inner: ParseError::Status {},
+        })
+    }
+
+    #[test]
+    fn test_optional_discards_warnings() {
+        let mut reader = Reader::with_mode("http", ParseMode::Lenient);
+        assert_eq!(
+            optional(lowercase_http_without_status, &mut reader).unwrap(),
+            None
+        );
+        assert_eq!(reader.state.cursor, 0);
+        assert!(reader.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_choice_discards_warnings() {
+        fn ok(_: &mut Reader) -> ParseResult<'static, ()> {
+            Ok(())
+        }
+        let mut reader = Reader::with_mode("http", ParseMode::Lenient);
+        choice(&[lowercase_http_without_status, ok], &mut reader).unwrap();
+        assert!(reader.warnings.is_empty());
+    }
+}
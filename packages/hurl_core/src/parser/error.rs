@@ -26,30 +26,57 @@ pub struct Error {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ParseError {
-    Expecting { value: String },
+    Expecting {
+        value: String,
+    },
 
-    Method { name: String },
+    /// An invalid method `name`, with the closest valid method as `suggestion` (if any).
+    Method {
+        name: String,
+        suggestion: Option<String>,
+    },
     Version {},
+    KeywordCase {
+        value: String,
+    },
     Status {},
+    StatusRange {
+        start: u64,
+        end: u64,
+    },
     Filename {},
     FileContentType {},
     Space {},
-    RequestSectionName { name: String },
-    ResponseSectionName { name: String },
+    RequestSectionName {
+        name: String,
+    },
+    ResponseSectionName {
+        name: String,
+    },
     JsonpathExpr {},
     XPathExpr {},
     TemplateVariable {},
     Json {},
+    JsonMaxDepth {
+        max: usize,
+    },
+    JsonComment,
     Xml {},
     Predicate,
     PredicateValue,
-    RegexExpr { message: String },
+    RegexExpr {
+        message: String,
+    },
 
-    Unexpected { character: String },
+    Unexpected {
+        character: String,
+    },
     Eof {},
     Url {},
 
     DuplicateSection,
+    MisplacedSettings,
+    MultipleBodies,
     RequestSection,
     ResponseSection,
 
@@ -61,8 +88,29 @@ pub enum ParseError {
     OddNumberOfHexDigits,
     UrlIllegalCharacter(char),
     InvalidOption,
+    Proxy {
+        value: String,
+    },
     Multiline,
+    Charset {
+        name: String,
+    },
+    ContentType {
+        value: String,
+    },
+    FromResponse {
+        index: i64,
+    },
+    SizeUnit {
+        name: String,
+    },
     GraphQlVariables,
+    DuplicateHeader {
+        name: String,
+    },
+    DurationUnit {
+        name: String,
+    },
 }
 
 impl Error {
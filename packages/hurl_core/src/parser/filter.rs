@@ -57,6 +57,7 @@ pub fn filter(reader: &mut Reader) -> ParseResult<'static, Filter> {
             format_filter,
             html_decode_filter,
             html_encode_filter,
+            jwt_claim_filter,
             nth_filter,
             regex_filter,
             replace_filter,
@@ -118,6 +119,13 @@ fn html_decode_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue>
     Ok(FilterValue::HtmlUnescape)
 }
 
+fn jwt_claim_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("jwtClaim", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let name = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    Ok(FilterValue::JwtClaim { space0, name })
+}
+
 fn nth_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
     try_literal("nth", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -178,7 +186,7 @@ fn url_decode_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ast::Pos;
+    use crate::ast::{Pos, Template, TemplateElement};
     use crate::parser::ParseError;
 
     #[test]
@@ -193,6 +201,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_jwt_claim() {
+        let mut reader = Reader::new("jwtClaim \"sub\"");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(1, 1, 1, 15),
+                value: FilterValue::JwtClaim {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(1, 9, 1, 10),
+                    },
+                    name: Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: "sub".to_string(),
+                            encoded: "sub".to_string(),
+                        }],
+                        source_info: SourceInfo::new(1, 10, 1, 15),
+                    },
+                },
+            }
+        );
+
+        let mut reader = Reader::new("jwtClaim sub");
+        let err = filter(&mut reader).err().unwrap();
+        assert_eq!(
+            err.pos,
+            Pos {
+                line: 1,
+                column: 10
+            }
+        );
+        assert!(!err.recoverable);
+    }
+
     #[test]
     fn test_error() {
         let mut reader = Reader::new("xcount");
@@ -15,7 +15,9 @@
  * limitations under the License.
  *
  */
-use crate::ast::{JsonListElement, JsonObjectElement, JsonValue, Pos, SourceInfo, Template};
+use crate::ast::{
+    JsonComment, JsonListElement, JsonObjectElement, JsonValue, Pos, SourceInfo, Template,
+};
 use crate::parser::combinators::*;
 use crate::parser::primitives::*;
 use crate::parser::reader::*;
@@ -234,38 +236,49 @@ fn expression_value(reader: &mut Reader) -> ParseResult<'static, JsonValue> {
     Ok(JsonValue::Expression(exp))
 }
 
-fn list_value(reader: &mut Reader) -> ParseResult<'static, JsonValue> {
+pub fn list_value(reader: &mut Reader) -> ParseResult<'static, JsonValue> {
+    let start = reader.state.pos.clone();
     try_literal("[", reader)?;
-    let space0 = whitespace(reader);
-    let mut elements = vec![];
-
-    // at least one element
-    if reader.peek() != Some(']') {
-        let first_element = list_element(reader)?;
-        elements.push(first_element);
-
-        loop {
-            if reader.peek() == Some(']') {
-                break;
-            }
-            if reader.peek() != Some(',') {
-                break;
+    nested(start, reader, |reader| {
+        let space0 = whitespace(reader);
+        let mut elements = vec![];
+        let mut comments = trailing_comments(']', reader);
+
+        // at least one element
+        if comments.is_none() && reader.peek() != Some(']') {
+            let first_element = list_element(reader)?;
+            elements.push(first_element);
+
+            loop {
+                if reader.peek() == Some(']') {
+                    break;
+                }
+                if reader.peek() != Some(',') {
+                    break;
+                }
+                literal(",", reader)?;
+                let element = list_element(reader)?;
+                elements.push(element);
             }
-            literal(",", reader)?;
-            let element = list_element(reader)?;
-            elements.push(element);
+            comments = trailing_comments(']', reader);
         }
-    }
-    literal("]", reader)?;
+        literal("]", reader)?;
 
-    Ok(JsonValue::List { space0, elements })
+        Ok(JsonValue::List {
+            space0,
+            elements,
+            comments: comments.unwrap_or_default(),
+        })
+    })
 }
 
 fn list_element(reader: &mut Reader) -> ParseResult<'static, JsonListElement> {
     let save = reader.state.pos.clone();
     let space0 = whitespace(reader);
+    let comments = comments(reader);
     let value = match parse(reader) {
         Ok(r) => r,
+        Err(e) if matches!(e.inner, error::ParseError::JsonMaxDepth { .. }) => return Err(e),
         Err(_) => {
             return Err(error::Error {
                 pos: save,
@@ -277,37 +290,66 @@ fn list_element(reader: &mut Reader) -> ParseResult<'static, JsonListElement> {
     let space1 = whitespace(reader);
     Ok(JsonListElement {
         space0,
+        comments,
         value,
         space1,
     })
 }
 
 pub fn object_value(reader: &mut Reader) -> ParseResult<'static, JsonValue> {
+    let start = reader.state.pos.clone();
     try_literal("{", reader)?;
-    let space0 = whitespace(reader);
-    let mut elements = vec![];
-    if reader.peek() != Some('}') {
-        let first_element = object_element(reader)?;
-        elements.push(first_element);
-
-        loop {
-            if reader.peek() == Some('}') {
-                break;
-            }
-            if reader.peek() != Some(',') {
-                break;
+    nested(start, reader, |reader| {
+        let space0 = whitespace(reader);
+        let mut elements = vec![];
+        let mut comments = trailing_comments('}', reader);
+        if comments.is_none() && reader.peek() != Some('}') {
+            let first_element = object_element(reader)?;
+            elements.push(first_element);
+
+            loop {
+                if reader.peek() == Some('}') {
+                    break;
+                }
+                if reader.peek() != Some(',') {
+                    break;
+                }
+                literal(",", reader)?;
+                let element = object_element(reader)?;
+                elements.push(element);
             }
-            literal(",", reader)?;
-            let element = object_element(reader)?;
-            elements.push(element);
+            comments = trailing_comments('}', reader);
         }
-    }
-
-    // at least one element
+        literal("}", reader)?;
 
-    literal("}", reader)?;
+        Ok(JsonValue::Object {
+            space0,
+            elements,
+            comments: comments.unwrap_or_default(),
+        })
+    })
+}
 
-    Ok(JsonValue::Object { space0, elements })
+/// Parses the content of a list or an object opened at `start` with `f`, failing if the
+/// maximum nesting depth of the reader is exceeded.
+fn nested(
+    start: Pos,
+    reader: &mut Reader,
+    f: impl FnOnce(&mut Reader) -> ParseResult<'static, JsonValue>,
+) -> ParseResult<'static, JsonValue> {
+    if reader.json_depth >= reader.max_json_depth {
+        return Err(error::Error {
+            pos: start,
+            recoverable: false,
+            inner: error::ParseError::JsonMaxDepth {
+                max: reader.max_json_depth,
+            },
+        });
+    }
+    reader.json_depth += 1;
+    let value = f(reader);
+    reader.json_depth -= 1;
+    value
 }
 
 fn key(reader: &mut Reader) -> ParseResult<'static, Template> {
@@ -325,6 +367,7 @@ fn key(reader: &mut Reader) -> ParseResult<'static, Template> {
 }
 fn object_element(reader: &mut Reader) -> ParseResult<'static, JsonObjectElement> {
     let space0 = whitespace(reader);
+    let comments = comments(reader);
     //literal("\"", reader)?;
     let name = key(reader)?;
     //literal("\"", reader)?;
@@ -334,6 +377,7 @@ fn object_element(reader: &mut Reader) -> ParseResult<'static, JsonObjectElement
     let space2 = whitespace(reader);
     let value = match parse(reader) {
         Ok(r) => r,
+        Err(e) if matches!(e.inner, error::ParseError::JsonMaxDepth { .. }) => return Err(e),
         Err(_) => {
             return Err(error::Error {
                 pos: save,
@@ -345,6 +389,7 @@ fn object_element(reader: &mut Reader) -> ParseResult<'static, JsonObjectElement
     let space3 = whitespace(reader);
     Ok(JsonObjectElement {
         space0,
+        comments,
         name,
         space1,
         space2,
@@ -357,6 +402,31 @@ fn whitespace(reader: &mut Reader) -> String {
     reader.read_while(|c| *c == ' ' || *c == '\t' || *c == '\n' || *c == '\r')
 }
 
+/// Parses the line comments (`// comment`) preceding a list or object element.
+pub fn comments(reader: &mut Reader) -> Vec<JsonComment> {
+    let mut comments = vec![];
+    while reader.try_literal("//") {
+        let value = reader.read_while(|c| *c != '\n');
+        let space0 = whitespace(reader);
+        comments.push(JsonComment { value, space0 });
+    }
+    comments
+}
+
+/// Parses the line comments before the `closing` char of a list or an object, after its last
+/// element or in an empty container. Returns `None` (without consuming anything) if there are
+/// no such comments, or if they precede another element.
+fn trailing_comments(closing: char, reader: &mut Reader) -> Option<Vec<JsonComment>> {
+    let save = reader.state.clone();
+    let comments = comments(reader);
+    if !comments.is_empty() && reader.peek() == Some(closing) {
+        Some(comments)
+    } else {
+        reader.state = save;
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -716,7 +786,8 @@ mod tests {
             list_value(&mut reader).unwrap(),
             JsonValue::List {
                 space0: "".to_string(),
-                elements: vec![]
+                elements: vec![],
+                comments: vec![]
             }
         );
         assert_eq!(reader.state.cursor, 2);
@@ -726,7 +797,8 @@ mod tests {
             list_value(&mut reader).unwrap(),
             JsonValue::List {
                 space0: " ".to_string(),
-                elements: vec![]
+                elements: vec![],
+                comments: vec![]
             }
         );
         assert_eq!(reader.state.cursor, 3);
@@ -738,9 +810,11 @@ mod tests {
                 space0: "".to_string(),
                 elements: vec![JsonListElement {
                     space0: "".to_string(),
+                    comments: vec![],
                     value: JsonValue::Boolean(true),
                     space1: "".to_string(),
                 }],
+                comments: vec![],
             }
         );
         assert_eq!(reader.state.cursor, 6);
@@ -766,6 +840,33 @@ mod tests {
         assert!(!error.recoverable);
     }
 
+    #[test]
+    fn test_max_depth() {
+        let mut reader = Reader::new("[{\"a\": [1]}]");
+        reader.max_json_depth = 3;
+        assert!(parse(&mut reader).is_ok());
+        assert_eq!(reader.json_depth, 0);
+
+        let mut reader = Reader::new("[{\"a\": [1]}]");
+        reader.max_json_depth = 2;
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 8 });
+        assert_eq!(error.inner, error::ParseError::JsonMaxDepth { max: 2 });
+        assert!(!error.recoverable);
+
+        let s = format!("{}{}", "[".repeat(129), "]".repeat(129));
+        let mut reader = Reader::new(&s);
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 129
+            }
+        );
+        assert_eq!(error.inner, error::ParseError::JsonMaxDepth { max: 128 });
+    }
+
     #[test]
     fn test_list_element() {
         let mut reader = Reader::new("true");
@@ -773,6 +874,7 @@ mod tests {
             list_element(&mut reader).unwrap(),
             JsonListElement {
                 space0: "".to_string(),
+                comments: vec![],
                 value: JsonValue::Boolean(true),
                 space1: "".to_string(),
             }
@@ -787,7 +889,8 @@ mod tests {
             object_value(&mut reader).unwrap(),
             JsonValue::Object {
                 space0: "".to_string(),
-                elements: vec![]
+                elements: vec![],
+                comments: vec![]
             }
         );
         assert_eq!(reader.state.cursor, 2);
@@ -797,7 +900,8 @@ mod tests {
             object_value(&mut reader).unwrap(),
             JsonValue::Object {
                 space0: " ".to_string(),
-                elements: vec![]
+                elements: vec![],
+                comments: vec![]
             }
         );
         assert_eq!(reader.state.cursor, 3);
@@ -809,6 +913,7 @@ mod tests {
                 space0: "\n  ".to_string(),
                 elements: vec![JsonObjectElement {
                     space0: "".to_string(),
+                    comments: vec![],
                     name: Template {
                         delimiter: Some('"'),
                         elements: vec![TemplateElement::String {
@@ -822,6 +927,7 @@ mod tests {
                     value: JsonValue::Boolean(true),
                     space3: "\n".to_string(),
                 }],
+                comments: vec![],
             }
         );
         assert_eq!(reader.state.cursor, 15);
@@ -854,6 +960,7 @@ mod tests {
             object_element(&mut reader).unwrap(),
             JsonObjectElement {
                 space0: "".to_string(),
+                comments: vec![],
                 name: Template {
                     delimiter: Some('"'),
                     elements: vec![TemplateElement::String {
@@ -871,6 +978,93 @@ mod tests {
         assert_eq!(reader.state.cursor, 9);
     }
 
+    #[test]
+    fn test_object_element_with_comments() {
+        let mut reader = Reader::new("\n  // Identifier\n  // (mandatory)\n  \"a\": true");
+        let element = object_element(&mut reader).unwrap();
+        assert_eq!(element.space0, "\n  ".to_string());
+        assert_eq!(
+            element.comments,
+            vec![
+                JsonComment {
+                    value: " Identifier".to_string(),
+                    space0: "\n  ".to_string(),
+                },
+                JsonComment {
+                    value: " (mandatory)".to_string(),
+                    space0: "\n  ".to_string(),
+                },
+            ]
+        );
+        assert_eq!(element.value, JsonValue::Boolean(true));
+        assert_eq!(reader.state.cursor, 45);
+    }
+
+    #[test]
+    fn test_trailing_comments() {
+        let mut reader = Reader::new("[1 // one\n]");
+        assert_eq!(
+            list_value(&mut reader).unwrap(),
+            JsonValue::List {
+                space0: "".to_string(),
+                elements: vec![JsonListElement {
+                    space0: "".to_string(),
+                    comments: vec![],
+                    value: JsonValue::Number("1".to_string()),
+                    space1: " ".to_string(),
+                }],
+                comments: vec![JsonComment {
+                    value: " one".to_string(),
+                    space0: "\n".to_string(),
+                }],
+            }
+        );
+        assert!(reader.is_eof());
+
+        let mut reader = Reader::new("{ // empty\n}");
+        assert_eq!(
+            object_value(&mut reader).unwrap(),
+            JsonValue::Object {
+                space0: " ".to_string(),
+                elements: vec![],
+                comments: vec![JsonComment {
+                    value: " empty".to_string(),
+                    space0: "\n".to_string(),
+                }],
+            }
+        );
+        assert!(reader.is_eof());
+
+        // A comment before a comma is not a trailing comment.
+        let mut reader = Reader::new("[1 // one\n, 2]");
+        assert!(list_value(&mut reader).is_err());
+    }
+
+    #[test]
+    fn test_comments_round_trip() {
+        let input = r#"{
+  // Identifier
+  "id": 1,
+  "tags": [
+    // first tag
+    "a", // second tag
+    "b"
+    // no more tags
+  ],
+  "extra": { // none yet
+  },
+  "aliases": [
+    // none yet
+  ]
+  // end
+}"#;
+        let mut reader = Reader::new(input);
+        let value = parse(&mut reader).unwrap();
+        assert!(reader.is_eof());
+        assert_eq!(value.to_string(), input);
+        assert_eq!(value.encoded(), input);
+    }
+
     #[test]
     fn test_object_element_error() {
         let mut reader = Reader::new(":");
@@ -23,12 +23,50 @@ pub fn parse_hurl_file(s: &str) -> ParseResult<'static, HurlFile> {
     parsers::hurl_file(&mut reader)
 }
 
+/// Parses a Hurl file with a given parsing `mode`, returning the file and the warnings
+/// collected while parsing (see [`Reader::warnings`]): duplicated headers in any mode, and the
+/// non canonical syntax accepted in [`ParseMode::Lenient`].
+pub fn parse_hurl_file_with_mode(
+    s: &str,
+    mode: ParseMode,
+) -> ParseResult<'static, (HurlFile, Vec<Error>)> {
+    let mut reader = Reader::with_mode(s, mode);
+    let hurl_file = parsers::hurl_file(&mut reader)?;
+    Ok((hurl_file, reader.warnings))
+}
+
+/// Parses a Hurl file from a configured `reader`, for the parsing options that have no
+/// dedicated entry point (like [`Reader::case_insensitive_methods`] or [`Reader::tab_width`]).
+///
+/// The warnings are collected in [`Reader::warnings`].
+///
+/// # Example
+/// ```
+/// use hurl_core::parser::{parse_hurl_file_with_reader, Reader};
+///
+/// let mut reader = Reader::new("get https://example.org\n");
+/// reader.case_insensitive_methods = true;
+/// let hurl_file = parse_hurl_file_with_reader(&mut reader).unwrap();
+/// assert_eq!(hurl_file.entries.len(), 1);
+/// ```
+pub fn parse_hurl_file_with_reader(reader: &mut Reader) -> ParseResult<'static, HurlFile> {
+    parsers::hurl_file(reader)
+}
+
+/// Parses a Hurl file, returning the entries that could be parsed and all the parsing errors,
+/// instead of stopping at the first error.
+pub fn parse_hurl_file_resilient(s: &str) -> (HurlFile, Vec<Error>) {
+    let mut reader = Reader::new(s);
+    parsers::hurl_file_resilient(&mut reader)
+}
+
 pub use self::error::{Error, ParseError};
 pub use self::json::{
     boolean_value as parse_json_boolean, null_value as parse_json_null,
     number_value as parse_json_number, parse as parse_json,
 };
-pub use self::reader::Reader;
+pub use self::primitives::regex_error_message;
+pub use self::reader::{ParseMode, Reader, DEFAULT_MAX_JSON_DEPTH};
 pub use self::template::templatize;
 use crate::ast::HurlFile;
 
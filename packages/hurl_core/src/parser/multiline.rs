@@ -26,7 +26,10 @@ pub fn multiline_string(reader: &mut Reader) -> ParseResult<'static, MultilineSt
     try_literal("```", reader)?;
     let save = reader.state.clone();
 
-    match choice(&[json_text, xml_text, graphql, plain_text], reader) {
+    match choice(
+        &[json_text, xml_text, graphql, charset_text, plain_text],
+        reader,
+    ) {
         Ok(multi) => Ok(multi),
         Err(err) => {
             // FIXME: how to parse
@@ -43,7 +46,10 @@ pub fn multiline_string(reader: &mut Reader) -> ParseResult<'static, MultilineSt
             // ```
             //
             // => this one is non recoverable should trigger an GraphQL variables error
-            if let ParseError::GraphQlVariables = err.inner {
+            if let ParseError::GraphQlVariables
+            | ParseError::Charset { .. }
+            | ParseError::ContentType { .. } = err.inner
+            {
                 return Err(err);
             }
             reader.state = save;
@@ -55,16 +61,86 @@ pub fn multiline_string(reader: &mut Reader) -> ParseResult<'static, MultilineSt
 
 fn text(lang: &str, reader: &mut Reader) -> ParseResult<'static, Text> {
     try_literal(lang, reader)?;
+    let charset = optional(multiline_charset, reader)?;
+    text_content(charset, reader)
+}
+
+fn text_content(
+    charset: Option<MultilineCharset>,
+    reader: &mut Reader,
+) -> ParseResult<'static, Text> {
+    let content_type = optional(multiline_content_type, reader)?;
     let space = zero_or_more_spaces(reader)?;
     let newline = newline(reader)?;
     let value = multiline_string_value(reader)?;
     Ok(Text {
+        charset,
+        content_type,
         space,
         newline,
         value,
     })
 }
 
+fn multiline_charset(reader: &mut Reader) -> ParseResult<'static, MultilineCharset> {
+    try_literal(";", reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    let start = reader.state.clone();
+    try_literal("charset=", reader)?;
+    let name = reader.read_while(|c| c.is_alphanumeric() || *c == '-' || *c == '_');
+    let value = match name.as_str() {
+        "utf-8" => Charset::Utf8,
+        "utf-16" => Charset::Utf16,
+        "utf-16be" => Charset::Utf16Be,
+        "utf-16le" => Charset::Utf16Le,
+        _ => {
+            return Err(Error {
+                pos: start.pos,
+                recoverable: false,
+                inner: ParseError::Charset { name },
+            })
+        }
+    };
+    Ok(MultilineCharset { space0, value })
+}
+
+fn multiline_content_type(reader: &mut Reader) -> ParseResult<'static, MultilineContentType> {
+    try_literal(";", reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    try_literal("content-type=", reader)?;
+    let start = reader.state.clone();
+    let value = reader.read_while(|c| !c.is_whitespace() && *c != '`' && *c != ';');
+    if !is_content_type(&value) {
+        return Err(Error {
+            pos: start.pos,
+            recoverable: false,
+            inner: ParseError::ContentType { value },
+        });
+    }
+    Ok(MultilineContentType { space0, value })
+}
+
+/// Returns true if `value` is a media type `type/subtype`, both being HTTP tokens.
+fn is_content_type(value: &str) -> bool {
+    let is_token = |s: &str| {
+        !s.is_empty()
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_|~".contains(c))
+    };
+    match value.split_once('/') {
+        Some((type_, subtype)) => is_token(type_) && is_token(subtype),
+        None => false,
+    }
+}
+
+/// Parses a plain text introduced by an explicit `text` lang, which requires a charset.
+fn charset_text(reader: &mut Reader) -> ParseResult<'static, MultilineString> {
+    try_literal("text", reader)?;
+    let charset = multiline_charset(reader)?;
+    let text = text_content(Some(charset), reader)?;
+    Ok(MultilineString::Text(text))
+}
+
 fn json_text(reader: &mut Reader) -> ParseResult<'static, MultilineString> {
     let text = text("json", reader)?;
     Ok(MultilineString::Json(text))
@@ -223,6 +299,8 @@ fn plain_text(reader: &mut Reader) -> ParseResult<'static, MultilineString> {
     let newline = newline(reader)?;
     let value = multiline_string_value(reader)?;
     Ok(MultilineString::Text(Text {
+        charset: None,
+        content_type: None,
         space,
         newline,
         value,
@@ -304,6 +382,8 @@ mod tests {
         assert_eq!(
             multiline_string(&mut reader).unwrap(),
             MultilineString::Text(Text {
+                charset: None,
+                content_type: None,
                 space: Whitespace {
                     value: "".to_string(),
                     source_info: SourceInfo::new(1, 4, 1, 4),
@@ -327,6 +407,8 @@ mod tests {
         assert_eq!(
             multiline_string(&mut reader).unwrap(),
             MultilineString::Text(Text {
+                charset: None,
+                content_type: None,
                 space: Whitespace {
                     value: "         ".to_string(),
                     source_info: SourceInfo::new(1, 4, 1, 13),
@@ -353,6 +435,8 @@ mod tests {
         assert_eq!(
             multiline_string(&mut reader).unwrap(),
             MultilineString::Json(Text {
+                charset: None,
+                content_type: None,
                 space: Whitespace {
                     value: "".to_string(),
                     source_info: SourceInfo::new(1, 8, 1, 8),
@@ -373,6 +457,144 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_multiline_string_charset() {
+        let mut reader = Reader::new("```text; charset=utf-16\nline1\n```");
+        let multiline = multiline_string(&mut reader).unwrap();
+        assert_eq!(
+            multiline,
+            MultilineString::Text(Text {
+                charset: Some(MultilineCharset {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(1, 9, 1, 10),
+                    },
+                    value: Charset::Utf16,
+                }),
+                content_type: None,
+                space: Whitespace {
+                    value: "".to_string(),
+                    source_info: SourceInfo::new(1, 24, 1, 24),
+                },
+                newline: Whitespace {
+                    value: "\n".to_string(),
+                    source_info: SourceInfo::new(1, 24, 2, 1),
+                },
+                value: Template {
+                    delimiter: None,
+                    elements: vec![TemplateElement::String {
+                        value: "line1\n".to_string(),
+                        encoded: "line1\n".to_string(),
+                    }],
+                    source_info: SourceInfo::new(2, 1, 3, 1),
+                },
+            })
+        );
+        assert_eq!(multiline.lang(), "text");
+        assert_eq!(multiline.charset().unwrap().to_string(), "; charset=utf-16");
+
+        let mut reader = Reader::new("```json;charset=utf-8\n{}\n```");
+        let multiline = multiline_string(&mut reader).unwrap();
+        assert_eq!(multiline.lang(), "json");
+        assert_eq!(multiline.charset().unwrap().value, Charset::Utf8);
+    }
+
+    #[test]
+    fn test_multiline_string_content_type() {
+        let mut reader = Reader::new("```json; content-type=application/vnd.api+json\n{}\n```");
+        let multiline = multiline_string(&mut reader).unwrap();
+        assert_eq!(
+            multiline,
+            MultilineString::Json(Text {
+                charset: None,
+                content_type: Some(MultilineContentType {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(1, 9, 1, 10),
+                    },
+                    value: "application/vnd.api+json".to_string(),
+                }),
+                space: Whitespace {
+                    value: "".to_string(),
+                    source_info: SourceInfo::new(1, 47, 1, 47),
+                },
+                newline: Whitespace {
+                    value: "\n".to_string(),
+                    source_info: SourceInfo::new(1, 47, 2, 1),
+                },
+                value: Template {
+                    delimiter: None,
+                    elements: vec![TemplateElement::String {
+                        value: "{}\n".to_string(),
+                        encoded: "{}\n".to_string(),
+                    }],
+                    source_info: SourceInfo::new(2, 1, 3, 1),
+                },
+            })
+        );
+        assert_eq!(
+            multiline.content_type().unwrap().to_string(),
+            "; content-type=application/vnd.api+json"
+        );
+
+        let mut reader = Reader::new("```text; charset=utf-16; content-type=text/csv\na,b\n```");
+        let multiline = multiline_string(&mut reader).unwrap();
+        assert_eq!(multiline.charset().unwrap().value, Charset::Utf16);
+        assert_eq!(multiline.content_type().unwrap().value, "text/csv");
+    }
+
+    #[test]
+    fn test_multiline_string_content_type_error() {
+        let mut reader = Reader::new("```xml; content-type=application\n<a/>\n```");
+        let error = multiline_string(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 22
+            }
+        );
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.inner,
+            ParseError::ContentType {
+                value: "application".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_multiline_string_charset_error() {
+        let mut reader = Reader::new("```text; charset=latin-2\nline1\n```");
+        let error = multiline_string(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 10
+            }
+        );
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.inner,
+            ParseError::Charset {
+                name: "latin-2".to_string()
+            }
+        );
+
+        // A charset requires the `charset=` prefix.
+        let mut reader = Reader::new("```json; utf-16\n{}\n```");
+        let error = multiline_string(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 4 });
+        assert_eq!(error.inner, ParseError::Multiline);
+
+        // Other parameters are not charsets.
+        let mut reader = Reader::new("```xml; encoding=utf-8\n<a/>\n```");
+        let error = multiline_string(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 4 });
+        assert_eq!(error.inner, ParseError::Multiline);
+    }
+
     #[test]
     fn test_multiline_string_graphql() {
         let mut reader = Reader::new("```graphql\nline1\nline2\nline3\n```");
@@ -453,6 +675,8 @@ mod tests {
         assert_eq!(
             multiline_string(&mut reader).unwrap(),
             MultilineString::Text(Text {
+                charset: None,
+                content_type: None,
                 space: Whitespace {
                     value: "".to_string(),
                     source_info: SourceInfo::new(1, 4, 1, 4),
@@ -472,6 +696,8 @@ mod tests {
         assert_eq!(
             multiline_string(&mut reader).unwrap(),
             MultilineString::Text(Text {
+                charset: None,
+                content_type: None,
                 space: Whitespace {
                     value: "".to_string(),
                     source_info: SourceInfo::new(1, 4, 1, 4),
@@ -527,6 +753,8 @@ mod tests {
         assert_eq!(
             multiline_string(&mut reader).unwrap(),
             MultilineString::Text(Text {
+                charset: None,
+                content_type: None,
                 space: Whitespace {
                     value: "".to_string(),
                     source_info: SourceInfo::new(1, 4, 1, 4),
@@ -553,6 +781,8 @@ mod tests {
         assert_eq!(
             multiline_string(&mut reader).unwrap(),
             MultilineString::Text(Text {
+                charset: None,
+                content_type: None,
                 space: Whitespace {
                     value: "".to_string(),
                     source_info: SourceInfo::new(1, 4, 1, 4),
@@ -577,6 +807,8 @@ mod tests {
         assert_eq!(
             multiline_string(&mut reader).unwrap(),
             MultilineString::Text(Text {
+                charset: None,
+                content_type: None,
                 space: Whitespace {
                     value: "".to_string(),
                     source_info: SourceInfo::new(1, 4, 1, 4),
@@ -701,6 +933,7 @@ variables {
                         space0: "\n  ".to_string(),
                         elements: vec![JsonObjectElement {
                             space0: "".to_string(),
+                            comments: vec![],
                             name: Template {
                                 delimiter: Some('"'),
                                 elements: vec![
@@ -724,7 +957,7 @@ variables {
                                 source_info: SourceInfo::new(9, 12, 9, 20)
                             }),
                             space3: "\n".to_string()
-                        }]
+                        }], comments: vec![]
                     },
                     whitespace: Whitespace {
                         value: "\n".to_string(),
@@ -21,30 +21,181 @@ use crate::parser::bytes::*;
 use crate::parser::combinators::*;
 use crate::parser::error::*;
 use crate::parser::primitives::*;
-use crate::parser::reader::Reader;
+use crate::parser::reader::{ParseMode, Reader};
 use crate::parser::sections::*;
 use crate::parser::url::url;
 use crate::parser::ParseResult;
 
 pub fn hurl_file(reader: &mut Reader) -> ParseResult<'static, HurlFile> {
+    skip_bom(reader);
+    let settings = optional(settings, reader)?;
     let entries = zero_or_more(entry, reader)?;
+    check_response_references(&entries)?;
     let line_terminators = optional_line_terminators(reader)?;
     eof(reader)?;
     Ok(HurlFile {
+        settings,
         entries,
         line_terminators,
     })
 }
 
+/// Parses a Hurl file, recovering from the errors of an entry to parse the following entries.
+///
+/// On an error, the parsing resumes at the next line starting with an HTTP method, so that all
+/// the independent errors of a file are reported at once. Returns the successfully parsed
+/// entries and the errors, in order.
+pub fn hurl_file_resilient(reader: &mut Reader) -> (HurlFile, Vec<Error>) {
+    skip_bom(reader);
+    let mut errors = vec![];
+    let settings = match optional(settings, reader) {
+        Ok(settings) => settings,
+        Err(e) => {
+            skip_to_next_entry(reader, e.pos.line);
+            errors.push(e);
+            None
+        }
+    };
+    let mut entries = vec![];
+    let mut line_terminators = vec![];
+    while !reader.is_eof() {
+        let start = reader.state.clone();
+        let error = match entry(reader) {
+            Ok(entry) => {
+                entries.push(entry);
+                continue;
+            }
+            Err(e) if e.recoverable => {
+                // As in `hurl_file`, the file can end with empty lines or comments.
+                reader.state = start.clone();
+                line_terminators = optional_line_terminators(reader).unwrap_or_default();
+                match eof(reader) {
+                    Ok(()) => break,
+                    Err(e) => e,
+                }
+            }
+            Err(e) => e,
+        };
+        reader.state = start;
+        skip_to_next_entry(reader, error.pos.line);
+        errors.push(error);
+    }
+    if let Err(e) = check_response_references(&entries) {
+        errors.push(e);
+    }
+    let hurl_file = HurlFile {
+        settings,
+        entries,
+        line_terminators,
+    };
+    (hurl_file, errors)
+}
+
+/// Moves `reader` to the start of the first line after `line` beginning with an HTTP method,
+/// or to the end of the file.
+fn skip_to_next_entry(reader: &mut Reader, line: usize) {
+    loop {
+        reader.read_while(|c| *c != '\n');
+        if reader.read().is_none() {
+            return;
+        }
+        if reader.state.pos.line <= line {
+            continue;
+        }
+        let start = reader.state.clone();
+        let is_entry = matches!(method(reader), Ok(method) if !matches!(method, Method::Custom(_)))
+            && reader.peek() == Some(' ');
+        reader.state = start;
+        if is_entry {
+            return;
+        }
+    }
+}
+
+/// Byte order mark, written at the start of a file by some editors.
+const BOM: char = '\u{feff}';
+
+/// Skips the byte order mark at the start of the file, if any.
+///
+/// The BOM is not displayed by editors, so the position is not changed: the first char
+/// following it is still at line 1, column 1.
+fn skip_bom(reader: &mut Reader) {
+    if reader.state.cursor == 0 && reader.peek() == Some(BOM) {
+        reader.state.cursor += 1;
+    }
+}
+
+/// Checks that the bodies referencing the response of a previous entry (`from-response N;`)
+/// refer to an entry of the file.
+fn check_response_references(entries: &[Entry]) -> ParseResult<'static, ()> {
+    for (index, entry) in entries.iter().enumerate() {
+        let response_body = entry.response.as_ref().and_then(|r| r.body.as_ref());
+        for body in entry.request.body.iter().chain(response_body) {
+            if let Bytes::FromResponse(n) = body.value {
+                if n > index {
+                    return Err(Error {
+                        pos: body.space0.source_info.end.clone(),
+                        recoverable: false,
+                        inner: ParseError::FromResponse { index: n as i64 },
+                    });
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a `[Settings]` section is not declared after an entry, as settings are only
+/// allowed before the first entry.
+fn no_settings(reader: &mut Reader) -> ParseResult<'static, ()> {
+    let start = reader.state.clone();
+    optional_line_terminators(reader)?;
+    zero_or_more_spaces(reader)?;
+    let pos = reader.state.pos.clone();
+    if reader.try_literal("[Settings]") {
+        return Err(Error {
+            pos,
+            recoverable: false,
+            inner: ParseError::MisplacedSettings,
+        });
+    }
+    reader.state = start;
+    Ok(())
+}
+
 fn entry(reader: &mut Reader) -> ParseResult<'static, Entry> {
+    no_settings(reader)?;
     let req = request(reader)?;
     let resp = optional(response, reader)?;
+    let name = entry_name(&req.line_terminators);
     Ok(Entry {
         request: req,
         response: resp,
+        name,
     })
 }
 
+/// Returns the entry label declared by a `# @name <label>` comment preceding the request.
+/// If there are several annotations, the one closest to the request wins.
+fn entry_name(line_terminators: &[LineTerminator]) -> Option<String> {
+    line_terminators
+        .iter()
+        .rev()
+        .filter_map(|line_terminator| line_terminator.comment.as_ref())
+        .find_map(|comment| {
+            let name = comment.value.trim_start().strip_prefix("@name")?;
+            if !name.starts_with([' ', '\t']) {
+                return None;
+            }
+            let name = name.trim();
+            if name.is_empty() {
+                None
+            } else {
+                Some(name.to_string())
+            }
+        })
+}
+
 fn request(reader: &mut Reader) -> ParseResult<'static, Request> {
     let start = reader.state.clone();
     let line_terminators = optional_line_terminators(reader)?;
@@ -55,8 +206,12 @@ fn request(reader: &mut Reader) -> ParseResult<'static, Request> {
 
     let line_terminator0 = line_terminator(reader)?;
     let headers = zero_or_more(key_value, reader)?;
+    check_duplicate_headers(&headers, reader);
     let sections = request_sections(reader)?;
     let b = optional(body, reader)?;
+    if b.is_some() {
+        no_other_body(reader)?;
+    }
     let source_info = SourceInfo::new(
         start.pos.line,
         start.pos.column,
@@ -101,8 +256,12 @@ fn response(reader: &mut Reader) -> ParseResult<'static, Response> {
     let _status = status(reader)?;
     let line_terminator0 = line_terminator(reader)?;
     let headers = zero_or_more(key_value, reader)?;
+    check_duplicate_headers(&headers, reader);
     let sections = response_sections(reader)?;
     let b = optional(body, reader)?;
+    if b.is_some() {
+        no_other_body(reader)?;
+    }
     Ok(Response {
         line_terminators,
         space0,
@@ -122,6 +281,55 @@ fn response(reader: &mut Reader) -> ParseResult<'static, Response> {
     })
 }
 
+/// Headers that can only have one value, see <https://www.rfc-editor.org/rfc/rfc9110>.
+///
+/// Other headers (like `Set-Cookie` or `Accept`) can legitimately be repeated.
+const SINGLE_VALUED_HEADERS: [&str; 12] = [
+    "Authorization",
+    "Content-Encoding",
+    "Content-Length",
+    "Content-Location",
+    "Content-Type",
+    "Date",
+    "ETag",
+    "Expires",
+    "Host",
+    "Last-Modified",
+    "Location",
+    "User-Agent",
+];
+
+/// Adds a warning to `reader` for each single-valued header of `headers` already defined
+/// (names being compared case-insensitively).
+///
+/// Duplicated headers are still accepted, they are only reported to help debugging.
+fn check_duplicate_headers(headers: &[KeyValue], reader: &mut Reader) {
+    let mut names = vec![];
+    for header in headers {
+        let name = header.key.value.to_lowercase();
+        if !SINGLE_VALUED_HEADERS
+            .iter()
+            .any(|single| single.to_lowercase() == name)
+        {
+            continue;
+        }
+        if names.contains(&name) {
+            reader.warnings.push(Error {
+                pos: header.key.source_info.start.clone(),
+                recoverable: true,
+                inner: ParseError::DuplicateHeader {
+                    name: header.key.value.clone(),
+                },
+            });
+        } else {
+            names.push(name);
+        }
+    }
+}
+
+/// Maximum length of a method name, longer than any of the available methods.
+const MAX_METHOD_LEN: usize = 32;
+
 fn method(reader: &mut Reader) -> ParseResult<'static, Method> {
     if reader.is_eof() {
         return Err(Error {
@@ -129,11 +337,13 @@ fn method(reader: &mut Reader) -> ParseResult<'static, Method> {
             recoverable: true,
             inner: ParseError::Method {
                 name: "<EOF>".to_string(),
+                suggestion: None,
             },
         });
     }
     let start = reader.state.clone();
-    let name = reader.read_while(|c| c.is_alphanumeric());
+    // Methods are short, don't read a whole garbage line to report a method error.
+    let name = reader.read_while_max(|c| c.is_alphanumeric(), MAX_METHOD_LEN);
     let available_methods = [
         ("GET", Method::Get),
         ("HEAD", Method::Head),
@@ -153,22 +363,41 @@ fn method(reader: &mut Reader) -> ParseResult<'static, Method> {
         ("VIEW", Method::View),
     ];
 
-    for (s, method) in available_methods.into_iter() {
-        if name == s {
-            return Ok(method);
+    for (s, method) in available_methods.iter() {
+        if name == *s || (reader.case_insensitive_methods && name.eq_ignore_ascii_case(s)) {
+            return Ok(method.clone());
         }
     }
+    // Other methods are accepted as long as they're uppercase (and not truncated).
+    let truncated = reader.peek().is_some_and(|c| c.is_alphanumeric());
+    if !name.is_empty() && !truncated && name.chars().all(|c| c.is_ascii_uppercase()) {
+        return Ok(Method::Custom(name));
+    }
     reader.state = start.clone();
+    let names = available_methods.map(|(s, _)| s);
+    let suggestion = crate::error::suggestion(&names, &name);
     Err(Error {
         pos: start.pos,
         recoverable: false,
-        inner: ParseError::Method { name },
+        inner: ParseError::Method { name, suggestion },
     })
 }
 
 fn version(reader: &mut Reader) -> ParseResult<'static, Version> {
     let start = reader.state.clone();
-    try_literal("HTTP", reader)?;
+    if let Err(e) = try_literal("HTTP", reader) {
+        if reader.mode != ParseMode::Lenient || reader.peek_n(4).to_uppercase() != "HTTP" {
+            return Err(e);
+        }
+        reader.read_n(4);
+        reader.warnings.push(Error {
+            pos: start.pos.clone(),
+            recoverable: true,
+            inner: ParseError::KeywordCase {
+                value: "HTTP".to_string(),
+            },
+        });
+    }
 
     let next_c = reader.peek();
     match next_c {
@@ -219,15 +448,18 @@ fn status(reader: &mut Reader) -> ParseResult<'static, Status> {
     let start = reader.state.pos.clone();
     let value = match try_literal("*", reader) {
         Ok(_) => StatusValue::Any,
-        Err(_) => match natural(reader) {
-            Ok(value) => StatusValue::Specific(value),
-            Err(_) => {
-                return Err(Error {
-                    pos: start,
-                    recoverable: false,
-                    inner: ParseError::Status {},
-                });
-            }
+        Err(_) => match status_class(reader) {
+            Some(class) => StatusValue::Class(class),
+            None => match natural(reader) {
+                Ok(value) => status_range_end(value, &start, reader)?,
+                Err(_) => {
+                    return Err(Error {
+                        pos: start,
+                        recoverable: false,
+                        inner: ParseError::Status {},
+                    });
+                }
+            },
         },
     };
     let end = reader.state.pos.clone();
@@ -237,6 +469,51 @@ fn status(reader: &mut Reader) -> ParseResult<'static, Status> {
     })
 }
 
+/// Parses the optional end of a status range (like `-204` in `200-204`), given its `first` code.
+///
+/// Without a range, the status is the specific code `first`.
+fn status_range_end(
+    first: u64,
+    start: &Pos,
+    reader: &mut Reader,
+) -> ParseResult<'static, StatusValue> {
+    if try_literal("-", reader).is_err() {
+        return Ok(StatusValue::Specific(first));
+    }
+    let last = match natural(reader) {
+        Ok(last) => last,
+        Err(e) => {
+            return Err(Error {
+                recoverable: false,
+                ..e
+            })
+        }
+    };
+    if first > last {
+        return Err(Error {
+            pos: start.clone(),
+            recoverable: false,
+            inner: ParseError::StatusRange {
+                start: first,
+                end: last,
+            },
+        });
+    }
+    Ok(StatusValue::Range(first, last))
+}
+
+/// Parses a status class, a digit from 1 to 5 followed by `xx` (like `2xx`), and returns its digit.
+fn status_class(reader: &mut Reader) -> Option<u8> {
+    let start = reader.state.clone();
+    if let Some(c @ '1'..='5') = reader.read() {
+        if try_literal("xx", reader).is_ok() {
+            return Some(c as u8 - b'0');
+        }
+    }
+    reader.state = start;
+    None
+}
+
 fn body(reader: &mut Reader) -> ParseResult<'static, Body> {
     //  let start = reader.state.clone();
     let line_terminators = optional_line_terminators(reader)?;
@@ -251,6 +528,21 @@ fn body(reader: &mut Reader) -> ParseResult<'static, Body> {
     })
 }
 
+/// Checks that a body is not followed by another one, as only one body is allowed
+/// for a request or a response.
+fn no_other_body(reader: &mut Reader) -> ParseResult<'static, ()> {
+    let start = reader.state.clone();
+    if let Ok(other) = body(reader) {
+        return Err(Error {
+            pos: other.space0.source_info.end,
+            recoverable: false,
+            inner: ParseError::MultipleBodies,
+        });
+    }
+    reader.state = start;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -262,6 +554,51 @@ mod tests {
         assert_eq!(hurl_file.entries.len(), 1);
     }
 
+    #[test]
+    fn test_hurl_file_settings() {
+        let mut reader = Reader::new(
+            "# Defaults\n[Settings]\ninsecure: true\nretry: 2\n\nGET http://localhost/a\nGET http://localhost/b\n",
+        );
+        let file = hurl_file(&mut reader).unwrap();
+        assert_eq!(file.entries.len(), 2);
+        let settings = file.settings.unwrap();
+        assert_eq!(settings.source_info, SourceInfo::new(2, 1, 2, 11));
+        assert_eq!(settings.options.len(), 2);
+        assert!(matches!(
+            settings.options[0],
+            EntryOption::Insecure(InsecureOption { value: true, .. })
+        ));
+        assert!(matches!(
+            settings.options[1],
+            EntryOption::Retry(RetryOption {
+                value: Retry::Finite(2),
+                ..
+            })
+        ));
+
+        let mut reader = Reader::new("GET http://localhost/a\n");
+        assert_eq!(hurl_file(&mut reader).unwrap().settings, None);
+    }
+
+    #[test]
+    fn test_hurl_file_misplaced_settings() {
+        for s in [
+            "GET http://localhost/a\n[Settings]\ninsecure: true\n",
+            "GET http://localhost/a\nHTTP 200\n[Settings]\ninsecure: true\n",
+            "GET http://localhost/a\n```\nHello\n```\n\n[Settings]\ninsecure: true\n",
+            "[Settings]\nretry: 2\nGET http://localhost/a\n[Settings]\ninsecure: true\n",
+        ] {
+            let mut reader = Reader::new(s);
+            let error = hurl_file(&mut reader).err().unwrap();
+            assert_eq!(error.inner, ParseError::MisplacedSettings);
+            assert!(!error.recoverable);
+        }
+
+        let mut reader = Reader::new("GET http://localhost/a\n[Settings]\n");
+        let error = hurl_file(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 2, column: 1 });
+    }
+
     #[test]
     fn test_entry() {
         let mut reader = Reader::new("GET http://google.fr");
@@ -298,6 +635,47 @@ mod tests {
         assert_eq!(reader.state.pos.line, 2);
     }
 
+    #[test]
+    fn test_entries_separated_by_comment() {
+        let mut reader = Reader::new("GET http://a\n# sep\nGET http://b");
+        let hurl_file = hurl_file(&mut reader).unwrap();
+        assert_eq!(hurl_file.entries.len(), 2);
+        assert_eq!(hurl_file.entries[0].request.url.to_string(), "http://a");
+        assert_eq!(hurl_file.entries[1].request.url.to_string(), "http://b");
+        // The comment line is attached to the following request.
+        let line_terminators = &hurl_file.entries[1].request.line_terminators;
+        assert_eq!(line_terminators.len(), 1);
+        assert_eq!(
+            line_terminators[0].comment.as_ref().unwrap().value,
+            " sep".to_string()
+        );
+    }
+
+    #[test]
+    fn test_entry_name() {
+        let mut reader = Reader::new("# @name login\nPOST http://localhost/login");
+        let e = entry(&mut reader).unwrap();
+        assert_eq!(e.name, Some("login".to_string()));
+        assert_eq!(e.request.method, Method::Post);
+        assert_eq!(e.request.line_terminators.len(), 1);
+
+        let mut reader = Reader::new("# Log in\n#  @name  get login page \nGET http://localhost");
+        let e = entry(&mut reader).unwrap();
+        assert_eq!(e.name, Some("get login page".to_string()));
+
+        let mut reader = Reader::new("# Log in\nGET http://localhost");
+        let e = entry(&mut reader).unwrap();
+        assert_eq!(e.name, None);
+
+        let mut reader = Reader::new("# @named\n# @name\nGET http://localhost");
+        let e = entry(&mut reader).unwrap();
+        assert_eq!(e.name, None);
+
+        let mut reader = Reader::new("GET http://localhost # @name foo");
+        let e = entry(&mut reader).unwrap();
+        assert_eq!(e.name, None);
+    }
+
     #[test]
     fn test_entry_with_response() {
         let mut reader = Reader::new("GET http://google.fr\nHTTP/1.1 200");
@@ -411,6 +789,8 @@ mod tests {
                     source_info: SourceInfo::new(2, 1, 2, 1),
                 },
                 value: Bytes::MultilineString(MultilineString::Text(Text {
+                    charset: None,
+                    content_type: None,
                     space: Whitespace {
                         value: "".to_string(),
                         source_info: SourceInfo::new(2, 4, 2, 4),
@@ -455,20 +835,24 @@ mod tests {
                 elements: vec![
                     JsonListElement {
                         space0: "".to_string(),
+                        comments: vec![],
                         value: JsonValue::Number("1".to_string()),
                         space1: "".to_string(),
                     },
                     JsonListElement {
                         space0: "".to_string(),
+                        comments: vec![],
                         value: JsonValue::Number("2".to_string()),
                         space1: "".to_string(),
                     },
                     JsonListElement {
                         space0: "".to_string(),
+                        comments: vec![],
                         value: JsonValue::Number("3".to_string()),
                         space1: "".to_string(),
                     },
                 ],
+                comments: vec![],
             })
         );
 
@@ -496,6 +880,268 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_request_multiple_bodies_error() {
+        let mut reader =
+            Reader::new("POST http://localhost/post\n{\"a\": 1}\n\n  {\"b\": 2}\nHTTP 200");
+        let error = hurl_file(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 4, column: 3 });
+        assert_eq!(error.inner, ParseError::MultipleBodies);
+        assert!(!error.recoverable);
+
+        let mut reader =
+            Reader::new("GET http://localhost/get\nHTTP 200\n```Hello```\n```World```\n");
+        let error = hurl_file(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 4, column: 1 });
+        assert_eq!(error.inner, ParseError::MultipleBodies);
+
+        // A body followed by a new entry is valid.
+        let mut reader =
+            Reader::new("POST http://localhost/post\n{\"a\": 1}\nGET http://localhost/get\n");
+        assert_eq!(hurl_file(&mut reader).unwrap().entries.len(), 2);
+    }
+
+    #[test]
+    fn test_hurl_file_urls() {
+        let mut reader = Reader::new(
+            "GET http://localhost/a\nHTTP 200\n\nPOST {{host}}/b\n\nDELETE http://localhost/c\n",
+        );
+        let hurl_file = hurl_file(&mut reader).unwrap();
+        let urls = hurl_file
+            .urls()
+            .iter()
+            .map(|url| url.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            urls,
+            vec![
+                "http://localhost/a".to_string(),
+                "{{host}}/b".to_string(),
+                "http://localhost/c".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hurl_file_entry_at() {
+        let mut reader = Reader::new(
+            "GET http://localhost/a\nHTTP 200\n\nPOST http://localhost/b\nx-foo: bar\nHTTP 201\n\n",
+        );
+        let hurl_file = hurl_file(&mut reader).unwrap();
+
+        let (index, entry) = hurl_file.entry_at(Pos { line: 1, column: 5 }).unwrap();
+        assert_eq!(index, 0);
+        assert_eq!(entry.request.url.to_string(), "http://localhost/a");
+
+        // The empty line between the two entries is part of the second entry.
+        for pos in [
+            Pos { line: 3, column: 1 },
+            Pos { line: 5, column: 3 },
+            Pos { line: 6, column: 6 },
+        ] {
+            let (index, entry) = hurl_file.entry_at(pos).unwrap();
+            assert_eq!(index, 1);
+            assert_eq!(entry.request.url.to_string(), "http://localhost/b");
+        }
+
+        // Trailing line terminators belong to no entry.
+        assert_eq!(hurl_file.entry_at(Pos { line: 7, column: 1 }), None);
+        assert_eq!(
+            hurl_file.entry_at(Pos {
+                line: 10,
+                column: 1
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_request_line_span() {
+        let mut reader =
+            Reader::new("\n# Get b\nPOST http://localhost/b # comment\nx-foo: bar\nx-bar: baz\n");
+        let post = request(&mut reader).unwrap();
+        assert_eq!(post.request_line_span(), SourceInfo::new(3, 1, 4, 1));
+        assert_eq!(post.source_info, SourceInfo::new(1, 1, 6, 1));
+
+        let mut reader = Reader::new("GET http://localhost/a");
+        let get = request(&mut reader).unwrap();
+        assert_eq!(get.request_line_span(), SourceInfo::new(1, 1, 1, 23));
+    }
+
+    #[test]
+    fn test_hurl_file_snippet_position() {
+        // A snippet from a host document, starting at line 42.
+        let mut reader = Reader::new_at(
+            "GET http://localhost\nHTTP 200\n[Asserts]\nstatus == \n",
+            Pos {
+                line: 42,
+                column: 1,
+            },
+        );
+        let error = hurl_file(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 45,
+                column: 11
+            }
+        );
+    }
+
+    #[test]
+    fn test_hurl_file_from_response() {
+        let mut reader = Reader::new(
+            "GET http://localhost/a\nHTTP 200\n\nPOST http://localhost/b\nfrom-response 1;\n",
+        );
+        let file = hurl_file(&mut reader).unwrap();
+        assert_eq!(
+            file.entries[1].request.body.as_ref().unwrap().value,
+            Bytes::FromResponse(1)
+        );
+
+        let mut reader =
+            Reader::new("GET http://localhost/a\n\nPOST http://localhost/b\nfrom-response 2;\n");
+        let error = hurl_file(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 4, column: 1 });
+        assert!(!error.recoverable);
+        assert_eq!(error.inner, ParseError::FromResponse { index: 2 });
+    }
+
+    #[test]
+    fn test_hurl_file_all_asserts() {
+        let mut reader = Reader::new(
+            "GET http://localhost/a\nHTTP 200\n[Asserts]\nstatus == 200\nbody contains \"a\"\n\nGET http://localhost/b\nHTTP 200\n[Asserts]\nheader \"x-b\" exists\n",
+        );
+        let hurl_file = hurl_file(&mut reader).unwrap();
+        let asserts = hurl_file
+            .all_asserts()
+            .iter()
+            .map(|(index, assert)| (*index, assert.query.source_info.start.line))
+            .collect::<Vec<_>>();
+        assert_eq!(asserts, vec![(0, 4), (0, 5), (1, 10)]);
+    }
+
+    #[test]
+    fn test_hurl_file_resilient() {
+        let mut reader = Reader::new(
+            "GET http://localhost/a\nHTTP 200\n\nGET http://localhost/b\nHTTP 200\n[Asserts]\nstatus ==\n\nGET http://localhost/c\n\nget http://localhost/d\nHTTP 200\n\nPOST http://localhost/e\n# end\n",
+        );
+        let (file, errors) = hurl_file_resilient(&mut reader);
+        let urls = file
+            .urls()
+            .iter()
+            .map(|url| url.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            urls,
+            vec![
+                "http://localhost/a",
+                "http://localhost/c",
+                "http://localhost/e"
+            ]
+        );
+        assert_eq!(
+            errors.iter().map(|e| e.pos.line).collect::<Vec<_>>(),
+            vec![7, 11]
+        );
+        assert_eq!(errors[0].inner, ParseError::PredicateValue);
+
+        // Without errors, the file is the one returned by `hurl_file`.
+        let s = "GET http://localhost/a\nHTTP 200\n# end\n";
+        let (file, errors) = hurl_file_resilient(&mut Reader::new(s));
+        assert!(errors.is_empty());
+        assert_eq!(file, hurl_file(&mut Reader::new(s)).unwrap());
+    }
+
+    #[test]
+    fn test_hurl_file_bom() {
+        let mut reader = Reader::new("\u{feff}GET http://localhost\nHTTP 200\n");
+        let file = hurl_file(&mut reader).unwrap();
+        assert_eq!(file.entries.len(), 1);
+        assert_eq!(file.entries[0].request.method, Method::Get);
+        assert_eq!(
+            file.entries[0].request.source_info,
+            SourceInfo::new(1, 1, 2, 1)
+        );
+        assert_eq!(
+            file.entries[0].request.url.source_info,
+            SourceInfo::new(1, 5, 1, 21)
+        );
+
+        let mut reader = Reader::new("GET http://localhost\n\u{feff}GET http://localhost\n");
+        let error = hurl_file(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 2, column: 1 });
+    }
+
+    #[test]
+    fn test_hurl_file_find_by_url() {
+        let mut reader = Reader::new(
+            "GET http://localhost/users\n\nGET http://localhost/orders\n\nGET {{host}}/users/1\n\nGET {{host}}/orders\n",
+        );
+        let hurl_file = hurl_file(&mut reader).unwrap();
+        let urls = hurl_file
+            .find_by_url("/users")
+            .iter()
+            .map(|entry| entry.request.url.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(urls, vec!["http://localhost/users", "{{host}}/users/1"]);
+
+        assert_eq!(hurl_file.find_by_url("{{host}}").len(), 2);
+        assert!(hurl_file.find_by_url("/products").is_empty());
+    }
+
+    #[test]
+    fn test_request_duplicate_headers() {
+        let mut reader = Reader::new(
+            "GET http://localhost\nContent-Type: text/plain\nAccept: text/plain\nAccept: text/html\ncontent-type: text/html\n",
+        );
+        let req = request(&mut reader).unwrap();
+        assert_eq!(req.headers.len(), 4);
+        assert_eq!(
+            reader.warnings,
+            vec![Error {
+                pos: Pos { line: 5, column: 1 },
+                recoverable: true,
+                inner: ParseError::DuplicateHeader {
+                    name: "content-type".to_string()
+                },
+            }]
+        );
+
+        let mut reader =
+            Reader::new("HTTP 200\nSet-Cookie: a=1\nSet-Cookie: b=2\nLocation: /a\nLocation: /b\n");
+        let resp = response(&mut reader).unwrap();
+        assert_eq!(resp.headers.len(), 4);
+        assert_eq!(reader.warnings.len(), 1);
+        assert_eq!(reader.warnings[0].pos, Pos { line: 5, column: 1 });
+    }
+
+    #[test]
+    fn test_request_header_empty_value() {
+        let mut reader = Reader::new("GET http://localhost\nX-Empty:\nX-Other: 1\n");
+        let req = request(&mut reader).unwrap();
+        assert_eq!(req.headers.len(), 2);
+        assert_eq!(req.headers[0].key.value, "X-Empty");
+        assert!(req.headers[0].value.elements.is_empty());
+        assert_eq!(req.headers[1].key.value, "X-Other");
+
+        let mut reader = Reader::new("GET http://localhost\nX-NoColon\n");
+        assert!(hurl_file(&mut reader).is_err());
+    }
+
+    #[test]
+    fn test_request_url_fragment() {
+        let mut reader = Reader::new("GET http://x/page#frag\n");
+        let req = request(&mut reader).unwrap();
+        assert_eq!(req.url.to_string(), "http://x/page#frag");
+        assert_eq!(req.line_terminator0.comment, None);
+
+        let mut reader = Reader::new("GET http://x/page #frag\n");
+        let req = request(&mut reader).unwrap();
+        assert_eq!(req.url.to_string(), "http://x/page");
+        assert_eq!(req.line_terminator0.comment.unwrap().value, "frag");
+    }
+
     #[test]
     fn test_request_error() {
         let mut reader = Reader::new("xxx");
@@ -513,6 +1159,63 @@ mod tests {
         assert_eq!(r.status.value, StatusValue::Specific(200));
     }
 
+    #[test]
+    fn test_response_captures_and_asserts() {
+        let mut reader = Reader::new(
+            "HTTP 200\n[Asserts]\nstatus == 200\nvariable \"id\" == 1\n[Captures]\nid: jsonpath \"$.id\"\n",
+        );
+        let r = response(&mut reader).unwrap();
+
+        // Sections are kept in textual order.
+        let names = r.sections.iter().map(|s| s.name()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["Asserts", "Captures"]);
+
+        let captures = r.captures();
+        assert_eq!(captures.len(), 1);
+        assert_eq!(captures[0].name.value, "id");
+
+        let asserts = r.asserts();
+        assert_eq!(asserts.len(), 2);
+        assert_eq!(asserts[0].query.value, QueryValue::Status {});
+    }
+
+    #[test]
+    fn test_response_any_status_with_headers() {
+        let mut reader = Reader::new("HTTP/1.1 *\nContent-Type: application/json");
+        let r = response(&mut reader).unwrap();
+        assert_eq!(r.version.value, VersionValue::Version11);
+        assert_eq!(r.status.value, StatusValue::Any);
+        assert_eq!(r.status.source_info, SourceInfo::new(1, 10, 1, 11));
+        assert_eq!(r.headers.len(), 1);
+        assert_eq!(r.headers[0].key.value, "Content-Type");
+        assert_eq!(r.headers[0].value.to_string(), "application/json");
+        assert!(r.body.is_none());
+        assert!(reader.is_eof());
+    }
+
+    #[test]
+    fn test_request_template_body() {
+        let mut reader = Reader::new("POST http://localhost/users\n{{payload}}\n");
+        let r = request(&mut reader).unwrap();
+        match r.body.unwrap().value {
+            Bytes::Template(template) => assert_eq!(template.to_string(), "{{payload}}"),
+            _ => panic!("expecting a template body"),
+        }
+        assert!(reader.is_eof());
+    }
+
+    #[test]
+    fn test_response_base64_body() {
+        let mut reader = Reader::new("HTTP/1.1 200\nbase64,SGVsbG8gV29ybGQh;\n");
+        let r = response(&mut reader).unwrap();
+        assert_eq!(r.status.value, StatusValue::Specific(200));
+        match r.body.unwrap().value {
+            Bytes::Base64(Base64 { value, .. }) => assert_eq!(value, b"Hello World!".to_vec()),
+            _ => panic!("expecting a base64 body"),
+        }
+        assert!(reader.is_eof());
+    }
+
     #[test]
     fn test_method() {
         let mut reader = Reader::new("xxx ");
@@ -528,6 +1231,95 @@ mod tests {
         let mut reader = Reader::new("GET ");
         assert_eq!(Ok(Method::Get), method(&mut reader));
         assert_eq!(reader.state.cursor, 3);
+
+        let mut reader = Reader::new("MKCALENDAR ");
+        assert_eq!(
+            Ok(Method::Custom("MKCALENDAR".to_string())),
+            method(&mut reader)
+        );
+        assert_eq!(reader.state.cursor, 10);
+
+        for s in ["Query ", "QUERY2 ", " "] {
+            let mut reader = Reader::new(s);
+            let error = method(&mut reader).err().unwrap();
+            assert_eq!(error.pos, Pos { line: 1, column: 1 });
+            assert_eq!(reader.state.cursor, 0);
+        }
+
+        let s = "X".repeat(100_000);
+        let mut reader = Reader::new(&s);
+        let error = method(&mut reader).err().unwrap();
+        assert_eq!(
+            error.inner,
+            ParseError::Method {
+                name: "X".repeat(MAX_METHOD_LEN),
+                suggestion: None,
+            }
+        );
+
+        let mut reader = Reader::new("POSt ");
+        let error = method(&mut reader).err().unwrap();
+        assert_eq!(
+            error.inner,
+            ParseError::Method {
+                name: "POSt".to_string(),
+                suggestion: Some("POST".to_string()),
+            }
+        );
+
+        let mut reader = Reader::new("Get ");
+        let error = method(&mut reader).err().unwrap();
+        assert_eq!(
+            error.inner,
+            ParseError::Method {
+                name: "Get".to_string(),
+                suggestion: Some("GET".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_method_case_insensitive() {
+        for s in ["get ", "Get ", "gEt "] {
+            let mut reader = Reader::new(s);
+            reader.case_insensitive_methods = true;
+            assert_eq!(Ok(Method::Get), method(&mut reader));
+            assert_eq!(reader.state.cursor, 3);
+            assert_eq!(reader.state.pos, Pos { line: 1, column: 4 });
+        }
+
+        // Only standard methods are normalized, custom methods must still be uppercase.
+        let mut reader = Reader::new("mkcalendar ");
+        reader.case_insensitive_methods = true;
+        let error = method(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 1 });
+        assert_eq!(reader.state.cursor, 0);
+
+        // Through the public API
+        let mut reader = Reader::new("GET http://localhost\ndelete http://localhost\n");
+        reader.case_insensitive_methods = true;
+        let file = crate::parser::parse_hurl_file_with_reader(&mut reader).unwrap();
+        assert_eq!(file.entries[1].request.method, Method::Delete);
+
+        let mut reader = Reader::new("GET http://localhost\n  post http://localhost");
+        let error = hurl_file(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 2, column: 3 });
+        assert_eq!(
+            error.inner,
+            ParseError::Method {
+                name: "post".to_string(),
+                suggestion: Some("POST".to_string()),
+            }
+        );
+
+        let mut reader = Reader::new("GET http://localhost\n  post http://localhost");
+        reader.case_insensitive_methods = true;
+        let file = hurl_file(&mut reader).unwrap();
+        assert_eq!(file.entries[1].request.method, Method::Post);
+        assert_eq!(
+            file.entries[1].request.space1.source_info,
+            SourceInfo::new(2, 7, 2, 8)
+        );
     }
 
     #[test]
@@ -540,6 +1332,34 @@ mod tests {
         assert_eq!(error.pos, Pos { line: 1, column: 1 });
     }
 
+    #[test]
+    fn test_version_lenient() {
+        let mut reader = Reader::with_mode("http/1.1 200", ParseMode::Lenient);
+        let r = response(&mut reader).unwrap();
+        assert_eq!(r.version.value, VersionValue::Version11);
+        assert_eq!(r.version.source_info, SourceInfo::new(1, 1, 1, 9));
+        assert_eq!(r.status.value, StatusValue::Specific(200));
+        assert_eq!(
+            reader.warnings,
+            vec![Error {
+                pos: Pos { line: 1, column: 1 },
+                recoverable: true,
+                inner: ParseError::KeywordCase {
+                    value: "HTTP".to_string()
+                },
+            }]
+        );
+
+        let mut reader = Reader::new("http/1.1 200");
+        let error = response(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 1 });
+        assert!(reader.warnings.is_empty());
+
+        let mut reader = Reader::with_mode("HTTP/1.1 200", ParseMode::Lenient);
+        assert!(response(&mut reader).is_ok());
+        assert!(reader.warnings.is_empty());
+    }
+
     #[test]
     fn test_status() {
         let mut reader = Reader::new("*");
@@ -550,11 +1370,54 @@ mod tests {
         let s = status(&mut reader).unwrap();
         assert_eq!(s.value, StatusValue::Specific(200));
 
+        let mut reader = Reader::new("2xx");
+        let s = status(&mut reader).unwrap();
+        assert_eq!(s.value, StatusValue::Class(2));
+        assert_eq!(s.source_info, SourceInfo::new(1, 1, 1, 4));
+
+        let mut reader = Reader::new("5xx");
+        let s = status(&mut reader).unwrap();
+        assert_eq!(s.value, StatusValue::Class(5));
+
+        // A digit not followed by `xx` is a specific status.
+        let mut reader = Reader::new("2x");
+        let s = status(&mut reader).unwrap();
+        assert_eq!(s.value, StatusValue::Specific(2));
+        assert_eq!(reader.state.cursor, 1);
+
         let mut reader = Reader::new("xxx");
         let result = status(&mut reader);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_status_range() {
+        let mut reader = Reader::new("200-204");
+        let s = status(&mut reader).unwrap();
+        assert_eq!(s.value, StatusValue::Range(200, 204));
+        assert_eq!(s.source_info, SourceInfo::new(1, 1, 1, 8));
+
+        let mut reader = Reader::new("204-204");
+        let s = status(&mut reader).unwrap();
+        assert_eq!(s.value, StatusValue::Range(204, 204));
+
+        let mut reader = Reader::new("204-200");
+        let error = status(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 1 });
+        assert_eq!(
+            error.inner,
+            ParseError::StatusRange {
+                start: 204,
+                end: 200
+            }
+        );
+
+        let mut reader = Reader::new("200-");
+        let error = status(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 5 });
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_body_json() {
         let mut reader = Reader::new("[1,2,3] ");
@@ -567,20 +1430,24 @@ mod tests {
                 elements: vec![
                     JsonListElement {
                         space0: "".to_string(),
+                        comments: vec![],
                         value: JsonValue::Number("1".to_string()),
                         space1: "".to_string(),
                     },
                     JsonListElement {
                         space0: "".to_string(),
+                        comments: vec![],
                         value: JsonValue::Number("2".to_string()),
                         space1: "".to_string(),
                     },
                     JsonListElement {
                         space0: "".to_string(),
+                        comments: vec![],
                         value: JsonValue::Number("3".to_string()),
                         space1: "".to_string(),
                     },
                 ],
+                comments: vec![],
             })
         );
         assert_eq!(reader.state.cursor, 8);
@@ -593,6 +1460,7 @@ mod tests {
             Bytes::Json(JsonValue::Object {
                 space0: "".to_string(),
                 elements: vec![],
+                comments: vec![],
             })
         );
         assert_eq!(reader.state.cursor, 2);
@@ -605,6 +1473,7 @@ mod tests {
             Bytes::Json(JsonValue::Object {
                 space0: "".to_string(),
                 elements: vec![],
+                comments: vec![],
             })
         );
         assert_eq!(reader.state.cursor, 24);
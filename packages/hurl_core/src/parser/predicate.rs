@@ -18,9 +18,11 @@
 use crate::ast::*;
 use crate::parser::combinators::*;
 use crate::parser::error::*;
+use crate::parser::json::{list_value, object_value};
 use crate::parser::predicate_value::predicate_value;
 use crate::parser::primitives::*;
 use crate::parser::reader::Reader;
+use crate::parser::string::{quoted_template, quoted_template_with_wildcards};
 use crate::parser::ParseResult;
 
 pub fn predicate(reader: &mut Reader) -> ParseResult<'static, Predicate> {
@@ -70,6 +72,7 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<'static, PredicateFu
     let start = reader.state.clone();
     match choice(
         &[
+            equal_ignore_order_predicate,
             equal_predicate,
             not_equal_predicate,
             greater_or_equal_predicate,
@@ -77,18 +80,32 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<'static, PredicateFu
             less_or_equal_predicate,
             less_predicate,
             count_equal_predicate,
+            count_greater_or_equal_predicate,
+            count_greater_predicate,
+            count_less_or_equal_predicate,
+            count_less_predicate,
+            length_equal_predicate,
             start_with_predicate,
             end_with_predicate,
             contain_predicate,
             include_predicate,
+            match_schema_predicate,
+            match_all_predicate,
+            match_any_predicate,
+            match_template_predicate,
             match_predicate,
+            close_to_predicate,
             integer_predicate,
             float_predicate,
+            number_predicate,
             boolean_predicate,
             string_predicate,
             collection_predicate,
+            sorted_descending_predicate,
+            sorted_predicate,
             exist_predicate,
             is_empty_predicate,
+            is_valid_json_predicate,
         ],
         reader,
     ) {
@@ -105,7 +122,10 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<'static, PredicateFu
 
 impl PredicateValue {
     pub fn is_number(&self) -> bool {
-        matches!(self, PredicateValue::Integer(_) | PredicateValue::Float(_))
+        matches!(
+            self,
+            PredicateValue::Integer(_) | PredicateValue::Float(_) | PredicateValue::Size(_)
+        )
     }
     pub fn is_string(&self) -> bool {
         matches!(self, PredicateValue::String(_))
@@ -135,6 +155,24 @@ fn equal_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncVal
     })
 }
 
+fn equal_ignore_order_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("equalsIgnoreOrder", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.state.clone();
+    let value = list_value(reader).map_err(|e| {
+        if e.recoverable {
+            Error {
+                pos: save.pos,
+                recoverable: false,
+                inner: ParseError::PredicateValue {},
+            }
+        } else {
+            e
+        }
+    })?;
+    Ok(PredicateFuncValue::EqualIgnoreOrder { space0, value })
+}
+
 fn not_equal_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
     let operator = try_literals("notEquals", "!=", reader)? == "!=";
     let space0 = if operator {
@@ -247,7 +285,43 @@ fn less_or_equal_predicate(reader: &mut Reader) -> ParseResult<'static, Predicat
 }
 
 fn count_equal_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
-    try_literal("countEquals", reader)?;
+    let (space0, value) = count_predicate("countEquals", reader)?;
+    Ok(PredicateFuncValue::CountEqual { space0, value })
+}
+
+fn count_greater_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    let (space0, value) = count_predicate("countGreaterThan", reader)?;
+    Ok(PredicateFuncValue::CountGreaterThan { space0, value })
+}
+
+fn count_greater_or_equal_predicate(
+    reader: &mut Reader,
+) -> ParseResult<'static, PredicateFuncValue> {
+    let (space0, value) = count_predicate("countGreaterThanOrEquals", reader)?;
+    Ok(PredicateFuncValue::CountGreaterThanOrEqual { space0, value })
+}
+
+fn count_less_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    let (space0, value) = count_predicate("countLessThan", reader)?;
+    Ok(PredicateFuncValue::CountLessThan { space0, value })
+}
+
+fn count_less_or_equal_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    let (space0, value) = count_predicate("countLessThanOrEquals", reader)?;
+    Ok(PredicateFuncValue::CountLessThanOrEqual { space0, value })
+}
+
+fn length_equal_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    let (space0, value) = count_predicate("lengthEquals", reader)?;
+    Ok(PredicateFuncValue::LengthEqual { space0, value })
+}
+
+/// Parses a count predicate named `name`, whose value must be an integer.
+fn count_predicate(
+    name: &str,
+    reader: &mut Reader,
+) -> ParseResult<'static, (Whitespace, PredicateValue)> {
+    try_literal(name, reader)?;
     let space0 = one_or_more_spaces(reader)?;
     let save = reader.state.clone();
     let value = predicate_value(reader)?;
@@ -258,7 +332,7 @@ fn count_equal_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateF
             inner: ParseError::PredicateValue {},
         });
     }
-    Ok(PredicateFuncValue::CountEqual { space0, value })
+    Ok((space0, value))
 }
 
 fn start_with_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
@@ -328,6 +402,145 @@ fn match_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncVal
     Ok(PredicateFuncValue::Match { space0, value })
 }
 
+fn match_template_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("matchesTemplate", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.state.clone();
+    let value = quoted_template_with_wildcards(reader).map_err(|e| {
+        if e.recoverable {
+            Error {
+                pos: save.pos,
+                recoverable: false,
+                inner: ParseError::PredicateValue {},
+            }
+        } else {
+            e
+        }
+    })?;
+    Ok(PredicateFuncValue::MatchTemplate { space0, value })
+}
+
+fn match_schema_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("matchesSchema", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.state.clone();
+    let value = choice(&[schema_file, schema_inline], reader).map_err(|e| {
+        if e.recoverable {
+            Error {
+                pos: save.pos,
+                recoverable: false,
+                inner: ParseError::PredicateValue {},
+            }
+        } else {
+            e
+        }
+    })?;
+    Ok(PredicateFuncValue::MatchSchema { space0, value })
+}
+
+fn schema_file(reader: &mut Reader) -> ParseResult<'static, SchemaSource> {
+    try_literal("file", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let filename = quoted_template(reader)?;
+    Ok(SchemaSource::File { space0, filename })
+}
+
+fn schema_inline(reader: &mut Reader) -> ParseResult<'static, SchemaSource> {
+    object_value(reader).map(SchemaSource::Inline)
+}
+
+fn match_all_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("matchesAll", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let value = regex_list(reader)?;
+    Ok(PredicateFuncValue::MatchAll { space0, value })
+}
+
+fn match_any_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("matchesAny", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let value = regex_list(reader)?;
+    Ok(PredicateFuncValue::MatchAny { space0, value })
+}
+
+/// Parses a non empty JSON list of regex patterns, like `["^a", "z$"]`.
+///
+/// Patterns without variables are validated here, templated ones are only compiled when
+/// the predicate is evaluated.
+fn regex_list(reader: &mut Reader) -> ParseResult<'static, JsonValue> {
+    let save = reader.state.clone();
+    let predicate_value_error = Error {
+        pos: save.pos.clone(),
+        recoverable: false,
+        inner: ParseError::PredicateValue {},
+    };
+    let value = list_value(reader).map_err(|e| {
+        if e.recoverable {
+            predicate_value_error.clone()
+        } else {
+            e
+        }
+    })?;
+    let elements = match &value {
+        JsonValue::List { elements, .. } if !elements.is_empty() => elements,
+        _ => return Err(predicate_value_error),
+    };
+    for element in elements {
+        let template = match &element.value {
+            JsonValue::String(template) => template,
+            _ => return Err(predicate_value_error),
+        };
+        let is_literal = template
+            .elements
+            .iter()
+            .all(|e| matches!(e, TemplateElement::String { .. }));
+        if !is_literal {
+            continue;
+        }
+        if let Err(e) = regex::Regex::new(&template.to_string()) {
+            return Err(Error {
+                pos: template.source_info.start.clone(),
+                recoverable: false,
+                inner: ParseError::RegexExpr {
+                    message: regex_error_message(e),
+                },
+            });
+        }
+    }
+    Ok(value)
+}
+
+fn close_to_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("isCloseTo", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let value = number_value(reader)?;
+    let space1 = one_or_more_spaces(reader)?;
+    literal("within", reader)?;
+    let space2 = one_or_more_spaces(reader)?;
+    let epsilon = number_value(reader)?;
+    Ok(PredicateFuncValue::IsCloseTo {
+        space0,
+        value,
+        space1,
+        space2,
+        epsilon,
+    })
+}
+
+/// Parses a predicate value which must be an integer or a float.
+fn number_value(reader: &mut Reader) -> ParseResult<'static, PredicateValue> {
+    let save = reader.state.clone();
+    let value = predicate_value(reader)?;
+    if !value.is_number() {
+        return Err(Error {
+            pos: save.pos,
+            recoverable: false,
+            inner: ParseError::PredicateValue {},
+        });
+    }
+    Ok(value)
+}
+
 fn integer_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
     try_literal("isInteger", reader)?;
     Ok(PredicateFuncValue::IsInteger {})
@@ -338,6 +551,11 @@ fn float_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncVal
     Ok(PredicateFuncValue::IsFloat {})
 }
 
+fn number_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("isNumber", reader)?;
+    Ok(PredicateFuncValue::IsNumber {})
+}
+
 fn boolean_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
     try_literal("isBoolean", reader)?;
     Ok(PredicateFuncValue::IsBoolean {})
@@ -353,6 +571,16 @@ fn collection_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFu
     Ok(PredicateFuncValue::IsCollection {})
 }
 
+fn sorted_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("isSorted", reader)?;
+    Ok(PredicateFuncValue::IsSorted {})
+}
+
+fn sorted_descending_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("isSortedDescending", reader)?;
+    Ok(PredicateFuncValue::IsSortedDescending {})
+}
+
 fn exist_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
     try_literal("exists", reader)?;
     Ok(PredicateFuncValue::Exist {})
@@ -363,6 +591,11 @@ fn is_empty_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFunc
     Ok(PredicateFuncValue::IsEmpty {})
 }
 
+fn is_valid_json_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("isValidJson", reader)?;
+    Ok(PredicateFuncValue::IsValidJson {})
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -447,6 +680,111 @@ mod tests {
         assert_eq!(error.inner, ParseError::Predicate {});
     }
 
+    #[test]
+    fn test_sorted_predicate() {
+        let mut reader = Reader::new("isSorted");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::IsSorted {}
+        );
+        assert_eq!(reader.state.cursor, 8);
+
+        let mut reader = Reader::new("isSortedDescending");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::IsSortedDescending {}
+        );
+        assert_eq!(reader.state.cursor, 18);
+    }
+
+    #[test]
+    fn test_match_template_predicate() {
+        let mut reader = Reader::new(r#"matchesTemplate "id-{{*}}.{{name}}""#);
+        let value = match predicate_func_value(&mut reader).unwrap() {
+            PredicateFuncValue::MatchTemplate { value, .. } => value,
+            _ => panic!("expecting a matchesTemplate predicate"),
+        };
+        assert_eq!(value.elements.len(), 4);
+        assert_eq!(
+            value.elements[1],
+            TemplateElement::String {
+                value: TEMPLATE_WILDCARD.to_string(),
+                encoded: TEMPLATE_WILDCARD.to_string(),
+            }
+        );
+        assert!(matches!(value.elements[3], TemplateElement::Expression(_)));
+        assert_eq!(value.to_string(), "id-{{*}}.{{name}}");
+        assert_eq!(value.source_info, SourceInfo::new(1, 17, 1, 36));
+
+        let mut reader = Reader::new("matchesTemplate id-{{*}}");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 17
+            }
+        );
+        assert_eq!(error.inner, ParseError::PredicateValue {});
+    }
+
+    #[test]
+    fn test_is_valid_json_predicate() {
+        let mut reader = Reader::new("isValidJson");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::IsValidJson {}
+        );
+        assert_eq!(reader.state.cursor, 11);
+    }
+
+    #[test]
+    fn test_predicate_operator_and_word() {
+        // Symbols and words parse to the same predicate, only the `operator` flag (used to
+        // format the predicate back) differs.
+        fn comparison(s: &str) -> (&'static str, PredicateValue, bool) {
+            let mut reader = Reader::new(s);
+            match predicate_func_value(&mut reader).unwrap() {
+                PredicateFuncValue::Equal {
+                    value, operator, ..
+                } => ("equal", value, operator),
+                PredicateFuncValue::NotEqual {
+                    value, operator, ..
+                } => ("not-equal", value, operator),
+                PredicateFuncValue::GreaterThan {
+                    value, operator, ..
+                } => ("greater", value, operator),
+                PredicateFuncValue::GreaterThanOrEqual {
+                    value, operator, ..
+                } => ("greater-or-equal", value, operator),
+                PredicateFuncValue::LessThan {
+                    value, operator, ..
+                } => ("less", value, operator),
+                PredicateFuncValue::LessThanOrEqual {
+                    value, operator, ..
+                } => ("less-or-equal", value, operator),
+                _ => panic!("expecting a comparison predicate"),
+            }
+        }
+
+        for (symbol, word) in [
+            ("== 3", "equals 3"),
+            ("!= 3", "notEquals 3"),
+            ("> 3", "greaterThan 3"),
+            (">= 3", "greaterThanOrEquals 3"),
+            ("< 3", "lessThan 3"),
+            ("<= 3", "lessThanOrEquals 3"),
+        ] {
+            let (symbol_name, symbol_value, symbol_operator) = comparison(symbol);
+            let (word_name, word_value, word_operator) = comparison(word);
+            assert_eq!(symbol_name, word_name);
+            assert_eq!(symbol_value, PredicateValue::Integer(3));
+            assert_eq!(symbol_value, word_value);
+            assert!(symbol_operator);
+            assert!(!word_operator);
+        }
+    }
+
     #[test]
     fn test_equal_predicate() {
         let mut reader = Reader::new("equals  true");
@@ -526,6 +864,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_negative_number_predicate() {
+        // The sign is part of the number.
+        let mut reader = Reader::new("== -5");
+        assert_eq!(
+            equal_predicate(&mut reader).unwrap(),
+            PredicateFuncValue::Equal {
+                value: PredicateValue::Integer(-5),
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(1, 3, 1, 4),
+                },
+                operator: true,
+            },
+        );
+        assert_eq!(reader.state.cursor, 5);
+
+        let mut reader = Reader::new("> -10");
+        assert_eq!(
+            greater_predicate(&mut reader).unwrap(),
+            PredicateFuncValue::GreaterThan {
+                value: PredicateValue::Integer(-10),
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(1, 2, 1, 3),
+                },
+                operator: true,
+            },
+        );
+
+        let mut reader = Reader::new("<-0.5");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::LessThan {
+                value: PredicateValue::Float(Float {
+                    value: -0.5,
+                    encoded: "-0.5".to_string(),
+                }),
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(1, 2, 1, 2),
+                },
+                operator: true,
+            },
+        );
+    }
+
+    #[test]
+    fn test_equal_multiline_predicate() {
+        let mut reader = Reader::new("== ```\nexpected multi\nline\n```");
+        let value = equal_predicate(&mut reader).unwrap();
+        let value = match value {
+            PredicateFuncValue::Equal {
+                value: PredicateValue::MultilineString(value),
+                operator: true,
+                ..
+            } => value,
+            _ => panic!("expecting a multiline expected value"),
+        };
+        assert_eq!(value.lang(), "");
+        assert_eq!(value.value().to_string(), "expected multi\nline\n");
+        assert!(reader.is_eof());
+    }
+
     #[test]
     fn test_equal_expression_predicate() {
         let mut reader = Reader::new("equals {{count}}");
@@ -555,6 +957,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_close_to_predicate() {
+        let mut reader = Reader::new("isCloseTo 2.5 within 1");
+        assert_eq!(
+            close_to_predicate(&mut reader).unwrap(),
+            PredicateFuncValue::IsCloseTo {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(1, 10, 1, 11),
+                },
+                value: PredicateValue::Float(Float {
+                    value: 2.5,
+                    encoded: "2.5".to_string(),
+                }),
+                space1: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(1, 14, 1, 15),
+                },
+                space2: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(1, 21, 1, 22),
+                },
+                epsilon: PredicateValue::Integer(1),
+            }
+        );
+
+        let mut reader = Reader::new("isCloseTo \"2.5\" within 0.1");
+        let error = close_to_predicate(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 11
+            }
+        );
+        assert!(!error.recoverable);
+        assert_eq!(error.inner, ParseError::PredicateValue {});
+
+        let mut reader = Reader::new("isCloseTo 2.5 0.1");
+        let error = close_to_predicate(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 15
+            }
+        );
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "within".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_count_equal_predicate() {
         let mut reader = Reader::new("countEquals 2");
@@ -582,6 +1039,76 @@ mod tests {
         assert_eq!(error.inner, ParseError::PredicateValue {});
     }
 
+    #[test]
+    fn test_length_equal_predicate() {
+        let mut reader = Reader::new("lengthEquals 3");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::LengthEqual {
+                value: PredicateValue::Integer(3),
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(1, 13, 1, 14),
+                },
+            }
+        );
+
+        let mut reader = Reader::new("lengthEquals \"abc\"");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 14
+            }
+        );
+        assert!(!error.recoverable);
+        assert_eq!(error.inner, ParseError::PredicateValue {});
+    }
+
+    #[test]
+    fn test_count_comparison_predicates() {
+        let mut reader = Reader::new("countGreaterThan 3");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::CountGreaterThan {
+                value: PredicateValue::Integer(3),
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(1, 17, 1, 18),
+                },
+            }
+        );
+
+        let mut reader = Reader::new("countGreaterThanOrEquals 3");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap().name(),
+            "countGreaterThanOrEquals"
+        );
+        let mut reader = Reader::new("countLessThan 3");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap().name(),
+            "countLessThan"
+        );
+        let mut reader = Reader::new("countLessThanOrEquals 3");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap().name(),
+            "countLessThanOrEquals"
+        );
+
+        let mut reader = Reader::new("countLessThan \"3\"");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 15,
+            }
+        );
+        assert!(!error.recoverable);
+        assert_eq!(error.inner, ParseError::PredicateValue {});
+    }
+
     #[test]
     fn test_start_with_predicate() {
         let mut reader = Reader::new("startsWith 2");
@@ -596,4 +1123,132 @@ mod tests {
         assert!(!error.recoverable);
         assert_eq!(error.inner, ParseError::PredicateValue {});
     }
+
+    #[test]
+    fn test_match_schema_predicate() {
+        let mut reader = Reader::new(r#"matchesSchema {"type": "object", "required": ["id"]}"#);
+        let value = match_schema_predicate(&mut reader).unwrap();
+        let (space0, value) = match value {
+            PredicateFuncValue::MatchSchema { space0, value } => (space0, value),
+            _ => panic!("expecting a matchesSchema predicate"),
+        };
+        assert_eq!(
+            space0,
+            Whitespace {
+                value: String::from(" "),
+                source_info: SourceInfo::new(1, 14, 1, 15),
+            }
+        );
+        match value {
+            SchemaSource::Inline(value) => {
+                assert_eq!(value.encoded(), r#"{"type": "object", "required": ["id"]}"#);
+            }
+            _ => panic!("expecting an inline schema"),
+        }
+
+        let mut reader = Reader::new(r#"matchesSchema file "schema.json""#);
+        let value = match_schema_predicate(&mut reader).unwrap();
+        match value {
+            PredicateFuncValue::MatchSchema {
+                value: SchemaSource::File { space0, filename },
+                ..
+            } => {
+                assert_eq!(space0.source_info, SourceInfo::new(1, 19, 1, 20));
+                assert_eq!(filename.to_string(), "schema.json");
+                assert_eq!(filename.source_info, SourceInfo::new(1, 20, 1, 33));
+            }
+            _ => panic!("expecting a matchesSchema predicate with a schema file"),
+        }
+
+        // `matches` is still parsed as the regex predicate
+        let mut reader = Reader::new("matches \"^a\"");
+        assert_eq!(predicate_func_value(&mut reader).unwrap().name(), "matches");
+    }
+
+    #[test]
+    fn test_match_schema_predicate_error() {
+        let mut reader = Reader::new("matchesSchema \"schema.json\"");
+        let error = match_schema_predicate(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 15,
+            }
+        );
+        assert!(!error.recoverable);
+        assert_eq!(error.inner, ParseError::PredicateValue {});
+    }
+
+    #[test]
+    fn test_equal_ignore_order_predicate() {
+        let mut reader = Reader::new("equalsIgnoreOrder [1, 2, 3]");
+        let value = match predicate_func_value(&mut reader).unwrap() {
+            PredicateFuncValue::EqualIgnoreOrder { value, .. } => value,
+            _ => panic!("expecting an equalsIgnoreOrder predicate"),
+        };
+        assert_eq!(value.encoded(), "[1, 2, 3]");
+
+        let mut reader = Reader::new("equalsIgnoreOrder 1");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 19,
+            }
+        );
+        assert!(!error.recoverable);
+        assert_eq!(error.inner, ParseError::PredicateValue {});
+    }
+
+    #[test]
+    fn test_match_all_predicate() {
+        let mut reader = Reader::new(r#"matchesAll ["^a", "{{suffix}}$"]"#);
+        let value = match_all_predicate(&mut reader).unwrap();
+        let value = match value {
+            PredicateFuncValue::MatchAll { value, .. } => value,
+            _ => panic!("expecting a matchesAll predicate"),
+        };
+        assert_eq!(value.encoded(), r#"["^a", "{{suffix}}$"]"#);
+
+        let mut reader = Reader::new(r#"matchesAny ["^a"]"#);
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap().name(),
+            "matchesAny"
+        );
+    }
+
+    #[test]
+    fn test_match_all_predicate_error() {
+        let mut reader = Reader::new(r#"matchesAll ["^a", "x{a}"]"#);
+        let error = match_all_predicate(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 20,
+            }
+        );
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.inner,
+            ParseError::RegexExpr {
+                message: "repetition quantifier expects a valid decimal".to_string()
+            }
+        );
+
+        for s in ["matchesAll []", "matchesAll [1]", "matchesAny \"^a\""] {
+            let mut reader = Reader::new(s);
+            let error = predicate_func_value(&mut reader).err().unwrap();
+            assert_eq!(
+                error.pos,
+                Pos {
+                    line: 1,
+                    column: 12,
+                }
+            );
+            assert_eq!(error.inner, ParseError::PredicateValue {});
+        }
+    }
 }
@@ -38,6 +38,10 @@ pub fn predicate_value(reader: &mut Reader) -> ParseResult<'static, PredicateVal
                 Ok(value) => Ok(PredicateValue::Float(value)),
                 Err(e) => Err(e),
             },
+            |p1| match size(p1) {
+                Ok(value) => Ok(PredicateValue::Size(value)),
+                Err(e) => Err(e),
+            },
             |p1| match integer(p1) {
                 Ok(value) => Ok(PredicateValue::Integer(value)),
                 Err(e) => Err(e),
@@ -80,6 +84,34 @@ pub fn predicate_value(reader: &mut Reader) -> ParseResult<'static, PredicateVal
     })
 }
 
+/// Parses a number of bytes with a size unit, like `512KB` or `1MB`.
+fn size(reader: &mut Reader) -> ParseResult<'static, Size> {
+    let value = natural(reader)?;
+    let start = reader.state.clone();
+    let name = reader.read_while(|c| c.is_ascii_alphabetic());
+    let unit = match name.as_str() {
+        "" => {
+            return Err(Error {
+                pos: start.pos,
+                recoverable: true,
+                inner: ParseError::Expecting {
+                    value: "size unit".to_string(),
+                },
+            })
+        }
+        "KB" => SizeUnit::Kilobyte,
+        "MB" => SizeUnit::Megabyte,
+        _ => {
+            return Err(Error {
+                pos: start.pos,
+                recoverable: false,
+                inner: ParseError::SizeUnit { name },
+            })
+        }
+    };
+    Ok(Size { value, unit })
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -110,6 +142,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_predicate_value_size() {
+        let mut reader = Reader::new("1MB");
+        let value = predicate_value(&mut reader).unwrap();
+        assert_eq!(
+            value,
+            PredicateValue::Size(Size {
+                value: 1,
+                unit: SizeUnit::Megabyte,
+            })
+        );
+        assert!(value.is_number());
+
+        let mut reader = Reader::new("512KB");
+        let value = predicate_value(&mut reader).unwrap();
+        assert_eq!(
+            value,
+            PredicateValue::Size(Size {
+                value: 512,
+                unit: SizeUnit::Kilobyte,
+            })
+        );
+        if let PredicateValue::Size(size) = value {
+            assert_eq!(size.bytes(), 524_288);
+            assert_eq!(size.to_string(), "512KB");
+        }
+
+        let mut reader = Reader::new("3GG");
+        let error = predicate_value(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 2 });
+        assert_eq!(
+            error.inner,
+            ParseError::SizeUnit {
+                name: "GG".to_string()
+            }
+        );
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_predicate_value_error() {
         let mut reader = Reader::new("xx");
@@ -363,27 +363,7 @@ pub fn regex(reader: &mut Reader) -> ParseResult<'static, Regex> {
     match regex::Regex::new(s.as_str()) {
         Ok(inner) => Ok(Regex { inner }),
         Err(e) => {
-            let message = match e {
-                regex::Error::Syntax(s) => {
-                    // The regex syntax error from the crate returns a multiline String
-                    // For example
-                    //     regex parse error:
-                    //         x{a}
-                    //           ^
-                    //     error: repetition quantifier expects a valid decimal
-                    //
-                    // To fit nicely in Hurl Error reporting, you need an error message string that does not spread on multiple lines
-                    // You will assume that the error most relevant description is on the last line
-                    let lines = s.split('\n').clone().collect::<Vec<&str>>();
-                    let last_line = lines.last().expect("at least one line");
-                    last_line
-                        .strip_prefix("error: ")
-                        .unwrap_or(last_line)
-                        .to_string()
-                }
-                regex::Error::CompiledTooBig(_) => "Size limit exceeded".to_string(),
-                _ => "unknown".to_string(),
-            };
+            let message = regex_error_message(e);
             Err(Error {
                 pos: start,
                 recoverable: false,
@@ -393,6 +373,31 @@ pub fn regex(reader: &mut Reader) -> ParseResult<'static, Regex> {
     }
 }
 
+/// Returns a one line message describing the regex error `e`.
+pub fn regex_error_message(e: regex::Error) -> String {
+    match e {
+        regex::Error::Syntax(s) => {
+            // The regex syntax error from the crate returns a multiline String
+            // For example
+            //     regex parse error:
+            //         x{a}
+            //           ^
+            //     error: repetition quantifier expects a valid decimal
+            //
+            // To fit nicely in Hurl Error reporting, you need an error message string that does not spread on multiple lines
+            // You will assume that the error most relevant description is on the last line
+            let lines = s.split('\n').clone().collect::<Vec<&str>>();
+            let last_line = lines.last().expect("at least one line");
+            last_line
+                .strip_prefix("error: ")
+                .unwrap_or(last_line)
+                .to_string()
+        }
+        regex::Error::CompiledTooBig(_) => "Size limit exceeded".to_string(),
+        _ => "unknown".to_string(),
+    }
+}
+
 pub fn null(reader: &mut Reader) -> ParseResult<'static, ()> {
     try_literal("null", reader)
 }
@@ -818,6 +823,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_key_value_empty_value() {
+        let mut reader = Reader::new("X-Empty:\nX-Next: value\n");
+        let key_value0 = key_value(&mut reader).unwrap();
+        assert_eq!(key_value0.key.value, "X-Empty");
+        assert_eq!(
+            key_value0.value,
+            Template {
+                delimiter: None,
+                elements: vec![],
+                source_info: SourceInfo::new(1, 9, 1, 9),
+            }
+        );
+        assert_eq!(key_value0.line_terminator0.newline.value, "\n");
+
+        // The next line is not swallowed by the empty value.
+        let key_value1 = key_value(&mut reader).unwrap();
+        assert_eq!(key_value1.key.value, "X-Next");
+        assert_eq!(key_value1.value.to_string(), "value");
+    }
+
+    #[test]
+    fn test_key_value_error() {
+        let mut reader = Reader::new("X-NoColon\n");
+        let error = key_value(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 10
+            }
+        );
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: String::from(":")
+            }
+        );
+    }
+
     #[test]
     fn test_boolean() {
         let mut reader = Reader::new("true");
@@ -38,6 +38,7 @@ fn query_value(reader: &mut Reader) -> ParseResult<'static, QueryValue> {
         &[
             status_query,
             url_query,
+            charset_query,
             header_query,
             cookie_query,
             body_query,
@@ -65,6 +66,11 @@ fn url_query(reader: &mut Reader) -> ParseResult<'static, QueryValue> {
     Ok(QueryValue::Url {})
 }
 
+fn charset_query(reader: &mut Reader) -> ParseResult<'static, QueryValue> {
+    try_literal("charset", reader)?;
+    Ok(QueryValue::Charset {})
+}
+
 fn header_query(reader: &mut Reader) -> ParseResult<'static, QueryValue> {
     try_literal("header", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -244,6 +250,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_charset_query() {
+        let mut reader = Reader::new("charset == \"utf-8\"");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(1, 1, 1, 8),
+                value: QueryValue::Charset {},
+            }
+        );
+        assert_eq!(reader.state.cursor, 7);
+    }
+
     #[test]
     fn test_header_query() {
         let mut reader = Reader::new("header \"Foo\"");
@@ -18,13 +18,14 @@
 use std::cmp::min;
 
 use crate::ast::Pos;
+use crate::parser::Error;
 
 /// Represents a text reader.
 ///
 /// The `Reader` implements methods to read a stream of text. A reader manages
 /// an internal `state` which is the position of the current cursor within the reader's buffer.
-/// Methods like [`Reader::read`], [`Reader::read_while`], [`Reader::read_while_escaping`]
-/// do advance the internal reader's`state`. Other methods, like [`Reader::peek`], [`Reader::peek_n`]
+/// Methods like [`Reader::read`], [`Reader::read_while`], [`Reader::read_while_max`],
+/// [`Reader::read_while_escaping`] do advance the internal reader's`state`. Other methods, like [`Reader::peek`], [`Reader::peek_n`]
 /// allows to get the next chars in the buffer without modifying the current reader state.
 ///
 /// # Example
@@ -41,6 +42,34 @@ use crate::ast::Pos;
 pub struct Reader {
     pub buffer: Vec<char>,
     pub state: ReaderState,
+    pub mode: ParseMode,
+    /// Recoverable diagnostics collected while parsing, like the non canonical syntax accepted
+    /// in [`ParseMode::Lenient`] or duplicated headers.
+    pub warnings: Vec<Error>,
+    /// Maximum nesting depth of lists and objects in JSON values.
+    pub max_json_depth: usize,
+    /// Current nesting depth of the JSON value being parsed.
+    pub(crate) json_depth: usize,
+    /// Number of columns a tab advances the reported position by (the cursor always
+    /// advances by one char).
+    pub tab_width: usize,
+    /// Whether standard methods are recognized regardless of their case (like `get` for `GET`).
+    /// Methods must be uppercase by default, so that a typo is not silently accepted.
+    pub case_insensitive_methods: bool,
+}
+
+/// Default maximum nesting depth of JSON values, see [`Reader::max_json_depth`].
+pub const DEFAULT_MAX_JSON_DEPTH: usize = 128;
+
+/// Represents how strictly a Hurl file is parsed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Only the canonical syntax is accepted.
+    #[default]
+    Strict,
+    /// Some non canonical syntax (like a lowercase `http` keyword) is accepted, and reported
+    /// as a warning in [`Reader::warnings`].
+    Lenient,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -52,12 +81,33 @@ pub struct ReaderState {
 impl Reader {
     /// Creates a new reader.
     pub fn new(s: &str) -> Reader {
+        Reader::with_mode(s, ParseMode::Strict)
+    }
+
+    /// Creates a new reader for `s` starting at `pos` instead of the first line and column.
+    ///
+    /// This is used to parse a snippet embedded in a host document (like a code block in a
+    /// Markdown file), so that the reported positions are the ones of the host document.
+    pub fn new_at(s: &str, pos: Pos) -> Reader {
+        let mut reader = Reader::new(s);
+        reader.state.pos = pos;
+        reader
+    }
+
+    /// Creates a new reader, parsing with `mode`.
+    pub fn with_mode(s: &str, mode: ParseMode) -> Reader {
         Reader {
             buffer: s.chars().collect(),
             state: ReaderState {
                 cursor: 0,
                 pos: Pos { line: 1, column: 1 },
             },
+            mode,
+            warnings: vec![],
+            max_json_depth: DEFAULT_MAX_JSON_DEPTH,
+            json_depth: 0,
+            tab_width: 1,
+            case_insensitive_methods: false,
         }
     }
 
@@ -72,7 +122,9 @@ impl Reader {
             None => None,
             Some(c) => {
                 self.state.cursor += 1;
-                if !is_combining_character(*c) {
+                if *c == '\t' {
+                    self.state.pos.column += self.tab_width;
+                } else if !is_combining_character(*c) {
                     self.state.pos.column += 1;
                 }
                 if *c == '\n' {
@@ -114,6 +166,21 @@ impl Reader {
         }
     }
 
+    /// Returns chars from the buffer while `predicate` is true, advancing the internal state.
+    ///
+    /// At most `max` chars are read, to guard against pathological inputs like an enormous
+    /// unterminated token.
+    pub fn read_while_max(&mut self, predicate: fn(&char) -> bool, max: usize) -> String {
+        let mut s = String::from("");
+        for _ in 0..max {
+            match self.peek() {
+                Some(c) if predicate(&c) => s.push(self.read().unwrap()),
+                _ => break,
+            }
+        }
+        s
+    }
+
     // only support escaped spaces for now
     pub fn read_while_escaping(&mut self, predicate: fn(&char) -> bool) -> String {
         let mut s = String::from("");
@@ -190,6 +257,71 @@ mod tests {
         assert_eq!(reader.read(), None);
     }
 
+    #[test]
+    fn test_reader_new_at() {
+        let mut reader = Reader::new_at(
+            "a\nb",
+            Pos {
+                line: 42,
+                column: 5,
+            },
+        );
+        assert_eq!(reader.state.cursor, 0);
+        assert_eq!(reader.read().unwrap(), 'a');
+        assert_eq!(
+            reader.state.pos,
+            Pos {
+                line: 42,
+                column: 6
+            }
+        );
+        reader.read_n(2);
+        assert_eq!(
+            reader.state.pos,
+            Pos {
+                line: 43,
+                column: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_tab_width() {
+        let mut reader = Reader::new("\t\tab");
+        reader.read_n(3);
+        assert_eq!(reader.state.cursor, 3);
+        assert_eq!(reader.state.pos, Pos { line: 1, column: 4 });
+
+        let mut reader = Reader::new("\t\tab");
+        reader.tab_width = 4;
+        reader.read_n(3);
+        assert_eq!(reader.state.cursor, 3);
+        assert_eq!(
+            reader.state.pos,
+            Pos {
+                line: 1,
+                column: 10
+            }
+        );
+        assert_eq!(reader.peek(), Some('b'));
+    }
+
+    #[test]
+    fn test_read_while_max() {
+        let mut reader = Reader::new("abcdef gh");
+        assert_eq!(reader.read_while_max(|c| c.is_alphanumeric(), 3), "abc");
+        assert_eq!(reader.state.cursor, 3);
+        assert_eq!(reader.read_while_max(|c| c.is_alphanumeric(), 10), "def");
+        assert_eq!(reader.state.cursor, 6);
+        assert_eq!(reader.read_while_max(|c| c.is_alphanumeric(), 10), "");
+        assert_eq!(reader.state.cursor, 6);
+
+        let s = "a".repeat(10_000);
+        let mut reader = Reader::new(&s);
+        assert_eq!(reader.read_while_max(|c| c.is_alphanumeric(), 32).len(), 32);
+        assert_eq!(reader.state.cursor, 32);
+    }
+
     #[test]
     fn test_try_predicate() {
         let mut reader = Reader::new("hi");
@@ -26,6 +26,26 @@ use crate::parser::reader::Reader;
 use crate::parser::string::*;
 use crate::parser::{filename, ParseResult};
 
+pub fn settings(reader: &mut Reader) -> ParseResult<'static, Settings> {
+    let line_terminators = optional_line_terminators(reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    let start = reader.state.clone();
+    try_literal("[Settings]", reader)?;
+    let source_info = SourceInfo {
+        start: start.pos,
+        end: reader.state.pos.clone(),
+    };
+    let line_terminator0 = line_terminator(reader)?;
+    let options = zero_or_more(option, reader)?;
+    Ok(Settings {
+        line_terminators,
+        space0,
+        line_terminator0,
+        options,
+        source_info,
+    })
+}
+
 pub fn request_sections(reader: &mut Reader) -> ParseResult<'static, Vec<Section>> {
     let sections = zero_or_more(request_section, reader)?;
     Ok(sections)
@@ -53,6 +73,23 @@ fn request_section(reader: &mut Reader) -> ParseResult<'static, Section> {
         "MultipartFormData" => section_value_multipart_form_data(reader)?,
         "Cookies" => section_value_cookies(reader)?,
         "Options" => section_value_options(reader)?,
+        "Captures" | "Asserts" => {
+            return Err(Error {
+                pos: Pos {
+                    line: start.pos.line,
+                    column: start.pos.column + 1,
+                },
+                recoverable: false,
+                inner: ParseError::RequestSection,
+            });
+        }
+        "Settings" => {
+            return Err(Error {
+                pos: start.pos,
+                recoverable: false,
+                inner: ParseError::MisplacedSettings,
+            });
+        }
         _ => {
             return Err(Error {
                 pos: Pos {
@@ -87,6 +124,26 @@ fn response_section(reader: &mut Reader) -> ParseResult<'static, Section> {
     let value = match name.as_str() {
         "Captures" => section_value_captures(reader)?,
         "Asserts" => section_value_asserts(reader)?,
+        // Cookies are sent in a request section, they are checked in a response with a
+        // `cookie` query.
+        "QueryStringParams" | "BasicAuth" | "FormParams" | "MultipartFormData" | "Cookies"
+        | "Options" => {
+            return Err(Error {
+                pos: Pos {
+                    line: start.pos.line,
+                    column: start.pos.column + 1,
+                },
+                recoverable: false,
+                inner: ParseError::ResponseSection,
+            });
+        }
+        "Settings" => {
+            return Err(Error {
+                pos: start.pos,
+                recoverable: false,
+                inner: ParseError::MisplacedSettings,
+            });
+        }
         _ => {
             return Err(Error {
                 pos: Pos {
@@ -361,11 +418,17 @@ fn option(reader: &mut Reader) -> ParseResult<'static, EntryOption> {
             option_cert,
             option_key,
             option_compressed,
+            option_connect_timeout,
+            option_curl,
             option_insecure,
             option_follow_location,
             option_max_redirect,
+            option_no_proxy,
+            option_proxy,
+            option_repeat,
             option_retry,
             option_retry_interval,
+            option_timeout,
             option_variable,
             option_verbose,
             option_very_verbose,
@@ -530,6 +593,202 @@ fn option_max_redirect(reader: &mut Reader) -> ParseResult<'static, EntryOption>
     Ok(EntryOption::MaxRedirect(option))
 }
 
+fn option_curl(reader: &mut Reader) -> ParseResult<'static, EntryOption> {
+    let line_terminators = optional_line_terminators(reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    try_literal("curl", reader)?;
+    let space1 = zero_or_more_spaces(reader)?;
+    try_literal(":", reader)?;
+    let space2 = zero_or_more_spaces(reader)?;
+    let value = curl_args(reader)?;
+    let line_terminator0 = line_terminator(reader)?;
+    let option = CurlOption {
+        line_terminators,
+        space0,
+        space1,
+        space2,
+        value,
+        line_terminator0,
+    };
+    Ok(EntryOption::Curl(option))
+}
+
+/// Parses the remaining arguments of a curl option line, up to a comment or the end of line.
+/// A comment starts with a `#` preceded by whitespace, outside of single or double quotes, so
+/// `-H "X-Id: #1"` is kept whole. The arguments are returned verbatim, without their trailing
+/// spaces.
+fn curl_args(reader: &mut Reader) -> ParseResult<'static, String> {
+    let start = reader.state.clone();
+    let mut end = reader.state.clone();
+    let mut quote = None;
+    let mut after_space = true;
+    while let Some(c) = reader.peek() {
+        if c == '\n' || c == '\r' || (c == '#' && after_space && quote.is_none()) {
+            break;
+        }
+        reader.read();
+        match quote {
+            Some(q) if c == q => quote = None,
+            None if c == '"' || c == '\'' => quote = Some(c),
+            _ => {}
+        }
+        after_space = c == ' ' || c == '\t';
+        if !after_space {
+            end = reader.state.clone();
+        }
+    }
+    reader.state = end;
+    let args = reader.peek_back(start.cursor);
+    if args.is_empty() {
+        return Err(Error {
+            pos: start.pos,
+            recoverable: false,
+            inner: ParseError::Expecting {
+                value: "curl arguments".to_string(),
+            },
+        });
+    }
+    Ok(args)
+}
+
+fn option_no_proxy(reader: &mut Reader) -> ParseResult<'static, EntryOption> {
+    let line_terminators = optional_line_terminators(reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    try_literal("no-proxy", reader)?;
+    let space1 = zero_or_more_spaces(reader)?;
+    try_literal(":", reader)?;
+    let space2 = zero_or_more_spaces(reader)?;
+    let value = no_proxy_hosts(reader)?;
+    let line_terminator0 = line_terminator(reader)?;
+    let option = NoProxyOption {
+        line_terminators,
+        space0,
+        space1,
+        space2,
+        value,
+        line_terminator0,
+    };
+    Ok(EntryOption::NoProxy(option))
+}
+
+fn no_proxy_hosts(reader: &mut Reader) -> ParseResult<'static, Vec<String>> {
+    let mut hosts = vec![];
+    loop {
+        let start = reader.state.clone();
+        let host = reader.read_while(|c| !c.is_whitespace() && *c != ',' && *c != '#');
+        if host.is_empty() {
+            return Err(Error {
+                pos: start.pos,
+                recoverable: false,
+                inner: ParseError::Expecting {
+                    value: "host".to_string(),
+                },
+            });
+        }
+        hosts.push(host);
+        if !reader.try_literal(",") {
+            break;
+        }
+    }
+    Ok(hosts)
+}
+
+fn option_proxy(reader: &mut Reader) -> ParseResult<'static, EntryOption> {
+    let line_terminators = optional_line_terminators(reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    try_literal("proxy", reader)?;
+    let space1 = zero_or_more_spaces(reader)?;
+    try_literal(":", reader)?;
+    let space2 = zero_or_more_spaces(reader)?;
+    let value = proxy(reader)?;
+    let line_terminator0 = line_terminator(reader)?;
+    let option = ProxyOption {
+        line_terminators,
+        space0,
+        space1,
+        space2,
+        value,
+        line_terminator0,
+    };
+    Ok(EntryOption::Proxy(option))
+}
+
+fn proxy(reader: &mut Reader) -> ParseResult<'static, Proxy> {
+    let start = reader.state.clone();
+    let value = reader.read_while(|c| !c.is_whitespace() && *c != '#');
+    let error = Error {
+        pos: start.pos,
+        recoverable: false,
+        inner: ParseError::Proxy {
+            value: value.clone(),
+        },
+    };
+    let (protocol, address) = match value.split_once("://") {
+        Some((protocol, address)) => {
+            let protocols = ["http", "https", "socks4", "socks4a", "socks5", "socks5h"];
+            if !protocols.contains(&protocol) {
+                return Err(error);
+            }
+            (Some(protocol.to_string()), address)
+        }
+        None => (None, value.as_str()),
+    };
+    // The port is optional, an IPv6 host is enclosed in brackets (`[::1]:8080`).
+    let (host, port) = match address.rsplit_once(':') {
+        Some((host, port)) if !port.ends_with(']') => match port.parse::<u16>() {
+            Ok(port) => (host, Some(port)),
+            Err(_) => return Err(error),
+        },
+        _ => (address, None),
+    };
+    let is_host_char = |c: char| c.is_alphanumeric() || "-._[]:".contains(c);
+    if host.is_empty() || !host.chars().all(is_host_char) {
+        return Err(error);
+    }
+    Ok(Proxy {
+        protocol,
+        host: host.to_string(),
+        port,
+    })
+}
+
+fn option_repeat(reader: &mut Reader) -> ParseResult<'static, EntryOption> {
+    let line_terminators = optional_line_terminators(reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    try_literal("repeat", reader)?;
+    let space1 = zero_or_more_spaces(reader)?;
+    try_literal(":", reader)?;
+    let space2 = zero_or_more_spaces(reader)?;
+    let value = repeat(reader)?;
+    let line_terminator0 = line_terminator(reader)?;
+    let option = RepeatOption {
+        line_terminators,
+        space0,
+        space1,
+        space2,
+        value,
+        line_terminator0,
+    };
+    Ok(EntryOption::Repeat(option))
+}
+
+/// Parses the number of times an entry is executed, a strictly positive integer.
+fn repeat(reader: &mut Reader) -> ParseResult<'static, usize> {
+    let pos = reader.state.pos.clone();
+    let value = nonrecover(integer, reader)?;
+    if value > 0 {
+        Ok(value as usize)
+    } else {
+        Err(Error {
+            pos,
+            recoverable: false,
+            inner: ParseError::Expecting {
+                value: "a strictly positive integer".to_string(),
+            },
+        })
+    }
+}
+
 fn option_retry(reader: &mut Reader) -> ParseResult<'static, EntryOption> {
     let line_terminators = optional_line_terminators(reader)?;
     let space0 = zero_or_more_spaces(reader)?;
@@ -590,6 +849,80 @@ fn option_retry_interval(reader: &mut Reader) -> ParseResult<'static, EntryOptio
     Ok(EntryOption::RetryInterval(option))
 }
 
+fn option_timeout(reader: &mut Reader) -> ParseResult<'static, EntryOption> {
+    let line_terminators = optional_line_terminators(reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    try_literal("timeout", reader)?;
+    let space1 = zero_or_more_spaces(reader)?;
+    try_literal(":", reader)?;
+    let space2 = zero_or_more_spaces(reader)?;
+    let value = duration(reader)?;
+    let line_terminator0 = line_terminator(reader)?;
+    let option = TimeoutOption {
+        line_terminators,
+        space0,
+        space1,
+        space2,
+        value,
+        line_terminator0,
+    };
+    Ok(EntryOption::Timeout(option))
+}
+
+fn option_connect_timeout(reader: &mut Reader) -> ParseResult<'static, EntryOption> {
+    let line_terminators = optional_line_terminators(reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    try_literal("connect-timeout", reader)?;
+    let space1 = zero_or_more_spaces(reader)?;
+    try_literal(":", reader)?;
+    let space2 = zero_or_more_spaces(reader)?;
+    let value = duration(reader)?;
+    let line_terminator0 = line_terminator(reader)?;
+    let option = ConnectTimeoutOption {
+        line_terminators,
+        space0,
+        space1,
+        space2,
+        value,
+        line_terminator0,
+    };
+    Ok(EntryOption::ConnectTimeout(option))
+}
+
+/// Parses a positive duration, like `30s`, with an optional unit (`ms`, `s` or `m`).
+fn duration(reader: &mut Reader) -> ParseResult<'static, Duration> {
+    let pos = reader.state.pos.clone();
+    let value = nonrecover(integer, reader)?;
+    if value < 0 {
+        return Err(Error {
+            pos,
+            recoverable: false,
+            inner: ParseError::Expecting {
+                value: "a positive duration".to_string(),
+            },
+        });
+    }
+    let start = reader.state.clone();
+    let name = reader.read_while(|c| c.is_ascii_alphabetic());
+    let unit = match name.as_str() {
+        "" => None,
+        "ms" => Some(DurationUnit::Millisecond),
+        "s" => Some(DurationUnit::Second),
+        "m" => Some(DurationUnit::Minute),
+        _ => {
+            return Err(Error {
+                pos: start.pos,
+                recoverable: false,
+                inner: ParseError::DurationUnit { name },
+            })
+        }
+    };
+    Ok(Duration {
+        value: value as u64,
+        unit,
+    })
+}
+
 fn option_variable(reader: &mut Reader) -> ParseResult<'static, EntryOption> {
     let line_terminators = optional_line_terminators(reader)?;
     let space0 = zero_or_more_spaces(reader)?;
@@ -830,6 +1163,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_assert_multiline_expected_value() {
+        let mut reader = Reader::new("body == ```\nexpected multi\nline\n```\nstatus == 200\n");
+        let assert0 = assert(&mut reader).unwrap();
+        assert_eq!(assert0.query.value, QueryValue::Body {});
+        match assert0.predicate.predicate_func.value {
+            PredicateFuncValue::Equal {
+                value: PredicateValue::MultilineString(value),
+                ..
+            } => assert_eq!(value.value().to_string(), "expected multi\nline\n"),
+            _ => panic!("expecting a multiline expected value"),
+        }
+        assert_eq!(reader.state.pos, Pos { line: 5, column: 1 });
+        assert!(assert(&mut reader).is_ok());
+        assert!(reader.is_eof());
+    }
+
     #[test]
     fn test_asserts_section_error() {
         let mut reader = Reader::new("x[Assertsx]\nheader Location equals \"https://google.fr\"\n");
@@ -855,6 +1205,64 @@ mod tests {
         assert!(!error.recoverable);
     }
 
+    #[test]
+    fn test_cookies_section() {
+        // Sent cookies in a request
+        let mut reader = Reader::new("[Cookies]\nx: y\n");
+        let section = request_section(&mut reader).unwrap();
+        match section.value {
+            SectionValue::Cookies(cookies) => {
+                assert_eq!(cookies.len(), 1);
+                assert_eq!(cookies[0].name.value, "x");
+                assert_eq!(cookies[0].value.to_string(), "y");
+            }
+            _ => panic!("expecting a cookies section"),
+        }
+
+        // Asserted cookies in a response
+        let mut reader = Reader::new("[Asserts]\ncookie \"x\" == \"y\"\n");
+        let section = response_section(&mut reader).unwrap();
+        match section.value {
+            SectionValue::Asserts(asserts) => {
+                assert_eq!(asserts.len(), 1);
+                assert_eq!(
+                    asserts[0].query.value,
+                    QueryValue::Cookie {
+                        space0: Whitespace {
+                            value: " ".to_string(),
+                            source_info: SourceInfo::new(2, 7, 2, 8),
+                        },
+                        expr: CookiePath {
+                            name: Template {
+                                delimiter: None,
+                                elements: vec![TemplateElement::String {
+                                    value: "x".to_string(),
+                                    encoded: "x".to_string(),
+                                }],
+                                source_info: SourceInfo::new(2, 9, 2, 10),
+                            },
+                            attribute: None,
+                        },
+                    }
+                );
+            }
+            _ => panic!("expecting an asserts section"),
+        }
+
+        // Misplaced sections
+        let mut reader = Reader::new("[Cookies]\nx: y\n");
+        let error = response_section(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 2 });
+        assert_eq!(error.inner, ParseError::ResponseSection);
+        assert!(!error.recoverable);
+
+        let mut reader = Reader::new("[Asserts]\ncookie \"x\" == \"y\"\n");
+        let error = request_section(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 2 });
+        assert_eq!(error.inner, ParseError::RequestSection);
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_cookie() {
         let mut reader = Reader::new("Foo: Bar");
@@ -1028,6 +1436,199 @@ mod tests {
         assert!(!error.recoverable)
     }
 
+    #[test]
+    fn test_option_proxy() {
+        let mut reader = Reader::new("proxy: http://localhost:3128 # squid\n");
+        let option = match option(&mut reader).unwrap() {
+            EntryOption::Proxy(option) => option,
+            _ => panic!("expecting a proxy option"),
+        };
+        assert_eq!(
+            option.value,
+            Proxy {
+                protocol: Some("http".to_string()),
+                host: "localhost".to_string(),
+                port: Some(3128),
+            }
+        );
+        assert_eq!(option.value.to_string(), "http://localhost:3128");
+        assert_eq!(
+            option.line_terminator0.comment,
+            Some(Comment {
+                value: " squid".to_string()
+            })
+        );
+
+        let mut reader = Reader::new("10.0.0.1");
+        assert_eq!(
+            proxy(&mut reader).unwrap(),
+            Proxy {
+                protocol: None,
+                host: "10.0.0.1".to_string(),
+                port: None,
+            }
+        );
+
+        let mut reader = Reader::new("socks5h://[::1]:1080");
+        let value = proxy(&mut reader).unwrap();
+        assert_eq!(value.host, "[::1]");
+        assert_eq!(value.port, Some(1080));
+    }
+
+    #[test]
+    fn test_option_proxy_error() {
+        for value in ["localhost:abc", "ftp://localhost", "http://"] {
+            let mut reader = Reader::new(&format!("proxy: {value}\n"));
+            let error = option_proxy(&mut reader).err().unwrap();
+            assert_eq!(error.pos, Pos { line: 1, column: 8 });
+            assert!(!error.recoverable);
+            assert!(matches!(error.inner, ParseError::Proxy { .. }));
+        }
+    }
+
+    #[test]
+    fn test_option_repeat() {
+        let mut reader = Reader::new("repeat: 5\n");
+        let option = match option(&mut reader).unwrap() {
+            EntryOption::Repeat(option) => option,
+            _ => panic!("expecting a repeat option"),
+        };
+        assert_eq!(option.value, 5);
+    }
+
+    #[test]
+    fn test_option_repeat_error() {
+        for value in ["0", "-2"] {
+            let mut reader = Reader::new(&format!("repeat: {value}\n"));
+            let error = option(&mut reader).err().unwrap();
+            assert_eq!(error.pos, Pos { line: 1, column: 9 });
+            assert!(!error.recoverable);
+            assert_eq!(
+                error.inner,
+                ParseError::Expecting {
+                    value: "a strictly positive integer".to_string()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_option_timeout() {
+        let mut reader = Reader::new("timeout: 30s\n");
+        let timeout = match option(&mut reader).unwrap() {
+            EntryOption::Timeout(option) => option,
+            _ => panic!("expecting a timeout option"),
+        };
+        assert_eq!(
+            timeout.value,
+            Duration {
+                value: 30,
+                unit: Some(DurationUnit::Second)
+            }
+        );
+        assert_eq!(timeout.value.millis(), 30_000);
+
+        let mut reader = Reader::new("connect-timeout: 5s\n");
+        let timeout = match option(&mut reader).unwrap() {
+            EntryOption::ConnectTimeout(option) => option,
+            _ => panic!("expecting a connect-timeout option"),
+        };
+        assert_eq!(timeout.value.millis(), 5_000);
+
+        let mut reader = Reader::new("timeout: 0\n");
+        let timeout = match option(&mut reader).unwrap() {
+            EntryOption::Timeout(option) => option,
+            _ => panic!("expecting a timeout option"),
+        };
+        assert_eq!(timeout.value.millis(), 0);
+    }
+
+    #[test]
+    fn test_option_timeout_error() {
+        let mut reader = Reader::new("timeout: -30s\n");
+        let error = option(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 10
+            }
+        );
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "a positive duration".to_string()
+            }
+        );
+
+        let mut reader = Reader::new("connect-timeout: 5h\n");
+        let error = option(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 19
+            }
+        );
+        assert_eq!(
+            error.inner,
+            ParseError::DurationUnit {
+                name: "h".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_option_curl() {
+        let mut reader = Reader::new("curl: --compressed --http2  # extra args\n");
+        let option = match option(&mut reader).unwrap() {
+            EntryOption::Curl(option) => option,
+            _ => panic!("expecting a curl option"),
+        };
+        assert_eq!(option.value, "--compressed --http2");
+        assert_eq!(option.line_terminator0.space0.value, "  ");
+        assert!(reader.is_eof());
+
+        let mut reader = Reader::new("curl: -H \"X-Id: #1\" --data a#b # comment\n");
+        let curl = match option_curl(&mut reader).unwrap() {
+            EntryOption::Curl(curl) => curl,
+            _ => panic!("expecting a curl option"),
+        };
+        assert_eq!(curl.value, "-H \"X-Id: #1\" --data a#b");
+        assert_eq!(curl.line_terminator0.comment.unwrap().value, " comment");
+        assert!(reader.is_eof());
+
+        let mut reader = Reader::new("curl:\n");
+        let error = option_curl(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 6 });
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_option_no_proxy() {
+        let mut reader = Reader::new("no-proxy: example.com,localhost\n");
+        let option = match option(&mut reader).unwrap() {
+            EntryOption::NoProxy(option) => option,
+            _ => panic!("expecting a no-proxy option"),
+        };
+        assert_eq!(
+            option.value,
+            vec!["example.com".to_string(), "localhost".to_string()]
+        );
+
+        let mut reader = Reader::new("no-proxy: example.com,,localhost\n");
+        let error = option_no_proxy(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 23
+            }
+        );
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_variable_definition() {
         let mut reader = Reader::new("a=1");
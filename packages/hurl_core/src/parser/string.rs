@@ -150,6 +150,70 @@ pub fn quoted_string(reader: &mut Reader) -> ParseResult<'static, String> {
 }
 
 pub fn quoted_template(reader: &mut Reader) -> ParseResult<'static, Template> {
+    let start = reader.state.pos.clone();
+    let encoded_string = quoted_encoded_string(reader)?;
+    let elements = template::templatize(encoded_string)?;
+    Ok(Template {
+        delimiter: Some('"'),
+        elements,
+        source_info: SourceInfo {
+            start,
+            end: reader.state.pos.clone(),
+        },
+    })
+}
+
+/// Parses a quoted template where [`TEMPLATE_WILDCARD`] is not an expression but a wildcard,
+/// kept as a string element between the templatized parts.
+pub fn quoted_template_with_wildcards(reader: &mut Reader) -> ParseResult<'static, Template> {
+    let start = reader.state.pos.clone();
+    let encoded_string = quoted_encoded_string(reader)?;
+    let wildcard = TEMPLATE_WILDCARD.chars().collect::<Vec<_>>();
+    let is_wildcard = |chars: &[(char, String, Pos)]| {
+        chars.len() >= wildcard.len()
+            && chars
+                .iter()
+                .zip(&wildcard)
+                .all(|((_, s, _), c)| s.len() == 1 && s.starts_with(*c))
+    };
+    let source_info = encoded_string.source_info;
+    let chars = encoded_string.chars;
+
+    let mut elements = vec![];
+    let mut part = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        if is_wildcard(&chars[i..]) {
+            elements.extend(template::templatize(template::EncodedString {
+                source_info: source_info.clone(),
+                chars: std::mem::take(&mut part),
+            })?);
+            elements.push(TemplateElement::String {
+                value: TEMPLATE_WILDCARD.to_string(),
+                encoded: TEMPLATE_WILDCARD.to_string(),
+            });
+            i += wildcard.len();
+        } else {
+            part.push(chars[i].clone());
+            i += 1;
+        }
+    }
+    elements.extend(template::templatize(template::EncodedString {
+        source_info,
+        chars: part,
+    })?);
+    Ok(Template {
+        delimiter: Some('"'),
+        elements,
+        source_info: SourceInfo {
+            start,
+            end: reader.state.pos.clone(),
+        },
+    })
+}
+
+/// Parses a quoted string, returning its chars with their encoding and position.
+fn quoted_encoded_string(reader: &mut Reader) -> ParseResult<'static, template::EncodedString> {
     let start = reader.state.clone().pos;
     let mut end = start.clone();
     try_literal("\"", reader)?;
@@ -173,21 +237,9 @@ pub fn quoted_template(reader: &mut Reader) -> ParseResult<'static, Template> {
         }
     }
     literal("\"", reader)?;
-    let encoded_string = template::EncodedString {
-        source_info: SourceInfo {
-            start: start.clone(),
-            end,
-        },
+    Ok(template::EncodedString {
+        source_info: SourceInfo { start, end },
         chars,
-    };
-    let elements = template::templatize(encoded_string)?;
-    Ok(Template {
-        delimiter: Some('"'),
-        elements,
-        source_info: SourceInfo {
-            start,
-            end: reader.state.pos.clone(),
-        },
     })
 }
 
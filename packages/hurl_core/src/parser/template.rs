@@ -82,11 +82,8 @@ pub fn templatize(encoded_string: EncodedString) -> ParseResult<'static, Vec<Tem
 
             State::FirstCloseBracket {} => {
                 if s.as_str() == "}" {
-                    let mut reader = Reader::new(encoded.as_str());
-                    reader.state = ReaderState {
-                        cursor: 0,
-                        pos: expression_start.unwrap().clone(),
-                    };
+                    let mut reader =
+                        Reader::new_at(encoded.as_str(), expression_start.unwrap().clone());
                     let expression = expr::parse2(&mut reader)?;
                     elements.push(TemplateElement::Expression(expression));
                     value = "".to_string();
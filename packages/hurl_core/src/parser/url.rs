@@ -49,8 +49,10 @@ pub fn url(reader: &mut Reader) -> ParseResult<'static, Template> {
 
     loop {
         let save = reader.state.clone();
+        // A `#` inside the url starts its fragment: a comment must be preceded by a whitespace.
+        let is_fragment = reader.peek() == Some('#');
         match line_terminator(reader) {
-            Ok(_) => {
+            Ok(_) if !is_fragment => {
                 reader.state = save;
                 break;
             }
@@ -79,7 +81,7 @@ pub fn url(reader: &mut Reader) -> ParseResult<'static, Template> {
                             if c.is_alphanumeric()
                                 | vec![
                                     ':', '/', '.', '-', '?', '=', '&', '_', '%', '*', ',', '@',
-                                    '~', '+', '!', '$', '\'', '(', ')', ';', '[', ']',
+                                    '~', '+', '!', '$', '\'', '(', ')', ';', '[', ']', '#',
                                 ]
                                 .contains(&c)
                             {
@@ -210,6 +212,23 @@ mod tests {
         assert_eq!(reader.state.cursor, 18);
     }
 
+    #[test]
+    fn test_url_with_fragment() {
+        let mut reader = Reader::new("http://x/page#frag # comment");
+        assert_eq!(
+            url(&mut reader).unwrap(),
+            Template {
+                elements: vec![TemplateElement::String {
+                    value: String::from("http://x/page#frag"),
+                    encoded: String::from("http://x/page#frag"),
+                }],
+                delimiter: None,
+                source_info: SourceInfo::new(1, 1, 1, 19),
+            }
+        );
+        assert_eq!(reader.state.cursor, 18);
+    }
+
     #[test]
     fn test_url_error_variable() {
         let mut reader = Reader::new("http://{{host>}}.fr");
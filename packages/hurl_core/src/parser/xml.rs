@@ -17,7 +17,6 @@
  */
 use sxd_document::parser;
 
-use crate::ast::Pos;
 use crate::parser::error::*;
 use crate::parser::reader::Reader;
 use crate::parser::ParseResult;
@@ -29,7 +28,7 @@ pub fn parse(reader: &mut Reader) -> ParseResult<'static, String> {
         Some('<') => buf.push('<'),
         _ => {
             return Err(Error {
-                pos: Pos { line: 1, column: 1 },
+                pos: start.pos,
                 recoverable: true,
                 inner: ParseError::Xml {},
             })
@@ -63,6 +62,7 @@ fn is_valid(s: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ast::Pos;
 
     #[test]
     fn test_parsing_xml_brute_force_errors() {
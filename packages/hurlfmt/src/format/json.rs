@@ -44,6 +44,9 @@ impl ToJson for Entry {
         if let Some(response) = &self.response {
             attributes.push(("response".to_string(), response.to_json()));
         }
+        if let Some(name) = &self.name {
+            attributes.push(("name".to_string(), JValue::String(name.clone())));
+        }
         JValue::Object(attributes)
     }
 }
@@ -97,8 +100,20 @@ impl ToJson for Response {
         if let Some(v) = get_json_version(&self.version.value) {
             attributes.push(("version".to_string(), JValue::String(v)))
         }
-        if let StatusValue::Specific(n) = self.status.value {
-            attributes.push(("status".to_string(), JValue::Number(n.to_string())));
+        match self.status.value {
+            StatusValue::Any => {}
+            StatusValue::Specific(n) => {
+                attributes.push(("status".to_string(), JValue::Number(n.to_string())));
+            }
+            StatusValue::Class(n) => {
+                attributes.push(("status".to_string(), JValue::String(format!("{n}xx"))));
+            }
+            StatusValue::Range(start, end) => {
+                attributes.push((
+                    "status".to_string(),
+                    JValue::String(format!("{start}-{end}")),
+                ));
+            }
         }
         add_headers(&mut attributes, &self.headers);
         if !self.captures().is_empty() {
@@ -135,6 +150,13 @@ impl ToJson for Bytes {
             Bytes::Base64(value) => value.to_json(),
             Bytes::Hex(value) => value.to_json(),
             Bytes::File(value) => value.to_json(),
+            Bytes::FromResponse(index) => JValue::Object(vec![
+                (
+                    "type".to_string(),
+                    JValue::String("from-response".to_string()),
+                ),
+                ("value".to_string(), JValue::Number(index.to_string())),
+            ]),
             Bytes::Json(value) => JValue::Object(vec![
                 ("type".to_string(), JValue::String("json".to_string())),
                 ("value".to_string(), value.to_json()),
@@ -147,6 +169,10 @@ impl ToJson for Bytes {
                 ("type".to_string(), JValue::String("text".to_string())),
                 ("value".to_string(), JValue::String(value.to_string())),
             ]),
+            Bytes::Template(value) => JValue::Object(vec![
+                ("type".to_string(), JValue::String("template".to_string())),
+                ("value".to_string(), JValue::String(value.to_string())),
+            ]),
             Bytes::MultilineString(multi) => {
                 // TODO: check these values. Maybe we want to have the same
                 // export when using:
@@ -169,10 +195,23 @@ impl ToJson for Bytes {
                     MultilineString::Xml(_) => "xml",
                     MultilineString::GraphQl(_) => "graphql",
                 };
-                JValue::Object(vec![
+                let mut attributes = vec![
                     ("type".to_string(), JValue::String(lang.to_string())),
                     ("value".to_string(), JValue::String(multi.to_string())),
-                ])
+                ];
+                if let Some(charset) = multi.charset() {
+                    attributes.push((
+                        "charset".to_string(),
+                        JValue::String(charset.value.to_string()),
+                    ));
+                }
+                if let Some(content_type) = multi.content_type() {
+                    attributes.push((
+                        "content_type".to_string(),
+                        JValue::String(content_type.value.clone()),
+                    ));
+                }
+                JValue::Object(attributes)
             }
         }
     }
@@ -307,6 +346,9 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         QueryValue::Url {} => {
             attributes.push(("type".to_string(), JValue::String("url".to_string())));
         }
+        QueryValue::Charset {} => {
+            attributes.push(("type".to_string(), JValue::String("charset".to_string())));
+        }
         QueryValue::Body {} => {
             attributes.push(("type".to_string(), JValue::String("body".to_string())));
         }
@@ -433,6 +475,35 @@ impl ToJson for Predicate {
                 attributes.push(("type".to_string(), JValue::String("count".to_string())));
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::CountGreaterThan { value, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("count-greater".to_string()),
+                ));
+                add_predicate_value(&mut attributes, value);
+            }
+            PredicateFuncValue::CountGreaterThanOrEqual { value, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("count-greater-or-equal".to_string()),
+                ));
+                add_predicate_value(&mut attributes, value);
+            }
+            PredicateFuncValue::CountLessThan { value, .. } => {
+                attributes.push(("type".to_string(), JValue::String("count-less".to_string())));
+                add_predicate_value(&mut attributes, value);
+            }
+            PredicateFuncValue::CountLessThanOrEqual { value, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("count-less-or-equal".to_string()),
+                ));
+                add_predicate_value(&mut attributes, value);
+            }
+            PredicateFuncValue::LengthEqual { value, .. } => {
+                attributes.push(("type".to_string(), JValue::String("length".to_string())));
+                add_predicate_value(&mut attributes, value);
+            }
             PredicateFuncValue::StartWith { value, .. } => {
                 attributes.push(("type".to_string(), JValue::String("start-with".to_string())));
                 add_predicate_value(&mut attributes, value);
@@ -453,12 +524,57 @@ impl ToJson for Predicate {
                 attributes.push(("type".to_string(), JValue::String("match".to_string())));
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::MatchSchema { value, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("match-schema".to_string()),
+                ));
+                match value {
+                    SchemaSource::Inline(value) => {
+                        attributes.push(("value".to_string(), value.to_json()));
+                    }
+                    SchemaSource::File { filename, .. } => {
+                        attributes.push(("file".to_string(), JValue::String(filename.to_string())));
+                    }
+                }
+            }
+            PredicateFuncValue::MatchTemplate { value, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("match-template".to_string()),
+                ));
+                attributes.push(("value".to_string(), JValue::String(value.to_string())));
+            }
+            PredicateFuncValue::EqualIgnoreOrder { value, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("equal-ignore-order".to_string()),
+                ));
+                attributes.push(("value".to_string(), value.to_json()));
+            }
+            PredicateFuncValue::MatchAll { value, .. } => {
+                attributes.push(("type".to_string(), JValue::String("match-all".to_string())));
+                attributes.push(("value".to_string(), value.to_json()));
+            }
+            PredicateFuncValue::MatchAny { value, .. } => {
+                attributes.push(("type".to_string(), JValue::String("match-any".to_string())));
+                attributes.push(("value".to_string(), value.to_json()));
+            }
+            PredicateFuncValue::IsCloseTo { value, epsilon, .. } => {
+                attributes.push(("type".to_string(), JValue::String("isCloseTo".to_string())));
+                add_predicate_value(&mut attributes, value);
+                let (epsilon, _) = json_predicate_value(epsilon);
+                attributes.push(("epsilon".to_string(), epsilon));
+            }
             PredicateFuncValue::IsInteger {} => {
                 attributes.push(("type".to_string(), JValue::String("isInteger".to_string())));
             }
             PredicateFuncValue::IsFloat {} => {
                 attributes.push(("type".to_string(), JValue::String("isFloat".to_string())));
             }
+            PredicateFuncValue::IsNumber {} => {
+                attributes.push(("type".to_string(), JValue::String("isNumber".to_string())));
+            }
             PredicateFuncValue::IsBoolean {} => {
                 attributes.push(("type".to_string(), JValue::String("isBoolean".to_string())));
             }
@@ -471,12 +587,27 @@ impl ToJson for Predicate {
                     JValue::String("isCollection".to_string()),
                 ));
             }
+            PredicateFuncValue::IsSorted {} => {
+                attributes.push(("type".to_string(), JValue::String("isSorted".to_string())));
+            }
+            PredicateFuncValue::IsSortedDescending {} => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("isSortedDescending".to_string()),
+                ));
+            }
             PredicateFuncValue::Exist {} => {
                 attributes.push(("type".to_string(), JValue::String("exist".to_string())));
             }
             PredicateFuncValue::IsEmpty {} => {
                 attributes.push(("type".to_string(), JValue::String("isEmpty".to_string())));
             }
+            PredicateFuncValue::IsValidJson {} => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("isValidJson".to_string()),
+                ));
+            }
         }
         JValue::Object(attributes)
     }
@@ -510,6 +641,7 @@ fn json_predicate_value(predicate_value: PredicateValue) -> (JValue, Option<Stri
         PredicateValue::Regex(value) => {
             (JValue::String(value.to_string()), Some("regex".to_string()))
         }
+        PredicateValue::Size(value) => (JValue::Number(value.bytes().to_string()), None),
     }
 }
 
@@ -582,6 +714,10 @@ impl ToJson for FilterValue {
                     JValue::String("htmlUnescape".to_string()),
                 ));
             }
+            FilterValue::JwtClaim { name, .. } => {
+                attributes.push(("type".to_string(), JValue::String("jwtClaim".to_string())));
+                attributes.push(("name".to_string(), JValue::String(name.to_string())));
+            }
             FilterValue::Regex { value, .. } => {
                 attributes.push(("type".to_string(), JValue::String("regex".to_string())));
                 attributes.push(("expr".to_string(), value.to_json()));
@@ -50,6 +50,9 @@ pub trait Tokenizable {
 impl Tokenizable for HurlFile {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![];
+        if let Some(settings) = &self.settings {
+            tokens.append(&mut settings.tokenize());
+        }
         tokens.append(&mut self.entries.iter().flat_map(|e| e.tokenize()).collect());
         tokens.append(
             &mut self
@@ -127,6 +130,10 @@ impl Tokenizable for Status {
         match self.value.clone() {
             StatusValue::Any => tokens.push(Token::Status("*".to_string())),
             StatusValue::Specific(v) => tokens.push(Token::Status(v.to_string())),
+            StatusValue::Class(v) => tokens.push(Token::Status(format!("{v}xx"))),
+            StatusValue::Range(start, end) => {
+                tokens.push(Token::Status(format!("{start}-{end}")));
+            }
         }
         tokens
     }
@@ -163,9 +170,16 @@ impl Tokenizable for Bytes {
             Bytes::Xml(value) => tokens.push(Token::String(value.to_string())),
             Bytes::MultilineString(value) => tokens.append(&mut value.tokenize()),
             Bytes::OnelineString(value) => tokens.append(&mut value.tokenize()),
+            Bytes::Template(value) => tokens.append(&mut value.tokenize()),
             Bytes::Base64(value) => tokens.append(&mut value.tokenize()),
             Bytes::Hex(value) => tokens.append(&mut value.tokenize()),
             Bytes::File(value) => tokens.append(&mut value.tokenize()),
+            Bytes::FromResponse(index) => {
+                tokens.push(Token::Keyword("from-response".to_string()));
+                tokens.push(Token::Whitespace(" ".to_string()));
+                tokens.push(Token::Number(index.to_string()));
+                tokens.push(Token::Keyword(";".to_string()));
+            }
         }
         tokens
     }
@@ -189,6 +203,24 @@ impl Tokenizable for Section {
     }
 }
 
+impl Tokenizable for Settings {
+    fn tokenize(&self) -> Vec<Token> {
+        let mut tokens: Vec<Token> = vec![];
+        tokens.append(
+            &mut self
+                .line_terminators
+                .iter()
+                .flat_map(|e| e.tokenize())
+                .collect(),
+        );
+        tokens.append(&mut self.space0.tokenize());
+        tokens.push(Token::SectionHeader("[Settings]".to_string()));
+        tokens.append(&mut self.line_terminator0.tokenize());
+        tokens.append(&mut self.options.iter().flat_map(|e| e.tokenize()).collect());
+        tokens
+    }
+}
+
 impl Tokenizable for SectionValue {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![];
@@ -397,6 +429,7 @@ impl Tokenizable for QueryValue {
         match self.clone() {
             QueryValue::Status {} => tokens.push(Token::QueryType(String::from("status"))),
             QueryValue::Url {} => tokens.push(Token::QueryType(String::from("url"))),
+            QueryValue::Charset {} => tokens.push(Token::QueryType(String::from("charset"))),
             QueryValue::Header { space0, name } => {
                 tokens.push(Token::QueryType(String::from("header")));
                 tokens.append(&mut space0.tokenize());
@@ -548,6 +581,31 @@ impl Tokenizable for PredicateFuncValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut value.tokenize());
             }
+            PredicateFuncValue::CountGreaterThan { space0, value } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+            }
+            PredicateFuncValue::CountGreaterThanOrEqual { space0, value } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+            }
+            PredicateFuncValue::CountLessThan { space0, value } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+            }
+            PredicateFuncValue::CountLessThanOrEqual { space0, value } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+            }
+            PredicateFuncValue::LengthEqual { space0, value } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+            }
             PredicateFuncValue::StartWith { space0, value } => {
                 tokens.push(Token::PredicateType(self.name()));
                 tokens.append(&mut space0.tokenize());
@@ -573,6 +631,38 @@ impl Tokenizable for PredicateFuncValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut value.tokenize());
             }
+            PredicateFuncValue::MatchSchema { space0, value } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+            }
+            PredicateFuncValue::MatchTemplate { space0, value } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+            }
+            PredicateFuncValue::EqualIgnoreOrder { space0, value }
+            | PredicateFuncValue::MatchAll { space0, value }
+            | PredicateFuncValue::MatchAny { space0, value } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+            }
+            PredicateFuncValue::IsCloseTo {
+                space0,
+                value,
+                space1,
+                space2,
+                epsilon,
+            } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+                tokens.append(&mut space1.tokenize());
+                tokens.push(Token::PredicateType(String::from("within")));
+                tokens.append(&mut space2.tokenize());
+                tokens.append(&mut epsilon.tokenize());
+            }
 
             PredicateFuncValue::IsInteger {} => {
                 tokens.push(Token::PredicateType(self.name()));
@@ -580,6 +670,9 @@ impl Tokenizable for PredicateFuncValue {
             PredicateFuncValue::IsFloat {} => {
                 tokens.push(Token::PredicateType(self.name()));
             }
+            PredicateFuncValue::IsNumber {} => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
             PredicateFuncValue::IsBoolean {} => {
                 tokens.push(Token::PredicateType(self.name()));
             }
@@ -589,17 +682,40 @@ impl Tokenizable for PredicateFuncValue {
             PredicateFuncValue::IsCollection {} => {
                 tokens.push(Token::PredicateType(self.name()));
             }
+            PredicateFuncValue::IsSorted {} => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
+            PredicateFuncValue::IsSortedDescending {} => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
             PredicateFuncValue::Exist {} => {
                 tokens.push(Token::PredicateType(self.name()));
             }
             PredicateFuncValue::IsEmpty {} => {
                 tokens.push(Token::PredicateType(self.name()));
             }
+            PredicateFuncValue::IsValidJson {} => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
         }
         tokens
     }
 }
 
+impl Tokenizable for SchemaSource {
+    fn tokenize(&self) -> Vec<Token> {
+        match self {
+            SchemaSource::Inline(value) => value.tokenize(),
+            SchemaSource::File { space0, filename } => {
+                let mut tokens: Vec<Token> = vec![Token::Keyword(String::from("file"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut filename.tokenize());
+                tokens
+            }
+        }
+    }
+}
+
 impl Tokenizable for PredicateValue {
     fn tokenize(&self) -> Vec<Token> {
         match self {
@@ -613,6 +729,7 @@ impl Tokenizable for PredicateValue {
             PredicateValue::Base64(value) => value.tokenize(),
             PredicateValue::Expression(value) => value.tokenize(),
             PredicateValue::Regex(value) => value.tokenize(),
+            PredicateValue::Size(value) => vec![Token::Number(value.to_string())],
         }
     }
 }
@@ -641,6 +758,12 @@ impl Tokenizable for MultilineString {
 impl Tokenizable for Text {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![];
+        if let Some(charset) = &self.charset {
+            tokens.push(Token::Lang(charset.to_string()));
+        }
+        if let Some(content_type) = &self.content_type {
+            tokens.push(Token::Lang(content_type.to_string()));
+        }
         tokens.append(&mut self.space.tokenize());
         tokens.append(&mut self.newline.tokenize());
         tokens.append(&mut self.value.tokenize());
@@ -790,7 +913,11 @@ impl Tokenizable for JsonValue {
             JsonValue::Boolean(value) => {
                 tokens.push(Token::Boolean(value.to_string()));
             }
-            JsonValue::List { space0, elements } => {
+            JsonValue::List {
+                space0,
+                elements,
+                comments,
+            } => {
                 tokens.push(Token::CodeDelimiter("[".to_string()));
                 tokens.push(Token::Whitespace(space0.clone()));
                 for (i, element) in elements.iter().enumerate() {
@@ -799,9 +926,16 @@ impl Tokenizable for JsonValue {
                     }
                     tokens.append(&mut element.tokenize());
                 }
+                for comment in comments {
+                    tokens.append(&mut comment.tokenize());
+                }
                 tokens.push(Token::CodeDelimiter("]".to_string()));
             }
-            JsonValue::Object { space0, elements } => {
+            JsonValue::Object {
+                space0,
+                elements,
+                comments,
+            } => {
                 tokens.push(Token::CodeDelimiter("{".to_string()));
                 tokens.push(Token::Whitespace(space0.clone()));
                 for (i, element) in elements.iter().enumerate() {
@@ -810,6 +944,9 @@ impl Tokenizable for JsonValue {
                     }
                     tokens.append(&mut element.tokenize());
                 }
+                for comment in comments {
+                    tokens.append(&mut comment.tokenize());
+                }
                 tokens.push(Token::CodeDelimiter("}".to_string()));
             }
             JsonValue::Null {} => {
@@ -826,6 +963,9 @@ impl Tokenizable for JsonValue {
 impl Tokenizable for JsonListElement {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![Token::Whitespace(self.space0.clone())];
+        for comment in &self.comments {
+            tokens.append(&mut comment.tokenize());
+        }
         tokens.append(&mut self.value.tokenize());
         tokens.push(Token::Whitespace(self.space1.clone()));
         tokens
@@ -835,6 +975,9 @@ impl Tokenizable for JsonListElement {
 impl Tokenizable for JsonObjectElement {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![Token::Whitespace(self.space0.clone())];
+        for comment in &self.comments {
+            tokens.append(&mut comment.tokenize());
+        }
         tokens.push(Token::StringDelimiter("\"".to_string()));
         tokens.push(Token::String(self.name.to_string()));
         tokens.push(Token::StringDelimiter("\"".to_string()));
@@ -847,6 +990,15 @@ impl Tokenizable for JsonObjectElement {
     }
 }
 
+impl Tokenizable for JsonComment {
+    fn tokenize(&self) -> Vec<Token> {
+        vec![
+            Token::Comment(format!("//{}", self.value)),
+            Token::Whitespace(self.space0.clone()),
+        ]
+    }
+}
+
 impl Tokenizable for EntryOption {
     fn tokenize(&self) -> Vec<Token> {
         match self {
@@ -854,11 +1006,17 @@ impl Tokenizable for EntryOption {
             EntryOption::ClientCert(option) => option.tokenize(),
             EntryOption::ClientKey(option) => option.tokenize(),
             EntryOption::Compressed(option) => option.tokenize(),
+            EntryOption::ConnectTimeout(option) => option.tokenize(),
+            EntryOption::Curl(option) => option.tokenize(),
             EntryOption::Insecure(option) => option.tokenize(),
             EntryOption::FollowLocation(option) => option.tokenize(),
             EntryOption::MaxRedirect(option) => option.tokenize(),
+            EntryOption::NoProxy(option) => option.tokenize(),
+            EntryOption::Proxy(option) => option.tokenize(),
+            EntryOption::Repeat(option) => option.tokenize(),
             EntryOption::Retry(option) => option.tokenize(),
             EntryOption::RetryInterval(option) => option.tokenize(),
+            EntryOption::Timeout(option) => option.tokenize(),
             EntryOption::Variable(option) => option.tokenize(),
             EntryOption::Verbose(option) => option.tokenize(),
             EntryOption::VeryVerbose(option) => option.tokenize(),
@@ -1013,6 +1171,90 @@ impl Tokenizable for MaxRedirectOption {
     }
 }
 
+impl Tokenizable for CurlOption {
+    fn tokenize(&self) -> Vec<Token> {
+        let mut tokens: Vec<Token> = vec![];
+        tokens.append(
+            &mut self
+                .line_terminators
+                .iter()
+                .flat_map(|e| e.tokenize())
+                .collect(),
+        );
+        tokens.append(&mut self.space0.tokenize());
+        tokens.push(Token::String("curl".to_string()));
+        tokens.append(&mut self.space1.tokenize());
+        tokens.push(Token::Colon(String::from(":")));
+        tokens.append(&mut self.space2.tokenize());
+        tokens.push(Token::String(self.value.clone()));
+        tokens.append(&mut self.line_terminator0.tokenize());
+        tokens
+    }
+}
+
+impl Tokenizable for NoProxyOption {
+    fn tokenize(&self) -> Vec<Token> {
+        let mut tokens: Vec<Token> = vec![];
+        tokens.append(
+            &mut self
+                .line_terminators
+                .iter()
+                .flat_map(|e| e.tokenize())
+                .collect(),
+        );
+        tokens.append(&mut self.space0.tokenize());
+        tokens.push(Token::String("no-proxy".to_string()));
+        tokens.append(&mut self.space1.tokenize());
+        tokens.push(Token::Colon(String::from(":")));
+        tokens.append(&mut self.space2.tokenize());
+        tokens.push(Token::String(self.value.join(",")));
+        tokens.append(&mut self.line_terminator0.tokenize());
+        tokens
+    }
+}
+
+impl Tokenizable for ProxyOption {
+    fn tokenize(&self) -> Vec<Token> {
+        let mut tokens: Vec<Token> = vec![];
+        tokens.append(
+            &mut self
+                .line_terminators
+                .iter()
+                .flat_map(|e| e.tokenize())
+                .collect(),
+        );
+        tokens.append(&mut self.space0.tokenize());
+        tokens.push(Token::String("proxy".to_string()));
+        tokens.append(&mut self.space1.tokenize());
+        tokens.push(Token::Colon(String::from(":")));
+        tokens.append(&mut self.space2.tokenize());
+        tokens.push(Token::String(self.value.to_string()));
+        tokens.append(&mut self.line_terminator0.tokenize());
+        tokens
+    }
+}
+
+impl Tokenizable for RepeatOption {
+    fn tokenize(&self) -> Vec<Token> {
+        let mut tokens: Vec<Token> = vec![];
+        tokens.append(
+            &mut self
+                .line_terminators
+                .iter()
+                .flat_map(|e| e.tokenize())
+                .collect(),
+        );
+        tokens.append(&mut self.space0.tokenize());
+        tokens.push(Token::String("repeat".to_string()));
+        tokens.append(&mut self.space1.tokenize());
+        tokens.push(Token::Colon(String::from(":")));
+        tokens.append(&mut self.space2.tokenize());
+        tokens.push(Token::Number(self.value.to_string()));
+        tokens.append(&mut self.line_terminator0.tokenize());
+        tokens
+    }
+}
+
 impl Tokenizable for RetryOption {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![];
@@ -1067,6 +1309,48 @@ impl Tokenizable for RetryIntervalOption {
     }
 }
 
+impl Tokenizable for TimeoutOption {
+    fn tokenize(&self) -> Vec<Token> {
+        let mut tokens: Vec<Token> = vec![];
+        tokens.append(
+            &mut self
+                .line_terminators
+                .iter()
+                .flat_map(|e| e.tokenize())
+                .collect(),
+        );
+        tokens.append(&mut self.space0.tokenize());
+        tokens.push(Token::String("timeout".to_string()));
+        tokens.append(&mut self.space1.tokenize());
+        tokens.push(Token::Colon(String::from(":")));
+        tokens.append(&mut self.space2.tokenize());
+        tokens.push(Token::Number(self.value.to_string()));
+        tokens.append(&mut self.line_terminator0.tokenize());
+        tokens
+    }
+}
+
+impl Tokenizable for ConnectTimeoutOption {
+    fn tokenize(&self) -> Vec<Token> {
+        let mut tokens: Vec<Token> = vec![];
+        tokens.append(
+            &mut self
+                .line_terminators
+                .iter()
+                .flat_map(|e| e.tokenize())
+                .collect(),
+        );
+        tokens.append(&mut self.space0.tokenize());
+        tokens.push(Token::String("connect-timeout".to_string()));
+        tokens.append(&mut self.space1.tokenize());
+        tokens.push(Token::Colon(String::from(":")));
+        tokens.append(&mut self.space2.tokenize());
+        tokens.push(Token::Number(self.value.to_string()));
+        tokens.append(&mut self.line_terminator0.tokenize());
+        tokens
+    }
+}
+
 impl Tokenizable for VariableOption {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![];
@@ -1169,6 +1453,12 @@ impl Tokenizable for Filter {
             FilterValue::HtmlUnescape => {
                 vec![Token::FilterType(String::from("htmlUnescape"))]
             }
+            FilterValue::JwtClaim { space0, name } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("jwtClaim"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut name.tokenize());
+                tokens
+            }
             FilterValue::Nth { space0, n } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("nth"))];
                 tokens.append(&mut space0.tokenize());
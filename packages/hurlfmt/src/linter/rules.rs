@@ -27,11 +27,22 @@ pub fn check_hurl_file(hurl_file: &HurlFile) -> Vec<Error> {
 /// Returns a new linted instance from this `hurl_file`.
 pub fn lint_hurl_file(hurl_file: &HurlFile) -> HurlFile {
     HurlFile {
+        settings: hurl_file.settings.as_ref().map(lint_settings),
         entries: hurl_file.entries.iter().map(lint_entry).collect(),
         line_terminators: hurl_file.line_terminators.clone(),
     }
 }
 
+fn lint_settings(settings: &Settings) -> Settings {
+    Settings {
+        line_terminators: settings.line_terminators.clone(),
+        space0: empty_whitespace(),
+        line_terminator0: settings.line_terminator0.clone(),
+        options: settings.options.iter().map(lint_entry_option).collect(),
+        source_info: SourceInfo::new(0, 0, 0, 0),
+    }
+}
+
 fn check_entry(entry: &Entry) -> Vec<Error> {
     let mut errors = vec![];
     errors.append(&mut check_request(&entry.request));
@@ -45,7 +56,12 @@ fn check_entry(entry: &Entry) -> Vec<Error> {
 fn lint_entry(entry: &Entry) -> Entry {
     let request = lint_request(&entry.request);
     let response = entry.response.as_ref().map(lint_response);
-    Entry { request, response }
+    let name = entry.name.clone();
+    Entry {
+        request,
+        response,
+        name,
+    }
 }
 
 fn check_request(request: &Request) -> Vec<Error> {
@@ -234,6 +250,7 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
     match query_value {
         QueryValue::Status {} => QueryValue::Status {},
         QueryValue::Url {} => QueryValue::Url {},
+        QueryValue::Charset {} => QueryValue::Charset {},
         QueryValue::Header { name, .. } => QueryValue::Header {
             name: name.clone(),
             space0: one_whitespace(),
@@ -382,6 +399,34 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
             space0: one_whitespace(),
             value: lint_predicate_value(value),
         },
+        PredicateFuncValue::MatchSchema { value, .. } => PredicateFuncValue::MatchSchema {
+            space0: one_whitespace(),
+            value: match value {
+                SchemaSource::Inline(value) => SchemaSource::Inline(value.clone()),
+                SchemaSource::File { filename, .. } => SchemaSource::File {
+                    space0: one_whitespace(),
+                    filename: lint_template(filename),
+                },
+            },
+        },
+        PredicateFuncValue::MatchTemplate { value, .. } => PredicateFuncValue::MatchTemplate {
+            space0: one_whitespace(),
+            value: lint_template(value),
+        },
+        PredicateFuncValue::EqualIgnoreOrder { value, .. } => {
+            PredicateFuncValue::EqualIgnoreOrder {
+                space0: one_whitespace(),
+                value: value.clone(),
+            }
+        }
+        PredicateFuncValue::MatchAll { value, .. } => PredicateFuncValue::MatchAll {
+            space0: one_whitespace(),
+            value: value.clone(),
+        },
+        PredicateFuncValue::MatchAny { value, .. } => PredicateFuncValue::MatchAny {
+            space0: one_whitespace(),
+            value: value.clone(),
+        },
         PredicateFuncValue::StartWith { value, .. } => PredicateFuncValue::StartWith {
             space0: one_whitespace(),
             value: lint_predicate_value(value),
@@ -394,13 +439,50 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
             space0: one_whitespace(),
             value: value.clone(),
         },
+        PredicateFuncValue::CountGreaterThan { value, .. } => {
+            PredicateFuncValue::CountGreaterThan {
+                space0: one_whitespace(),
+                value: value.clone(),
+            }
+        }
+        PredicateFuncValue::CountGreaterThanOrEqual { value, .. } => {
+            PredicateFuncValue::CountGreaterThanOrEqual {
+                space0: one_whitespace(),
+                value: value.clone(),
+            }
+        }
+        PredicateFuncValue::CountLessThan { value, .. } => PredicateFuncValue::CountLessThan {
+            space0: one_whitespace(),
+            value: value.clone(),
+        },
+        PredicateFuncValue::CountLessThanOrEqual { value, .. } => {
+            PredicateFuncValue::CountLessThanOrEqual {
+                space0: one_whitespace(),
+                value: value.clone(),
+            }
+        }
+        PredicateFuncValue::LengthEqual { value, .. } => PredicateFuncValue::LengthEqual {
+            space0: one_whitespace(),
+            value: value.clone(),
+        },
+        PredicateFuncValue::IsCloseTo { value, epsilon, .. } => PredicateFuncValue::IsCloseTo {
+            space0: one_whitespace(),
+            value: value.clone(),
+            space1: one_whitespace(),
+            space2: one_whitespace(),
+            epsilon: epsilon.clone(),
+        },
         PredicateFuncValue::IsInteger {} => PredicateFuncValue::IsInteger {},
         PredicateFuncValue::IsFloat {} => PredicateFuncValue::IsFloat {},
+        PredicateFuncValue::IsNumber {} => PredicateFuncValue::IsNumber {},
         PredicateFuncValue::IsBoolean {} => PredicateFuncValue::IsBoolean {},
         PredicateFuncValue::IsString {} => PredicateFuncValue::IsString {},
         PredicateFuncValue::IsCollection {} => PredicateFuncValue::IsCollection {},
+        PredicateFuncValue::IsSorted {} => PredicateFuncValue::IsSorted {},
+        PredicateFuncValue::IsSortedDescending {} => PredicateFuncValue::IsSortedDescending {},
         PredicateFuncValue::Exist {} => PredicateFuncValue::Exist {},
         PredicateFuncValue::IsEmpty {} => PredicateFuncValue::IsEmpty {},
+        PredicateFuncValue::IsValidJson {} => PredicateFuncValue::IsValidJson {},
     }
 }
 
@@ -418,6 +500,7 @@ fn lint_predicate_value(predicate_value: &PredicateValue) -> PredicateValue {
         PredicateValue::Base64(value) => PredicateValue::Base64(lint_base64(value)),
         PredicateValue::Expression(value) => PredicateValue::Expression(value.clone()),
         PredicateValue::Regex(value) => PredicateValue::Regex(value.clone()),
+        PredicateValue::Size(value) => PredicateValue::Size(value.clone()),
     }
 }
 
@@ -435,7 +518,20 @@ fn lint_text(text: &Text) -> Text {
     let space = empty_whitespace();
     let newline = text.newline.clone();
     let value = lint_template(&text.value);
+    let charset = text.charset.as_ref().map(|charset| MultilineCharset {
+        space0: one_whitespace(),
+        value: charset.value.clone(),
+    });
+    let content_type = text
+        .content_type
+        .as_ref()
+        .map(|content_type| MultilineContentType {
+            space0: one_whitespace(),
+            value: content_type.value.clone(),
+        });
     Text {
+        charset,
+        content_type,
         space,
         newline,
         value,
@@ -478,8 +574,10 @@ fn lint_bytes(bytes: &Bytes) -> Bytes {
         Bytes::Hex(value) => Bytes::Hex(lint_hex(value)),
         Bytes::Json(value) => Bytes::Json(value.clone()),
         Bytes::OnelineString(value) => Bytes::OnelineString(lint_template(value)),
+        Bytes::Template(value) => Bytes::Template(lint_template(value)),
         Bytes::MultilineString(value) => Bytes::MultilineString(lint_multiline_string(value)),
         Bytes::Xml(value) => Bytes::Xml(value.clone()),
+        Bytes::FromResponse(index) => Bytes::FromResponse(*index),
     }
 }
 
@@ -652,10 +750,12 @@ mod tests {
     #[test]
     fn test_hurl_file() {
         let hurl_file = HurlFile {
+            settings: None,
             entries: vec![],
             line_terminators: vec![],
         };
         let hurl_file_linted = HurlFile {
+            settings: None,
             entries: vec![],
             line_terminators: vec![],
         };
@@ -666,10 +766,12 @@ mod tests {
     #[test]
     fn test_entry() {
         let entry = HurlFile {
+            settings: None,
             entries: vec![],
             line_terminators: vec![],
         };
         let entry_linted = HurlFile {
+            settings: None,
             entries: vec![],
             line_terminators: vec![],
         };
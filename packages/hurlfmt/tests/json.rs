@@ -113,16 +113,19 @@ fn value() -> BoxedStrategy<JsonValue> {
                 // Lists
                 (whitespace()).prop_map(|space0| JsonValue::List {
                     space0,
-                    elements: vec![]
+                    elements: vec![],
+                    comments: vec![]
                 }),
                 (whitespace(), whitespace(), value.clone()).prop_map(|(space0, space1, value)| {
                     JsonValue::List {
                         space0,
                         elements: vec![JsonListElement {
                             space0: "".to_string(),
+                            comments: vec![],
                             value,
                             space1,
                         }],
+                        comments: vec![],
                     }
                 }),
                 (
@@ -139,15 +142,18 @@ fn value() -> BoxedStrategy<JsonValue> {
                             elements: vec![
                                 JsonListElement {
                                     space0: "".to_string(),
+                                    comments: vec![],
                                     value: value0,
                                     space1: space01
                                 },
                                 JsonListElement {
                                     space0: space10,
+                                    comments: vec![],
                                     value: value1,
                                     space1: space11
                                 },
-                            ]
+                            ],
+                            comments: vec![]
                         }
                     ),
                 (
@@ -164,15 +170,18 @@ fn value() -> BoxedStrategy<JsonValue> {
                             elements: vec![
                                 JsonListElement {
                                     space0: "".to_string(),
+                                    comments: vec![],
                                     value: value0,
                                     space1: space01
                                 },
                                 JsonListElement {
                                     space0: space10,
+                                    comments: vec![],
                                     value: value1,
                                     space1: space11
                                 },
-                            ]
+                            ],
+                            comments: vec![]
                         }
                     ),
                 (
@@ -189,21 +198,25 @@ fn value() -> BoxedStrategy<JsonValue> {
                             elements: vec![
                                 JsonListElement {
                                     space0: "".to_string(),
+                                    comments: vec![],
                                     value: value0,
                                     space1: space01
                                 },
                                 JsonListElement {
                                     space0: space10,
+                                    comments: vec![],
                                     value: value1,
                                     space1: space11
                                 },
-                            ]
+                            ],
+                            comments: vec![]
                         }
                     ),
                 // Object
                 (whitespace()).prop_map(|space0| JsonValue::Object {
                     space0,
-                    elements: vec![]
+                    elements: vec![],
+                    comments: vec![]
                 }),
                 (
                     whitespace(),
@@ -217,6 +230,7 @@ fn value() -> BoxedStrategy<JsonValue> {
                             space0,
                             elements: vec![JsonObjectElement {
                                 space0: "".to_string(),
+                                comments: vec![],
                                 name: Template {
                                     delimiter: None,
                                     elements: vec![TemplateElement::String {
@@ -230,6 +244,7 @@ fn value() -> BoxedStrategy<JsonValue> {
                                 value,
                                 space3,
                             }],
+                            comments: vec![],
                         }
                     }),
             ]
@@ -252,7 +267,8 @@ fn format_token(token: Token) -> String {
         | Token::StringDelimiter(s)
         | Token::QueryType(s)
         | Token::CodeVariable(s)
-        | Token::CodeDelimiter(s) => s,
+        | Token::CodeDelimiter(s)
+        | Token::Comment(s) => s,
         _ => panic!("invalid token {token:?}"),
     }
 }
@@ -300,3 +316,25 @@ fn test_parse_files() {
         assert_eq!(format_value(parsed_value), s);
     }
 }
+
+#[test]
+fn test_format_comments() {
+    let s = r#"{
+  // Identifier
+  "id": 1,
+  "tags": [
+    "a", // second tag
+    "b"
+    // no more tags
+  ],
+  "extra": { // none yet
+  },
+  "aliases": [
+    // none yet
+  ]
+  // end
+}"#;
+    let mut reader = Reader::new(s);
+    let parsed_value = parse_json(&mut reader).unwrap();
+    assert_eq!(format_value(parsed_value), s);
+}